@@ -0,0 +1,110 @@
+//! Benchmarks of the LCS of two strings
+//!
+//! Run with `cargo bench --bench lcs`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use the_algorithms_rust::dynamic_programming::longest_common_subsequence;
+use the_algorithms_rust::string::{
+    lcs_four_russians, lcs_hirschberg, lcs_hunt_szymanski, lcs_length, mlcs_len_pair,
+};
+
+#[allow(dead_code)]
+#[path = "../src/string/random_strings.rs"]
+mod random_strings;
+
+use random_strings::Xorshift;
+
+/// Two strings of 1000 chars, by the classic dynamic programming and the Four Russians
+fn four_russians(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lcs/1000");
+    for letters in [4, 26] {
+        let s1 = Xorshift::new(0x2545_f491_4f6c_dd1d).string(1000, letters);
+        let s2 = Xorshift::new(0x9e37_79b9_7f4a_7c15).string(1000, letters);
+        assert_eq!(lcs_four_russians(&s1, &s2), mlcs_len_pair(&s1, &s2));
+        group.bench_function(format!("dynamic_programming/{letters}_letters"), |b| {
+            b.iter(|| mlcs_len_pair(black_box(&s1), black_box(&s2)));
+        });
+        group.bench_function(format!("four_russians/{letters}_letters"), |b| {
+            b.iter(|| lcs_four_russians(black_box(&s1), black_box(&s2)));
+        });
+    }
+    group.finish();
+}
+
+/// Two strings of 4000 chars, the full table taking 128 MB and the row of `lcs_length`
+/// 32 kB
+///
+/// Both fill the same 1.6 * 10^7 cells, the full table spending its time in cache misses
+/// and in the allocation of its rows.
+fn linear_space(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lcs/4000");
+    group.sample_size(10);
+    let s1 = Xorshift::new(0x2545_f491_4f6c_dd1d).string(4000, 4);
+    let s2 = Xorshift::new(0x9e37_79b9_7f4a_7c15).string(4000, 4);
+    assert_eq!(
+        lcs_length(&s1, &s2),
+        longest_common_subsequence(&s1, &s2).len()
+    );
+    group.bench_function("full_table", |b| {
+        b.iter(|| longest_common_subsequence(black_box(&s1), black_box(&s2)).len());
+    });
+    group.bench_function("one_row", |b| {
+        b.iter(|| lcs_length(black_box(&s1), black_box(&s2)));
+    });
+    group.finish();
+}
+
+/// A string of 1 MB against one of 1000 chars, whose full LCS table would take 8 GB
+///
+/// Two strings of 1 MB would fit in linear space too, but their 10^12 cells take hours.
+fn hirschberg(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lcs/1MB");
+    group.sample_size(10);
+    let s1 = Xorshift::new(0x2545_f491_4f6c_dd1d).string(1 << 20, 4);
+    let s2 = Xorshift::new(0x9e37_79b9_7f4a_7c15).string(1000, 4);
+    group.bench_function("hirschberg", |b| {
+        b.iter(|| lcs_hirschberg(black_box(&s1), black_box(&s2)));
+    });
+    group.finish();
+}
+
+/// Two files of 50 000 lines, about 1% of the lines of each occurring in the other one
+///
+/// The dynamic programming would fill 2.5 * 10^9 cells, the Hunt-Szymanski algorithm only
+/// visits the matching pairs of lines.
+fn hunt_szymanski(c: &mut Criterion) {
+    let lines = 50_000;
+    let file = |name: &str, seed: u64| -> Vec<String> {
+        let mut rng = Xorshift::new(seed);
+        (0..lines)
+            .map(|n| {
+                // one line in a hundred is drawn from 500 lines shared by both files
+                let draw = rng.below(100 * 500);
+                if draw.is_multiple_of(100) {
+                    format!("    shared line {};", draw / 100)
+                } else {
+                    format!("    {name} line {n};")
+                }
+            })
+            .collect()
+    };
+    let old = file("old", 0x2545_f491_4f6c_dd1d);
+    let new = file("new", 0x9e37_79b9_7f4a_7c15);
+
+    let mut group = c.benchmark_group("lcs/50k_lines");
+    group.sample_size(10);
+    group.bench_function("hunt_szymanski", |b| {
+        b.iter(|| lcs_hunt_szymanski(black_box(&old), black_box(&new)));
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    four_russians,
+    linear_space,
+    hirschberg,
+    hunt_szymanski
+);
+criterion_main!(benches);
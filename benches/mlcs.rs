@@ -0,0 +1,100 @@
+//! Benchmarks of the MLCS-A* search
+//!
+//! Run with `cargo bench --bench mlcs`
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use the_algorithms_rust::string::{
+    lcs_len_pair_with_scratch, multiple_longest_common_subsequence, Context, MlcsConfig,
+};
+
+#[path = "../src/string/multiple_longest_common_subsequence/fixtures.rs"]
+mod fixtures;
+
+use fixtures::{assert_fixture, synthetic, FIXTURES};
+
+fn mlcs(input: &[&str]) -> String {
+    multiple_longest_common_subsequence(&input.to_vec())
+}
+
+/// Building the tables only: alphabet, lookup tables and suffix tables
+fn preprocessing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mlcs/preprocessing");
+    for fixture in &FIXTURES {
+        group.bench_function(fixture.name, |b| {
+            b.iter(|| Context::new(black_box(fixture.input)));
+        });
+    }
+    group.finish();
+}
+
+/// The whole computation, preprocessing included
+fn search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mlcs/search");
+    for fixture in &FIXTURES {
+        assert_fixture(fixture, mlcs);
+        group.bench_function(fixture.name, |b| {
+            b.iter(|| mlcs(black_box(fixture.input)));
+        });
+    }
+    group.finish();
+}
+
+/// d strings of length n over an alphabet of k letters
+fn synthetic_inputs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mlcs/synthetic");
+    for (d, n, k) in [
+        (3, 50, 4),
+        (5, 50, 4),
+        (3, 200, 4),
+        (3, 200, 20),
+        (6, 60, 8),
+    ] {
+        let strings = synthetic(d, n, k, 42);
+        let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("d{d}_n{n}_k{k}")),
+            &input,
+            |b, input| {
+                b.iter(|| Context::new(black_box(input)).solve(&MlcsConfig::default()));
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The LCS lengths of every pair of 30 strings of 100 chars, with a new row for each pair
+/// or a single scratch row
+fn pair_lengths(c: &mut Criterion) {
+    let strings = synthetic(30, 100, 4, 42);
+    let mut group = c.benchmark_group("mlcs/pair_lengths");
+    group.bench_function("allocating", |b| {
+        b.iter(|| {
+            for s1 in &strings {
+                for s2 in &strings {
+                    black_box(lcs_len_pair_with_scratch(s1, s2, &mut Vec::new()));
+                }
+            }
+        });
+    });
+    group.bench_function("scratch", |b| {
+        let mut scratch = vec![];
+        b.iter(|| {
+            for s1 in &strings {
+                for s2 in &strings {
+                    black_box(lcs_len_pair_with_scratch(s1, s2, &mut scratch));
+                }
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    preprocessing,
+    search,
+    synthetic_inputs,
+    pair_lengths
+);
+criterion_main!(benches);
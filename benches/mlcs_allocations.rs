@@ -0,0 +1,57 @@
+//! Number of allocations of the MLCS-A* search on the fixtures, counted by a global
+//! allocator wrapping the system one
+//!
+//! Run with `cargo bench --bench mlcs_allocations`
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use the_algorithms_rust::string::{Context, MlcsConfig};
+
+// the synthetic inputs are not measured
+#[allow(dead_code)]
+#[path = "../src/string/multiple_longest_common_subsequence/fixtures.rs"]
+mod fixtures;
+
+use fixtures::{assert_fixture, FIXTURES};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+// the allocations and reallocations made by f
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    (result, ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+fn main() {
+    for fixture in &FIXTURES {
+        let (mut ctx, tables) = allocations(|| Context::new(fixture.input));
+        let (result, search) = allocations(|| ctx.solve(&MlcsConfig::default()));
+        assert_fixture(fixture, |_| result.clone());
+        println!(
+            "{}: {tables} allocations building the tables, {search} searching",
+            fixture.name
+        );
+    }
+}
@@ -0,0 +1,50 @@
+//! Benchmarks of the exact string searches
+//!
+//! Run with `cargo bench --bench string_search`
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use the_algorithms_rust::string::{bmh_search, boyer_moore_search, knuth_morris_pratt};
+
+#[allow(dead_code)]
+#[path = "../src/string/random_strings.rs"]
+mod random_strings;
+
+use random_strings::Xorshift;
+
+/// About 1MB of pseudo-random lowercase words, with the pattern near the end
+fn text_and_pattern() -> (String, &'static str) {
+    let pattern = "quicksort!";
+    let mut rng = Xorshift::default();
+    let mut text = String::with_capacity(1 << 20);
+    while text.len() < (1 << 20) - 32 {
+        let len = 1 + rng.below(9);
+        text.push_str(&rng.string(len, 26));
+        text.push(' ');
+    }
+    text.push_str(pattern);
+    (text, pattern)
+}
+
+/// Searching a 10 byte pattern in a 1MB text
+fn one_megabyte(c: &mut Criterion) {
+    let (text, pattern) = text_and_pattern();
+    let expected = knuth_morris_pratt(&text, pattern);
+    assert_eq!(bmh_search(text.as_bytes(), pattern.as_bytes()), expected);
+
+    let mut group = c.benchmark_group("string_search/1MB");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("knuth_morris_pratt", |b| {
+        b.iter(|| knuth_morris_pratt(black_box(&text), black_box(pattern)));
+    });
+    group.bench_function("boyer_moore_horspool", |b| {
+        b.iter(|| bmh_search(black_box(text.as_bytes()), black_box(pattern.as_bytes())));
+    });
+    group.bench_function("boyer_moore", |b| {
+        b.iter(|| boyer_moore_search(black_box(&text), black_box(pattern)));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, one_megabyte);
+criterion_main!(benches);
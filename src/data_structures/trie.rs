@@ -76,6 +76,100 @@ where
         }
         node.value.as_ref()
     }
+
+    /// Checks if some sequence of keys in the Trie starts with `prefix`.
+    ///
+    /// The empty prefix is always found, even in an empty Trie.
+    pub fn has_prefix(&self, prefix: impl IntoIterator<Item = Key>) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    /// Retrieves the values of every sequence of keys starting with `prefix`, in no
+    /// particular order.
+    pub fn values_with_prefix(&self, prefix: impl IntoIterator<Item = Key>) -> Vec<&Type> {
+        let mut values = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            node.collect_values(&mut values);
+        }
+        values
+    }
+
+    /// Retrieves the value of the longest sequence of keys in the Trie which is a prefix of
+    /// `key`, if any.
+    pub fn longest_prefix_value(&self, key: impl IntoIterator<Item = Key>) -> Option<&Type> {
+        let mut node = &self.root;
+        let mut longest = node.value.as_ref();
+        for c in key {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => break,
+            }
+            longest = node.value.as_ref().or(longest);
+        }
+        longest
+    }
+
+    /// Returns the number of sequences of keys holding a value.
+    pub fn len(&self) -> usize {
+        self.root.count_values()
+    }
+
+    /// Checks if no sequence of keys holds a value.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the value associated with a sequence of keys, pruning the nodes left without
+    /// values below them.
+    ///
+    /// # Returns
+    /// The removed value, or `None` if the sequence of keys was not in the Trie.
+    pub fn remove(&mut self, key: impl IntoIterator<Item = Key>) -> Option<Type> {
+        let key: Vec<Key> = key.into_iter().collect();
+        self.root.remove(&key)
+    }
+
+    fn find(&self, prefix: impl IntoIterator<Item = Key>) -> Option<&Node<Key, Type>> {
+        let mut node = &self.root;
+        for c in prefix {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+impl<Key, Type> Node<Key, Type>
+where
+    Key: Default + Eq + Hash,
+    Type: Default,
+{
+    fn collect_values<'a>(&'a self, values: &mut Vec<&'a Type>) {
+        values.extend(self.value.as_ref());
+        for child in self.children.values() {
+            child.collect_values(values);
+        }
+    }
+
+    fn count_values(&self) -> usize {
+        usize::from(self.value.is_some())
+            + self
+                .children
+                .values()
+                .map(Self::count_values)
+                .sum::<usize>()
+    }
+
+    fn remove(&mut self, key: &[Key]) -> Option<Type> {
+        let Some((first, rest)) = key.split_first() else {
+            return self.value.take();
+        };
+        let child = self.children.get_mut(first)?;
+        let removed = child.remove(rest);
+        if child.value.is_none() && child.children.is_empty() {
+            self.children.remove(first);
+        }
+        removed
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +236,43 @@ mod tests {
         assert_eq!(trie.get("care".chars()), None);
     }
 
+    #[test]
+    fn test_prefix_queries() {
+        let mut trie = Trie::new();
+
+        trie.insert("car".chars(), 1);
+        trie.insert("cart".chars(), 2);
+        trie.insert("dog".chars(), 3);
+        assert!(trie.has_prefix("ca".chars()));
+        assert!(trie.has_prefix("".chars()));
+        assert!(!trie.has_prefix("cb".chars()));
+        let mut values = trie.values_with_prefix("car".chars());
+        values.sort_unstable();
+        assert_eq!(values, [&1, &2]);
+        assert!(trie.values_with_prefix("x".chars()).is_empty());
+        assert_eq!(trie.longest_prefix_value("carts".chars()), Some(&2));
+        assert_eq!(trie.longest_prefix_value("carp".chars()), Some(&1));
+        assert_eq!(trie.longest_prefix_value("ca".chars()), None);
+    }
+
+    #[test]
+    fn test_remove_and_len() {
+        let mut trie = Trie::new();
+
+        trie.insert(vec![1, 2], "a");
+        trie.insert(vec![1, 2, 3, 4], "b");
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.remove(vec![1, 2, 3]), None);
+        assert_eq!(trie.remove(vec![1, 2, 3, 4]), Some("b"));
+        assert_eq!(trie.remove(vec![1, 2, 3, 4]), None);
+        // the branch left without values is pruned
+        assert!(!trie.has_prefix(vec![1, 2, 3]));
+        assert_eq!(trie.get(vec![1, 2]), Some(&"a"));
+        assert_eq!(trie.remove(vec![1, 2]), Some("a"));
+        assert!(trie.is_empty());
+        assert!(!trie.has_prefix(vec![1]));
+    }
+
     #[test]
     fn test_partial_match() {
         let mut trie = Trie::new();
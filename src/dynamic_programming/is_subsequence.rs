@@ -32,6 +32,14 @@ pub fn is_subsequence(sub: &str, main: &str) -> bool {
     true
 }
 
+/// Checks if `sub` is a subsequence of `main`, for slices of any items compared with `==`.
+///
+/// This is `is_subsequence` on sequences which are not strings, e.g. numbers or tokens.
+pub fn is_subsequence_slice<T: PartialEq>(sub: &[T], main: &[T]) -> bool {
+    let mut main_iter = main.iter();
+    sub.iter().all(|item| main_iter.any(|x| x == item))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +76,23 @@ mod tests {
         test_case_sensitive: ("aBc", "abc", false),
         test_subsequence_with_whitespace: ("hello world", "h e l l o   w o r l d", true),
     }
+
+    #[test]
+    fn slices_match_strings() {
+        for (sub, main) in [
+            ("", ""),
+            ("ace", "abcde"),
+            ("aec", "abcde"),
+            ("abcd", "abc"),
+        ] {
+            let sub_chars: Vec<char> = sub.chars().collect();
+            let main_chars: Vec<char> = main.chars().collect();
+            assert_eq!(
+                is_subsequence_slice(&sub_chars, &main_chars),
+                is_subsequence(sub, main)
+            );
+        }
+        assert!(is_subsequence_slice(&[1, 3], &[1, 2, 3]));
+        assert!(!is_subsequence_slice(&[3, 1], &[1, 2, 3]));
+    }
 }
@@ -1,14 +1,16 @@
 //! This module provides a function to find the length of the longest common substring
 //! between two strings using dynamic programming.
 
+use crate::string::longest_common_substring_pair;
+
 /// Finds the length of the longest common substring between two strings using dynamic programming.
 ///
-/// The algorithm uses a 2D dynamic programming table where each cell represents
-/// the length of the longest common substring ending at the corresponding indices in
-/// the two input strings. The maximum value in the DP table is the result, i.e., the
-/// length of the longest common substring.
+/// The substring itself is found by `string::longest_common_substring_pair`, whose table
+/// cells hold the length of the longest common substring ending at the corresponding
+/// indices in the two input strings, a single row being kept at a time.
 ///
-/// The time complexity is `O(n * m)`, where `n` and `m` are the lengths of the two strings.
+/// The time complexity is `O(n * m)`, where `n` and `m` are the lengths of the two strings,
+/// and the space complexity `O(m)`.
 /// # Arguments
 ///
 /// * `s1` - The first input string.
@@ -16,21 +18,9 @@
 ///
 /// # Returns
 ///
-/// Returns the length of the longest common substring between `s1` and `s2`.
+/// Returns the length, in chars, of the longest common substring between `s1` and `s2`.
 pub fn longest_common_substring(s1: &str, s2: &str) -> usize {
-    let mut substr_len = vec![vec![0; s2.len() + 1]; s1.len() + 1];
-    let mut max_len = 0;
-
-    s1.as_bytes().iter().enumerate().for_each(|(i, &c1)| {
-        s2.as_bytes().iter().enumerate().for_each(|(j, &c2)| {
-            if c1 == c2 {
-                substr_len[i + 1][j + 1] = substr_len[i][j] + 1;
-                max_len = max_len.max(substr_len[i + 1][j + 1]);
-            }
-        });
-    });
-
-    max_len
+    longest_common_substring_pair(s1, s2).chars().count()
 }
 
 #[cfg(test)]
@@ -66,5 +56,6 @@ mod tests {
         test_substring_with_repeated_chars: ("aaaaaaaaaaaaa", "aaa", 3),
         test_longer_strings_with_common_substring: ("OldSite:GeeksforGeeks.org", "NewSite:GeeksQuiz.com", 10),
         test_no_common_substring_with_special_chars: ("!!!", "???", 0),
+        test_multibyte_chars: ("日本語の文字", "英語の文字列", 4),
     }
 }
@@ -35,7 +35,7 @@ pub use self::fibonacci::{
 };
 pub use self::fractional_knapsack::fractional_knapsack;
 pub use self::integer_partition::partition;
-pub use self::is_subsequence::is_subsequence;
+pub use self::is_subsequence::{is_subsequence, is_subsequence_slice};
 pub use self::knapsack::knapsack;
 pub use self::longest_common_subsequence::longest_common_subsequence;
 pub use self::longest_common_substring::longest_common_substring;
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::collections::VecDeque;
+use std::ops::Range;
 use std::rc::{Rc, Weak};
 
 #[derive(Default)]
@@ -61,6 +62,14 @@ impl AhoCorasick {
     }
 
     pub fn search<'a>(&self, s: &'a str) -> Vec<&'a str> {
+        self.search_spans(s)
+            .into_iter()
+            .map(|span| &s[span])
+            .collect()
+    }
+
+    /// Same as `search`, but returns the byte range of each match in `s`
+    pub fn search_spans(&self, s: &str) -> Vec<Range<usize>> {
         let mut ans = vec![];
         let mut cur = Rc::clone(&self.root);
         let mut position: usize = 0;
@@ -78,7 +87,7 @@ impl AhoCorasick {
             }
             position += c.len_utf8();
             for &len in &cur.borrow().lengths {
-                ans.push(&s[position - len..position]);
+                ans.push(position - len..position);
             }
         }
         ans
@@ -129,4 +138,10 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_aho_corasick_spans() {
+        let ac = AhoCorasick::new(&["ab", "b中", "中"]);
+        assert_eq!(ac.search_spans("xab中"), [1..3, 2..6, 3..6]);
+    }
 }
@@ -25,6 +25,54 @@ pub fn check_anagram(s: &str, t: &str) -> Result<bool, AnagramError> {
     Ok(char_count(&s_cleaned) == char_count(&t_cleaned))
 }
 
+/// Checks if two strings are anagrams, comparing their characters as they are.
+///
+/// Unlike `check_anagram`, spaces, case and non-alphabetic characters are all significant.
+///
+/// # Arguments
+///
+/// * `s1` - First input string.
+/// * `s2` - Second input string.
+///
+/// # Returns
+///
+/// * `true` if `s2` is a permutation of the characters of `s1`, `false` otherwise.
+pub fn are_anagrams(s1: &str, s2: &str) -> bool {
+    let mut counts: HashMap<char, i64> = HashMap::new();
+    for c in s1.chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    for c in s2.chars() {
+        *counts.entry(c).or_insert(0) -= 1;
+    }
+    counts.values().all(|&count| count == 0)
+}
+
+/// Partitions a list of words into groups of anagrams, as defined by `are_anagrams`.
+///
+/// # Arguments
+///
+/// * `words` - The words to group.
+///
+/// # Returns
+///
+/// * The groups, in the order of the first appearance of one of their words,
+///   each one listing its words in input order.
+pub fn group_anagrams<'a>(words: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut groups: Vec<Vec<&'a str>> = vec![];
+    let mut group_of: HashMap<Vec<char>, usize> = HashMap::new();
+    for &word in words {
+        let mut key: Vec<char> = word.chars().collect();
+        key.sort_unstable();
+        let index = *group_of.entry(key).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        groups[index].push(word);
+    }
+    groups
+}
+
 /// Cleans the input string by removing spaces and converting to lowercase.
 /// Returns an error if any non-alphabetic character is found.
 ///
@@ -108,4 +156,43 @@ mod tests {
         invalid_anagram_with_symbols: ("check@anagram", "check@nagaram", Err(AnagramError::NonAlphabeticCharacter)),
         non_anagram_length_mismatch: ("abc", "abcd", Ok(false)),
     }
+
+    macro_rules! are_anagrams_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(are_anagrams($s1, $s2), $expected);
+                    assert_eq!(are_anagrams($s2, $s1), $expected);
+                }
+            )*
+        };
+    }
+
+    are_anagrams_tests! {
+        are_anagrams_empty: ("", "", true),
+        are_anagrams_simple: ("listen", "silent", true),
+        are_anagrams_repeated_chars: ("aab", "abb", false),
+        are_anagrams_case_sensitive: ("Listen", "silent", false),
+        are_anagrams_spaces_count: ("a b", "ab", false),
+        are_anagrams_symbols: ("a1!", "!1a", true),
+        are_anagrams_unicode: ("中文字", "字中文", true),
+        are_anagrams_length_mismatch: ("abc", "abcc", false),
+    }
+
+    #[test]
+    fn group_anagrams_in_order_of_appearance() {
+        let words = ["eat", "tea", "tan", "ate", "nat", "bat"];
+        assert_eq!(
+            group_anagrams(&words),
+            vec![vec!["eat", "tea", "ate"], vec!["tan", "nat"], vec!["bat"]]
+        );
+    }
+
+    #[test]
+    fn group_anagrams_edge_cases() {
+        assert!(group_anagrams(&[]).is_empty());
+        assert_eq!(group_anagrams(&["", ""]), vec![vec!["", ""]]);
+        assert_eq!(group_anagrams(&["ab", "Ab"]), vec![vec!["ab"], vec!["Ab"]]);
+    }
 }
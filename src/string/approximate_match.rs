@@ -0,0 +1,229 @@
+//! Approximate string matching: occurrences of a pattern with a few differences.
+//!
+//! `approximate_match` allows substitutions, i.e. windows of the text at Hamming distance
+//! at most k from the pattern. It runs the bitap (shift-and) algorithm: for each number of
+//! substitutions d from 0 to k, a bitmask holds the prefixes of the pattern ending at the
+//! current char of the text with at most d substitutions, and a char of the text updates
+//! every mask with a shift and a few bitwise operations. The masks have one bit per char of
+//! the pattern, so longer patterns than `usize::BITS` chars are compared window by window.
+//!
+//! `approximate_subsequence_match` allows gaps instead: the pattern is a subsequence of the
+//! text, with at most k chars of the text skipped between its first and last chars.
+//!
+//! Positions are counted in chars.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Bitap_algorithm)
+
+use std::collections::HashMap;
+
+/// Finds the windows of `text` differing from `pattern` by at most `k` substitutions
+///
+/// # Returns
+///
+/// The starting positions of the windows, in increasing order. An empty pattern has no
+/// occurrence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::approximate_match;
+///
+/// // "bat", "cat" and "cot" are at most one substitution away from "cat"
+/// assert_eq!(approximate_match("bat cat cot", "cat", 1), [0, 4, 8]);
+/// assert_eq!(approximate_match("bat cat cot", "cat", 0), [4]);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * k) time for a text of n chars and a pattern of at most `usize::BITS` chars,
+/// O(n * m) time for a longer pattern of m chars.
+pub fn approximate_match(text: &str, pattern: &str, k: usize) -> Vec<usize> {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return vec![];
+    }
+    if pattern.len() > usize::BITS as usize {
+        return hamming_windows(&text, &pattern, k);
+    }
+    bitap(&text, &pattern, k.min(pattern.len()))
+}
+
+// the shift-and algorithm with k substitutions, for a pattern of at most usize::BITS chars
+fn bitap(text: &[char], pattern: &[char], k: usize) -> Vec<usize> {
+    let m = pattern.len();
+    // the bit i of masks[c] is set when pattern[i] == c
+    let mut masks: HashMap<char, usize> = HashMap::new();
+    for (i, &c) in pattern.iter().enumerate() {
+        *masks.entry(c).or_insert(0) |= 1 << i;
+    }
+    let last = 1 << (m - 1);
+
+    // the bit i of states[d] is set when pattern[..=i] ends at the current char with at
+    // most d substitutions
+    let mut states = vec![0usize; k + 1];
+    let mut positions = vec![];
+    for (j, c) in text.iter().enumerate() {
+        let mask = masks.get(c).copied().unwrap_or(0);
+        // the state of the previous char with one substitution less
+        let mut previous: Option<usize> = None;
+        for state in &mut states {
+            let old = *state;
+            // a prefix extended by c, or by a substitution of c
+            *state = ((old << 1) | 1) & mask;
+            if let Some(previous) = previous {
+                *state |= (previous << 1) | 1;
+            }
+            previous = Some(old);
+        }
+        if states[k] & last != 0 {
+            positions.push(j + 1 - m);
+        }
+    }
+    positions
+}
+
+// the windows at Hamming distance at most k, compared char by char
+fn hamming_windows(text: &[char], pattern: &[char], k: usize) -> Vec<usize> {
+    text.windows(pattern.len())
+        .enumerate()
+        .filter(|(_, window)| window.iter().zip(pattern).filter(|(a, b)| a != b).count() <= k)
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// Finds the occurrences of `pattern` as a subsequence of `text`, with at most `k` chars of
+/// the text skipped between its first and last chars
+///
+/// # Returns
+///
+/// The positions where such an occurrence starts, in increasing order. An empty pattern
+/// has no occurrence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::approximate_subsequence_match;
+///
+/// // "c-a-t" skips two chars, "ca_t" one
+/// assert_eq!(approximate_subsequence_match("c-a-t ca_t", "cat", 1), [6]);
+/// assert_eq!(approximate_subsequence_match("c-a-t ca_t", "cat", 2), [0, 6]);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * (m + k)) time for a text of n chars and a pattern of m chars.
+pub fn approximate_subsequence_match(text: &str, pattern: &str, k: usize) -> Vec<usize> {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    if pattern.is_empty() {
+        return vec![];
+    }
+    (0..text.len())
+        .filter(|&start| {
+            // matching each char of the pattern as early as possible skips the fewest chars
+            let mut skipped = 0;
+            let mut rest = text[start..].iter();
+            text[start] == pattern[0]
+                && pattern.iter().all(|c| {
+                    for x in rest.by_ref() {
+                        if x == c {
+                            return true;
+                        }
+                        skipped += 1;
+                        if skipped > k {
+                            return false;
+                        }
+                    }
+                    false
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_match(text: &str, pattern: &str, k: usize) -> Vec<usize> {
+        let text: Vec<char> = text.chars().collect();
+        let pattern: Vec<char> = pattern.chars().collect();
+        if pattern.is_empty() || pattern.len() > text.len() {
+            return vec![];
+        }
+        hamming_windows(&text, &pattern, k)
+    }
+
+    macro_rules! approximate_match_tests {
+        ($($name:ident: ($text:expr, $pattern:expr, $k:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (text, pattern, k): (&str, &str, usize) = ($text, $pattern, $k);
+                    let expected: Vec<usize> = $expected;
+                    assert_eq!(approximate_match(text, pattern, k), expected);
+                    assert_eq!(naive_match(text, pattern, k), expected);
+                }
+            )*
+        };
+    }
+
+    approximate_match_tests! {
+        exact: ("abracadabra", "abra", 0, vec![0, 7]),
+        one_substitution: ("abracadabra", "abra", 1, vec![0, 7]),
+        two_substitutions: ("abracadabra", "abra", 2, vec![0, 7]),
+        three_substitutions: ("abracadabra", "abra", 3, vec![0, 2, 3, 4, 5, 7]),
+        everything_matches: ("abcd", "xy", 2, vec![0, 1, 2]),
+        more_substitutions_than_chars: ("abc", "xy", 5, vec![0, 1]),
+        pattern_longer_than_text: ("ab", "abc", 1, vec![]),
+        empty_pattern: ("abc", "", 1, vec![]),
+        empty_text: ("", "a", 0, vec![]),
+        unicode: ("串用于测试串用", "串用", 0, vec![0, 5]),
+        unicode_substitution: ("串用于测试串用", "串于", 1, vec![0, 1, 5]),
+    }
+
+    #[test]
+    fn matches_the_naive_comparison() {
+        let text = "the quick brown fox jumps over the lazy dog, then the fox sleeps";
+        for pattern in ["the", "fox", "o", " the ", "dog, then", "zzz", "quack"] {
+            for k in 0..4 {
+                assert_eq!(
+                    approximate_match(text, pattern, k),
+                    naive_match(text, pattern, k),
+                    "{pattern} {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn long_patterns() {
+        let text = "ab".repeat(100);
+        let pattern = "ab".repeat(40);
+        let mut altered = pattern.clone();
+        altered.replace_range(0..1, "x");
+        assert_eq!(approximate_match(&text, &pattern, 0).len(), 61);
+        assert_eq!(approximate_match(&text, &altered, 0), []);
+        assert_eq!(approximate_match(&text, &altered, 1).len(), 61);
+        // the longest pattern the bitmasks hold
+        let pattern = "ab".repeat(usize::BITS as usize / 2);
+        assert_eq!(
+            approximate_match(&text, &pattern, 1),
+            naive_match(&text, &pattern, 1)
+        );
+    }
+
+    #[test]
+    fn subsequences() {
+        let text = "c-a-t ca_t cat";
+        assert_eq!(approximate_subsequence_match(text, "cat", 0), [11]);
+        assert_eq!(approximate_subsequence_match(text, "cat", 1), [6, 11]);
+        assert_eq!(approximate_subsequence_match(text, "cat", 2), [0, 6, 11]);
+        // the occurrence starting at 0 may also end at the 't' of "ca_t"
+        assert_eq!(approximate_subsequence_match(text, "ct", 9), [0, 6, 11]);
+        assert_eq!(approximate_subsequence_match(text, "", 1), []);
+        assert_eq!(approximate_subsequence_match("", "a", 1), []);
+        assert_eq!(approximate_subsequence_match("串-用于", "串用", 1), [0]);
+        assert_eq!(approximate_subsequence_match("串-用于", "串用", 0), []);
+    }
+}
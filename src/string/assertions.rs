@@ -0,0 +1,7 @@
+//! Assertions shared by the tests of the module.
+
+/// Asserts that two floats are equal up to rounding errors, i.e. less than 1e-9 apart
+#[track_caller]
+pub fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
@@ -0,0 +1,174 @@
+//! Multiple longest common subsequences of many independent groups of strings, e.g. the
+//! consensus of each family of sequences once they are grouped by family.
+//!
+//! Each group is solved on its own `Context`, so nothing is shared between the groups. With
+//! the `rayon` feature, the groups are solved in parallel, the results keeping the order of
+//! the groups.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::multiple_longest_common_subsequence::{prepare_chains, to_chains, Prepared};
+use super::{multiple_longest_common_subsequence, MlcsConfig, MlcsError};
+
+/// Finds a longest common subsequence of each group of strings
+///
+/// # Returns
+///
+/// The subsequence of each group, in the order of `groups`, as given by
+/// `multiple_longest_common_subsequence`.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_batch;
+///
+/// let groups = vec![vec!["ABC", "AC", "BAC"], vec!["abcd", "bcd"], vec![]];
+/// assert_eq!(mlcs_batch(&groups), ["AC", "bcd", ""]);
+/// ```
+pub fn mlcs_batch(groups: &[Vec<&str>]) -> Vec<String> {
+    #[cfg(feature = "rayon")]
+    let groups = groups.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let groups = groups.iter();
+    groups.map(multiple_longest_common_subsequence).collect()
+}
+
+/// Same as `mlcs_batch`, each group being searched with `config`
+///
+/// The limits of `config` apply to each group on its own: a group reaching its
+/// `max_nodes` or `timeout` does not stop the others.
+///
+/// # Errors
+///
+/// `MlcsError::Interrupted` for the groups whose search reached a limit of `config`,
+/// holding the best subsequence of the group found until then. The groups answered without
+/// a search, e.g. of equal strings, never are.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{mlcs_batch_with_config, MlcsConfig, MlcsError};
+///
+/// let groups = vec![vec!["ABCBDAB", "BDCABA"], vec!["abcd", "bcd"]];
+/// let config = MlcsConfig::default();
+/// assert_eq!(
+///     mlcs_batch_with_config(&groups, &config),
+///     [Ok("BCBA".to_string()), Ok("bcd".to_string())]
+/// );
+///
+/// let config = MlcsConfig {
+///     max_nodes: Some(0),
+///     ..MlcsConfig::default()
+/// };
+/// assert!(matches!(
+///     mlcs_batch_with_config(&groups, &config)[0],
+///     Err(MlcsError::Interrupted { .. })
+/// ));
+/// ```
+pub fn mlcs_batch_with_config(
+    groups: &[Vec<&str>],
+    config: &MlcsConfig,
+) -> Vec<Result<String, MlcsError>> {
+    let solve = |group: &Vec<&str>| match prepare_chains(to_chains(group)) {
+        Prepared::Solved(mlcs) => Ok(mlcs.into_iter().collect()),
+        Prepared::Search(mut ctx) => {
+            let (subsequence, stats) = ctx.solve_with_stats(config);
+            if stats.interrupted {
+                Err(MlcsError::Interrupted { subsequence })
+            } else {
+                Ok(subsequence)
+            }
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    let groups = groups.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let groups = groups.iter();
+    groups.map(solve).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn groups() -> Vec<Vec<&'static str>> {
+        vec![
+            vec!["ABCBDAB", "BDCABA", "BCDAAB"],
+            vec!["串用于测试", "用于串测试"],
+            vec!["abc", "def"],
+            vec!["single"],
+            vec![],
+            vec!["", ""],
+            vec!["gxtxayb", "abgtab", "gyaytahjb", "gyayjjjtab"],
+        ]
+    }
+
+    #[test]
+    fn each_group_is_solved_on_its_own() {
+        let groups = groups();
+        let results = mlcs_batch(&groups);
+        assert_eq!(results.len(), groups.len());
+        for (group, result) in groups.iter().zip(&results) {
+            assert_eq!(*result, multiple_longest_common_subsequence(group));
+        }
+        assert!(mlcs_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn with_the_default_config() {
+        let groups = groups();
+        let expected: Vec<Result<String, MlcsError>> =
+            mlcs_batch(&groups).into_iter().map(Ok).collect();
+        assert_eq!(
+            mlcs_batch_with_config(&groups, &MlcsConfig::default()),
+            expected
+        );
+    }
+
+    #[test]
+    fn interrupted_groups() {
+        let groups = groups();
+        let config = MlcsConfig {
+            max_nodes: Some(0),
+            ..MlcsConfig::default()
+        };
+        for (group, result) in groups.iter().zip(mlcs_batch_with_config(&groups, &config)) {
+            // the groups answered without a search are not interrupted
+            match result {
+                Ok(subsequence) => assert_eq!(
+                    subsequence,
+                    multiple_longest_common_subsequence(group),
+                    "{group:?}"
+                ),
+                Err(MlcsError::Interrupted { subsequence }) => {
+                    assert!(subsequence.chars().count() <= group[0].chars().count());
+                }
+                Err(err) => panic!("{group:?} gave {err}"),
+            }
+        }
+
+        let config = MlcsConfig {
+            timeout: Some(Duration::ZERO),
+            ..MlcsConfig::default()
+        };
+        let results = mlcs_batch_with_config(&groups, &config);
+        assert!(matches!(results[0], Err(MlcsError::Interrupted { .. })));
+    }
+
+    #[test]
+    fn equal_strings_need_no_search() {
+        let long = "ab".repeat(1500);
+        let groups = vec![vec![long.as_str(); 3], vec!["abc", "abc"]];
+        let config = MlcsConfig {
+            max_nodes: Some(0),
+            ..MlcsConfig::default()
+        };
+        assert_eq!(
+            mlcs_batch_with_config(&groups, &config),
+            [Ok(long), Ok("abc".to_string())]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Boyer-Moore-Horspool string search on bytes.
+//!
+//! Horspool simplifies Boyer-Moore by keeping only a bad character table, indexed by the
+//! byte of the text aligned with the last byte of the pattern: whether the window matches
+//! or not, the pattern is shifted so that this byte meets its last occurrence in the
+//! pattern, its last byte excluded, or entirely past it when it does not occur.
+//! On natural language texts, most shifts skip nearly the whole pattern.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore%E2%80%93Horspool_algorithm)
+
+/// Finds all occurrences of `pattern` in `text`
+///
+/// # Returns
+///
+/// The byte offsets of the occurrences, overlapping ones included, in increasing order.
+/// An empty pattern has no occurrence.
+///
+/// # Complexity
+///
+/// O(n / m) comparisons at best and O(n * m) at worst, for a text of n bytes and a
+/// pattern of m bytes, after an O(m + 256) preprocessing.
+pub fn bmh_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    let mut positions = vec![];
+    if m == 0 || m > text.len() {
+        return positions;
+    }
+
+    // the shift aligning each byte with its last occurrence in pattern[..m - 1]
+    let mut shifts = [m; 256];
+    for (i, &b) in pattern[..m - 1].iter().enumerate() {
+        shifts[b as usize] = m - 1 - i;
+    }
+
+    let mut start = 0;
+    while start + m <= text.len() {
+        let last = text[start + m - 1];
+        if last == pattern[m - 1] && text[start..start + m - 1] == pattern[..m - 1] {
+            positions.push(start);
+        }
+        start += shifts[last as usize];
+    }
+    positions
+}
+
+/// Same as `bmh_search`, on ASCII strings
+///
+/// Offsets of bytes and of chars are the same in ASCII strings. Other strings are
+/// rejected, as a byte offset inside a multi-byte char would not be a valid position:
+/// `bmh_search` on their bytes still finds their occurrences as byte offsets.
+///
+/// # Panics
+///
+/// Panics if `text` or `pattern` is not ASCII.
+pub fn bmh_search_str(text: &str, pattern: &str) -> Vec<usize> {
+    assert!(text.is_ascii(), "the text is not ASCII");
+    assert!(pattern.is_ascii(), "the pattern is not ASCII");
+    bmh_search(text.as_bytes(), pattern.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        if pattern.is_empty() {
+            return vec![];
+        }
+        text.windows(pattern.len())
+            .enumerate()
+            .filter(|(_, window)| *window == pattern)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    macro_rules! bmh_tests {
+        ($($name:ident: ($text:expr, $pattern:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (text, pattern): (&str, &str) = ($text, $pattern);
+                    let expected: Vec<usize> = $expected;
+                    assert_eq!(bmh_search_str(text, pattern), expected);
+                    assert_eq!(naive_search(text.as_bytes(), pattern.as_bytes()), expected);
+                }
+            )*
+        };
+    }
+
+    bmh_tests! {
+        simple_match: ("AABCAB12AFAABCABFFEGABCAB", "ABCAB", vec![1, 11, 20]),
+        no_match: ("AABCAB12AFAABCABFFEGABCAB", "FFF", vec![]),
+        overlapping: ("AAAAA", "AAA", vec![0, 1, 2]),
+        single_byte: ("abcabc", "c", vec![2, 5]),
+        whole_text: ("pattern", "pattern", vec![0]),
+        pattern_longer_than_text: ("ABC", "ABCDEFG", vec![]),
+        empty_pattern: ("ABC", "", vec![]),
+        empty_text: ("", "A", vec![]),
+        last_byte_elsewhere_in_pattern: ("xxabcbcabx", "bcab", vec![5]),
+        periodic_pattern: ("abababab", "abab", vec![0, 2, 4]),
+    }
+
+    #[test]
+    fn bytes() {
+        let text = [0u8, 255, 0, 255, 255, 0];
+        assert_eq!(bmh_search(&text, &[255, 0]), [1, 4]);
+        let (text, pattern) = ("串用串", "串");
+        assert_eq!(bmh_search(text.as_bytes(), pattern.as_bytes()), [0, 6]);
+    }
+
+    #[test]
+    fn matches_naive_search() {
+        let text = b"the quick brown fox jumps over the lazy dog, then the fox sleeps";
+        for pattern in [
+            &b"the"[..],
+            b"fox",
+            b"o",
+            b" the ",
+            b"dog, then",
+            b"zzz",
+            b"s",
+        ] {
+            assert_eq!(bmh_search(text, pattern), naive_search(text, pattern));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the text is not ASCII")]
+    fn non_ascii_text() {
+        bmh_search_str("串用", "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "the pattern is not ASCII")]
+    fn non_ascii_pattern() {
+        bmh_search_str("abc", "é");
+    }
+}
@@ -1,7 +1,7 @@
 //! This module implements the Boyer-Moore string search algorithm, an efficient method
 //! for finding all occurrences of a pattern within a given text. The algorithm skips
 //! sections of the text by leveraging two key rules: the bad character rule and the
-//! good suffix rule (only the bad character rule is implemented here for simplicity).
+//! good suffix rule, the pattern being shifted by the larger of the two shifts.
 
 use std::collections::HashMap;
 
@@ -22,6 +22,51 @@ fn build_bad_char_table(pat: &[char]) -> HashMap<char, isize> {
     bad_char_table
 }
 
+/// Builds the good suffix table for the Boyer-Moore algorithm.
+/// When the suffix `pat[j..]` matched and `pat[j - 1]` did not, the pattern can be shifted
+/// by `table[j]`: up to the previous occurrence of that suffix preceded by another char,
+/// or else up to the longest prefix of the pattern which is also a suffix of `pat[j..]`.
+///
+/// # Arguments
+/// * `pat` - The pattern as a slice of characters.
+///
+/// # Returns
+/// The shifts, `table[0]` being the shift after a full match.
+fn build_good_suffix_table(pat: &[char]) -> Vec<usize> {
+    let m = pat.len();
+    let mut table = vec![0; m + 1];
+    // border[i] is the start of the widest border of pat[i..]
+    let mut border = vec![0; m + 1];
+
+    // the suffixes occurring elsewhere in the pattern
+    let mut i = m;
+    let mut j = m + 1;
+    border[i] = j;
+    while i > 0 {
+        while j <= m && pat[i - 1] != pat[j - 1] {
+            if table[j] == 0 {
+                table[j] = j - i;
+            }
+            j = border[j];
+        }
+        i -= 1;
+        j -= 1;
+        border[i] = j;
+    }
+
+    // the other suffixes, shifted up to the widest border of the pattern they contain
+    let mut j = border[0];
+    for (i, shift) in table.iter_mut().enumerate() {
+        if *shift == 0 {
+            *shift = j;
+        }
+        if i == j {
+            j = border[j];
+        }
+    }
+    table
+}
+
 /// Calculates the shift when a full match occurs in the Boyer-Moore algorithm.
 /// It uses the bad character table to determine how much to shift the pattern.
 ///
@@ -85,6 +130,10 @@ fn calc_mismatch_shift(
 pub fn boyer_moore_search(text: &str, pat: &str) -> Vec<usize> {
     let mut positions = Vec::new();
 
+    // Convert text and pattern to character vectors for easier indexing
+    let pat: Vec<char> = pat.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    // the lengths in chars, which differ from the lengths in bytes outside of ASCII
     let text_len = text.len() as isize;
     let pat_len = pat.len() as isize;
 
@@ -93,12 +142,9 @@ pub fn boyer_moore_search(text: &str, pat: &str) -> Vec<usize> {
         return positions;
     }
 
-    // Convert text and pattern to character vectors for easier indexing
-    let pat: Vec<char> = pat.chars().collect();
-    let text: Vec<char> = text.chars().collect();
-
-    // Build the bad character table for the pattern
+    // Build the bad character and good suffix tables for the pattern
     let bad_char_table = build_bad_char_table(&pat);
+    let good_suffix_table = build_good_suffix_table(&pat);
 
     let mut shift = 0;
 
@@ -114,10 +160,16 @@ pub fn boyer_moore_search(text: &str, pat: &str) -> Vec<usize> {
         // If we found a match (j < 0), record the position
         if j < 0 {
             positions.push(shift as usize);
-            shift += calc_match_shift(shift, pat_len, text_len, &bad_char_table, &text);
+            shift += std::cmp::max(
+                calc_match_shift(shift, pat_len, text_len, &bad_char_table, &text),
+                good_suffix_table[0] as isize,
+            );
         } else {
-            // If mismatch, calculate how far to shift based on the bad character rule
-            shift += calc_mismatch_shift(j, shift, &text, &bad_char_table);
+            // If mismatch, shift by the larger of the bad character and good suffix shifts
+            shift += std::cmp::max(
+                calc_mismatch_shift(j, shift, &text, &bad_char_table),
+                good_suffix_table[(j + 1) as usize] as isize,
+            );
         }
     }
 
@@ -157,5 +209,28 @@ mod tests {
         test_partial_overlap_no_match: ("ABCD", "ABCDE", vec![]),
         test_single_occurrence: ("XXXXXXXXXXXXXXXXXXPATTERNXXXXXXXXXXXXXXXXXX", "PATTERN", vec![18]),
         test_single_occurrence_with_noise: ("PATPATPATPATTERNPAT", "PATTERN", vec![9]),
+        test_periodic_pattern: ("abababab", "abab", vec![0, 2, 4]),
+        test_repeated_suffix: ("xxabcbcabxbcab", "bcab", vec![5, 10]),
+        test_unicode: ("串用串用于串用", "串用", vec![0, 2, 5]),
+    }
+
+    #[test]
+    fn good_suffix_table() {
+        let pat: Vec<char> = "ABBABAB".chars().collect();
+        assert_eq!(build_good_suffix_table(&pat), [5, 5, 5, 5, 2, 5, 4, 1]);
+        let pat: Vec<char> = "aaaa".chars().collect();
+        // a mismatch means that the text char is not an a, which the pattern must skip
+        assert_eq!(build_good_suffix_table(&pat), [1, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn matches_naive_search() {
+        let text = "abaababaabaababaababaabaabab";
+        for pat in ["aba", "abaab", "baba", "ab", "aabaa", "bb", "abaababaab"] {
+            let expected: Vec<usize> = (0..=text.len() - pat.len())
+                .filter(|&i| text[i..].starts_with(pat))
+                .collect();
+            assert_eq!(boyer_moore_search(text, pat), expected, "{pat}");
+        }
     }
 }
@@ -0,0 +1,260 @@
+//! Hierarchical clustering of labelled items by UPGMA, and the Newick format of the tree.
+//!
+//! UPGMA (unweighted pair group method with arithmetic mean) starts with one cluster per
+//! item and merges the two closest clusters until one is left. The distance between the
+//! merged cluster and any other one is the average distance between their items, i.e. the
+//! average of the distances of the two merged clusters weighted by their sizes. Each merge
+//! is an internal node of the tree, at a height of half the distance of the clusters: the
+//! tree is rooted and all its leaves are at the same depth, as the tree of species evolving
+//! at a constant rate.
+//!
+//! The distances may be those of strings, e.g. the number of chars left out of their
+//! LCS, computed from `pairwise_similarity_matrix`.
+//!
+//! References: [UPGMA](https://en.wikipedia.org/wiki/UPGMA),
+//! [Newick format](https://en.wikipedia.org/wiki/Newick_format)
+
+use std::fmt::Write;
+
+/// A binary tree of labelled leaves
+#[derive(Clone, Debug, PartialEq)]
+pub enum NewickTree {
+    Leaf(String),
+    /// The merge of two clusters, `distance` apart
+    Internal {
+        left: Box<NewickTree>,
+        right: Box<NewickTree>,
+        distance: f64,
+    },
+}
+
+impl NewickTree {
+    // the height of the node, half the distance between its clusters, 0 for a leaf
+    fn height(&self) -> f64 {
+        match self {
+            NewickTree::Leaf(_) => 0.0,
+            NewickTree::Internal { distance, .. } => distance / 2.0,
+        }
+    }
+}
+
+/// Clusters the items `labels` by UPGMA, `distance_matrix[i][j]` being the distance between
+/// the i-th and the j-th item
+///
+/// The matrix should be symmetric. Among clusters at the same distance, the first pair by
+/// index of their first items is merged, the cluster of the smaller index on the left.
+///
+/// # Panics
+///
+/// Panics if there is no label, or if the matrix does not have a row and a column for each
+/// label.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{newick_to_string, upgma};
+///
+/// let distances = vec![
+///     vec![0.0, 2.0, 3.0],
+///     vec![2.0, 0.0, 3.0],
+///     vec![3.0, 3.0, 0.0],
+/// ];
+/// let tree = upgma(&distances, &["A", "B", "C"]);
+/// assert_eq!(newick_to_string(&tree), "((A:1.0,B:1.0):0.5,C:1.5)");
+/// ```
+///
+/// # Complexity
+///
+/// O(n^3) time and O(n^2) space, for n labels.
+pub fn upgma(distance_matrix: &[Vec<f64>], labels: &[&str]) -> NewickTree {
+    let n = labels.len();
+    assert!(n > 0, "no label to cluster");
+    assert!(
+        distance_matrix.len() == n && distance_matrix.iter().all(|row| row.len() == n),
+        "the distance matrix is not {n} by {n}"
+    );
+    let mut distances = distance_matrix.to_vec();
+    // each cluster with its number of items, None once merged into another one
+    let mut clusters: Vec<Option<(NewickTree, usize)>> = labels
+        .iter()
+        .map(|label| Some((NewickTree::Leaf(label.to_string()), 1)))
+        .collect();
+
+    for _ in 1..n {
+        let (a, b) = closest_pair(&clusters, &distances);
+        let (left, size_a) = clusters[a].take().expect("the closest clusters are left");
+        let (right, size_b) = clusters[b].take().expect("the closest clusters are left");
+        let (weight_a, weight_b) = (size_a as f64, size_b as f64);
+        for k in (0..n).filter(|&k| clusters[k].is_some()) {
+            let average =
+                (distances[a][k] * weight_a + distances[b][k] * weight_b) / (weight_a + weight_b);
+            distances[a][k] = average;
+            distances[k][a] = average;
+        }
+        let merged = NewickTree::Internal {
+            left: Box::new(left),
+            right: Box::new(right),
+            distance: distances[a][b],
+        };
+        clusters[a] = Some((merged, size_a + size_b));
+    }
+    clusters
+        .into_iter()
+        .flatten()
+        .next()
+        .expect("a cluster is left")
+        .0
+}
+
+// the two clusters left at the smallest distance, the first pair by index among equal ones
+fn closest_pair<T>(clusters: &[Option<T>], distances: &[Vec<f64>]) -> (usize, usize) {
+    let left: Vec<usize> = (0..clusters.len())
+        .filter(|&i| clusters[i].is_some())
+        .collect();
+    let mut closest = (left[0], left[1]);
+    for (k, &i) in left.iter().enumerate() {
+        for &j in &left[k + 1..] {
+            if distances[i][j] < distances[closest.0][closest.1] {
+                closest = (i, j);
+            }
+        }
+    }
+    closest
+}
+
+/// Writes `tree` in the Newick format, each subtree followed by the length of the branch
+/// above it
+///
+/// The length of a branch is the difference between the heights of its two ends, the root
+/// having no branch. The labels are written as they are, without quoting the chars the
+/// format reserves, such as `'('`, `':'` or `','`, and no `';'` ends the tree.
+pub fn newick_to_string(tree: &NewickTree) -> String {
+    let mut newick = String::new();
+    write_subtree(tree, &mut newick);
+    newick
+}
+
+fn write_subtree(tree: &NewickTree, newick: &mut String) {
+    match tree {
+        NewickTree::Leaf(label) => newick.push_str(label),
+        NewickTree::Internal { left, right, .. } => {
+            newick.push('(');
+            write_subtree(left, newick);
+            write!(newick, ":{:?},", tree.height() - left.height()).unwrap();
+            write_subtree(right, newick);
+            write!(newick, ":{:?})", tree.height() - right.height()).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::pairwise_similarity_matrix;
+
+    fn leaf(label: &str) -> Box<NewickTree> {
+        Box::new(NewickTree::Leaf(label.to_string()))
+    }
+
+    macro_rules! upgma_tests {
+        ($($name:ident: ($distances:expr, $labels:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let distances: Vec<Vec<f64>> = $distances.iter().map(|row| row.to_vec()).collect();
+                    let tree = upgma(&distances, &$labels);
+                    assert_eq!(newick_to_string(&tree), $expected);
+                }
+            )*
+        };
+    }
+
+    upgma_tests! {
+        single: ([[0.0]], ["A"], "A"),
+        pair: ([[0.0, 4.0], [4.0, 0.0]], ["A", "B"], "(A:2.0,B:2.0)"),
+        three: (
+            [[0.0, 2.0, 3.0], [2.0, 0.0, 3.0], [3.0, 3.0, 0.0]],
+            ["A", "B", "C"],
+            "((A:1.0,B:1.0):0.5,C:1.5)"
+        ),
+        // the closest pair is not the first one
+        closest_last: (
+            [[0.0, 6.0, 8.0], [6.0, 0.0, 2.0], [8.0, 2.0, 0.0]],
+            ["A", "B", "C"],
+            "(A:3.5,(B:1.0,C:1.0):2.5)"
+        ),
+        // A and B, then C and D, are merged before the two pairs
+        two_pairs: (
+            [
+                [0.0, 2.0, 6.0, 6.0],
+                [2.0, 0.0, 6.0, 6.0],
+                [6.0, 6.0, 0.0, 4.0],
+                [6.0, 6.0, 4.0, 0.0],
+            ],
+            ["A", "B", "C", "D"],
+            "((A:1.0,B:1.0):2.0,(C:2.0,D:2.0):1.0)"
+        ),
+        ties: (
+            [[0.0, 1.0, 1.0], [1.0, 0.0, 1.0], [1.0, 1.0, 0.0]],
+            ["A", "B", "C"],
+            "((A:0.5,B:0.5):0.0,C:0.5)"
+        ),
+    }
+
+    #[test]
+    fn average_weighted_by_the_sizes() {
+        // the cluster of A, B and C is at (9 + 9 + 12) / 3 = 10 from D
+        let distances = vec![
+            vec![0.0, 2.0, 2.0, 9.0],
+            vec![2.0, 0.0, 2.0, 9.0],
+            vec![2.0, 2.0, 0.0, 12.0],
+            vec![9.0, 9.0, 12.0, 0.0],
+        ];
+        let tree = upgma(&distances, &["A", "B", "C", "D"]);
+        let abc = NewickTree::Internal {
+            left: Box::new(NewickTree::Internal {
+                left: leaf("A"),
+                right: leaf("B"),
+                distance: 2.0,
+            }),
+            right: leaf("C"),
+            distance: 2.0,
+        };
+        let expected = NewickTree::Internal {
+            left: Box::new(abc),
+            right: leaf("D"),
+            distance: 10.0,
+        };
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn strings_by_their_lcs_distances() {
+        let strings = ["GATTACA", "GATACA", "CATTAC", "TTTTT"];
+        let lcs = pairwise_similarity_matrix(&strings);
+        let distances: Vec<Vec<f64>> = (0..strings.len())
+            .map(|i| {
+                (0..strings.len())
+                    .map(|j| (lcs[i][i] + lcs[j][j] - 2 * lcs[i][j]) as f64)
+                    .collect()
+            })
+            .collect();
+        let tree = upgma(&distances, &strings);
+        assert_eq!(
+            newick_to_string(&tree),
+            "(((GATTACA:0.5,GATACA:0.5):1.25,CATTAC:1.75):2.25,TTTTT:4.0)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no label to cluster")]
+    fn no_label() {
+        upgma(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the distance matrix is not 2 by 2")]
+    fn matrix_of_the_wrong_size() {
+        upgma(&[vec![0.0, 1.0]], &["A", "B"]);
+    }
+}
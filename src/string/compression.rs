@@ -0,0 +1,232 @@
+//! Estimates of how much a string would shrink if it were compressed, e.g. to decide
+//! whether the inputs of an MLCS search are repetitive enough to be collapsed first (see
+//! `mlcs_collapse_runs`), or to tell random inputs from structured ones.
+//!
+//! None of these compresses anything, they only count what a compressor would store:
+//! * the runs of identical chars, for run-length encoding
+//! * the phrases of the Lempel-Ziv (LZ78) parsing, each phrase being the shortest prefix of
+//!   the rest of the string which is not an earlier phrase; this is the base of the LZ
+//!   family of compressors, gzip included
+//! * the Shannon entropy of the bigrams, the number of bits per char an encoder knowing the
+//!   frequencies of the pairs of consecutive chars needs
+//!
+//! Lengths are counted in chars, except the raw size of `ZlibEstimate`, in bytes.
+//!
+//! References: [Lempel-Ziv complexity](https://en.wikipedia.org/wiki/Lempel-Ziv_complexity),
+//! [LZ78](https://en.wikipedia.org/wiki/LZ77_and_LZ78),
+//! [Entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory))
+
+use std::collections::{HashMap, HashSet};
+
+/// The way `compression_ratio` estimates the compressed size of a string
+///
+/// # Variants
+/// * `RunLength` - the number of runs of identical chars, divided by the number of chars
+/// * `LzComplexity` - the number of phrases of the LZ78 parsing, divided by the number of
+///   chars
+/// * `ZlibEstimate` - the bits per char given by the entropy of the bigrams, times the
+///   number of chars, divided by the bits of the string in UTF-8
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionMethod {
+    RunLength,
+    LzComplexity,
+    ZlibEstimate,
+}
+
+/// Estimates the size of `s` once compressed by `method`, relative to its size
+///
+/// # Returns
+///
+/// A ratio in [0, 1], the lower the more compressible, 0 for an empty string.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{compression_ratio, CompressionMethod};
+///
+/// assert_eq!(compression_ratio("aaaabbbb", CompressionMethod::RunLength), 0.25);
+/// // a|b|ab|aba|ba|bab
+/// assert_eq!(compression_ratio("abababababab", CompressionMethod::LzComplexity), 0.5);
+/// // a single bigram repeated over and over carries no information
+/// assert_eq!(compression_ratio("aaaaaaaa", CompressionMethod::ZlibEstimate), 0.0);
+/// ```
+///
+/// # Complexity
+///
+/// O(n) expected time for a string of n chars.
+pub fn compression_ratio(s: &str, method: CompressionMethod) -> f64 {
+    let n = s.chars().count();
+    if n == 0 {
+        return 0.0;
+    }
+    match method {
+        CompressionMethod::RunLength => runs(s) as f64 / n as f64,
+        CompressionMethod::LzComplexity => lz_complexity(s) as f64 / n as f64,
+        CompressionMethod::ZlibEstimate => {
+            bigram_entropy(s) / 2.0 * n as f64 / (8 * s.len()) as f64
+        }
+    }
+}
+
+/// Approximates the Kolmogorov complexity of `s` by its normalized Lempel-Ziv complexity
+///
+/// The number of phrases of the LZ78 parsing of a random string of n chars over k letters
+/// tends to n / log_k(n), so the number of phrases of `s` divided by this bound is close
+/// to 1 for random strings, and lower for repetitive ones, going down to 0 as they grow.
+/// With a single letter, k is taken as 2.
+///
+/// # Returns
+///
+/// The normalized complexity, 0 for an empty string. It may exceed 1 on short strings.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::kolmogorov_approximation;
+///
+/// // 26 phrases of one letter, as many as the bound
+/// assert_eq!(kolmogorov_approximation("tmbqxkfwzjcroeuvdgayslphin"), 1.0);
+/// // 45 phrases of up to 44 letters, against a bound of about 100
+/// assert!(kolmogorov_approximation(&"a".repeat(1000)) < 0.5);
+/// ```
+pub fn kolmogorov_approximation(s: &str) -> f64 {
+    let n = s.chars().count();
+    if n == 0 {
+        return 0.0;
+    }
+    let letters = s.chars().collect::<HashSet<char>>().len().max(2);
+    let bound = n as f64 / (n as f64).log(letters as f64).max(1.0);
+    lz_complexity(s) as f64 / bound
+}
+
+// the number of runs of identical chars
+fn runs(s: &str) -> usize {
+    let mut chars = s.chars().peekable();
+    let mut runs = 0;
+    while let Some(c) = chars.next() {
+        while chars.next_if_eq(&c).is_some() {}
+        runs += 1;
+    }
+    runs
+}
+
+// the number of phrases of the LZ78 parsing, the last one being counted even when it
+// repeats an earlier phrase
+fn lz_complexity(s: &str) -> usize {
+    // the phrases form a trie, a phrase being an earlier phrase followed by a char;
+    // the node 0 is the empty phrase
+    let mut trie: HashMap<(usize, char), usize> = HashMap::new();
+    let mut node = 0;
+    for c in s.chars() {
+        match trie.get(&(node, c)) {
+            Some(&next) => node = next,
+            None => {
+                let phrase = trie.len() + 1;
+                trie.insert((node, c), phrase);
+                node = 0;
+            }
+        }
+    }
+    trie.len() + usize::from(node != 0)
+}
+
+// the Shannon entropy, in bits, of the bigrams of s, 0 without bigram
+fn bigram_entropy(s: &str) -> f64 {
+    let chars: Vec<char> = s.chars().collect();
+    let mut counts: HashMap<(char, char), usize> = HashMap::new();
+    for pair in chars.windows(2) {
+        *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+    let total = chars.len().saturating_sub(1) as f64;
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::assertions::assert_close;
+    use crate::string::random_strings::Xorshift;
+
+    macro_rules! ratio_tests {
+        ($($name:ident: ($s:expr, $method:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_close(compression_ratio($s, $method), $expected);
+                }
+            )*
+        };
+    }
+
+    ratio_tests! {
+        run_length: ("aaabbbcc", CompressionMethod::RunLength, 3.0 / 8.0),
+        run_length_without_runs: ("abcd", CompressionMethod::RunLength, 1.0),
+        run_length_unicode: ("串串用用用", CompressionMethod::RunLength, 2.0 / 5.0),
+        lz_single_letter: ("aaaaaa", CompressionMethod::LzComplexity, 3.0 / 6.0),
+        lz_last_phrase_repeated: ("aaaa", CompressionMethod::LzComplexity, 3.0 / 4.0),
+        lz_distinct_chars: ("abcd", CompressionMethod::LzComplexity, 1.0),
+        lz_unicode: ("串用串用串", CompressionMethod::LzComplexity, 4.0 / 5.0),
+        zlib_single_bigram: ("aaaaaa", CompressionMethod::ZlibEstimate, 0.0),
+        // ab, ba, ab: 0.918 bits per bigram, half of it per char, over 8 bits per char
+        zlib_two_bigrams: ("abab", CompressionMethod::ZlibEstimate, 0.918_295_834_054_489_6 / 16.0),
+        // 3 equally likely bigrams over 3 chars of 3 bytes each
+        zlib_unicode: ("串用于测", CompressionMethod::ZlibEstimate, 3f64.log2() / 2.0 * 4.0 / 96.0),
+    }
+
+    #[test]
+    fn empty_and_single_chars() {
+        for method in [
+            CompressionMethod::RunLength,
+            CompressionMethod::LzComplexity,
+            CompressionMethod::ZlibEstimate,
+        ] {
+            assert_close(compression_ratio("", method), 0.0);
+        }
+        assert_close(compression_ratio("a", CompressionMethod::RunLength), 1.0);
+        assert_close(compression_ratio("a", CompressionMethod::LzComplexity), 1.0);
+        assert_close(compression_ratio("a", CompressionMethod::ZlibEstimate), 0.0);
+        assert_close(kolmogorov_approximation(""), 0.0);
+        assert_close(kolmogorov_approximation("a"), 1.0);
+    }
+
+    #[test]
+    fn phrases_of_the_parsing() {
+        assert_eq!(lz_complexity(""), 0);
+        // a|b|ab|aba|ba|bab, then a repeated phrase
+        assert_eq!(lz_complexity("abababababab"), 6);
+        assert_eq!(lz_complexity("ababababababa"), 7);
+        // a|ab|abb|b|bb|ba
+        assert_eq!(lz_complexity("aababbbbbba"), 6);
+        assert_eq!(runs("aababbbbbba"), 5);
+        assert_eq!(runs(""), 0);
+    }
+
+    #[test]
+    fn random_strings_compress_less() {
+        let random = Xorshift::default().string(2000, 26);
+        let repetitive = "aaaaabbbbb".repeat(200);
+        let single_letter = "a".repeat(2000);
+        for method in [
+            CompressionMethod::RunLength,
+            CompressionMethod::LzComplexity,
+            CompressionMethod::ZlibEstimate,
+        ] {
+            let ratios =
+                [&single_letter, &repetitive, &random].map(|s| compression_ratio(s, method));
+            assert!(ratios.iter().all(|ratio| (0.0..=1.0).contains(ratio)));
+            assert!(
+                ratios[0] <= ratios[1] && ratios[1] <= ratios[2],
+                "{method:?} {ratios:?}"
+            );
+        }
+        assert!(kolmogorov_approximation(&single_letter) < kolmogorov_approximation(&repetitive));
+        assert!(kolmogorov_approximation(&repetitive) < kolmogorov_approximation(&random));
+        assert!((0.5..1.5).contains(&kolmogorov_approximation(&random)));
+    }
+}
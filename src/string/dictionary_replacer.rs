@@ -0,0 +1,125 @@
+//! Replacement of a dictionary of patterns in a text, built on `AhoCorasick`.
+//!
+//! All the occurrences of all the patterns are found in a single pass over the text.
+//! They are then selected from left to right: among the occurrences starting at the same
+//! position the longest one wins, and the occurrences overlapping a selected one are skipped.
+
+use super::AhoCorasick;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Replaces the patterns of a dictionary with their replacements
+///
+/// Empty patterns are ignored. When a pattern is given several times, its first
+/// replacement is used.
+pub struct DictionaryReplacer {
+    automaton: AhoCorasick,
+    replacements: HashMap<String, String>,
+}
+
+impl DictionaryReplacer {
+    pub fn new(pairs: Vec<(&str, &str)>) -> Self {
+        let mut replacements = HashMap::new();
+        for (pattern, replacement) in pairs {
+            if !pattern.is_empty() {
+                replacements
+                    .entry(pattern.to_owned())
+                    .or_insert_with(|| replacement.to_owned());
+            }
+        }
+        let patterns: Vec<&str> = replacements.keys().map(String::as_str).collect();
+
+        DictionaryReplacer {
+            automaton: AhoCorasick::new(&patterns),
+            replacements,
+        }
+    }
+
+    /// Replaces every selected occurrence of the patterns in `text`
+    pub fn replace_all(&self, text: &str) -> String {
+        self.replace(text, &self.matches(text))
+    }
+
+    /// Replaces the leftmost (and then longest) occurrence of a pattern in `text`
+    ///
+    /// # Returns
+    /// `None` if no pattern occurs in `text`
+    pub fn replace_first(&self, text: &str) -> Option<String> {
+        let first = self.matches(text).into_iter().next()?;
+        Some(self.replace(text, &[first]))
+    }
+
+    /// Returns the number of occurrences `replace_all` replaces
+    pub fn match_count(&self, text: &str) -> usize {
+        self.matches(text).len()
+    }
+
+    /// The selected occurrences, as sorted and non-overlapping byte ranges of `text`
+    fn matches(&self, text: &str) -> Vec<Range<usize>> {
+        let mut spans = self.automaton.search_spans(text);
+        spans.sort_unstable_by_key(|span| (span.start, std::cmp::Reverse(span.end)));
+
+        let mut selected: Vec<Range<usize>> = vec![];
+        for span in spans {
+            if selected.last().is_none_or(|last| last.end <= span.start) {
+                selected.push(span);
+            }
+        }
+        selected
+    }
+
+    fn replace(&self, text: &str, spans: &[Range<usize>]) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut copied = 0;
+        for span in spans {
+            result.push_str(&text[copied..span.start]);
+            result.push_str(&self.replacements[&text[span.clone()]]);
+            copied = span.end;
+        }
+        result.push_str(&text[copied..]);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! replace_all_tests {
+        ($($name:ident: ($pairs:expr, $text:expr, $expected:expr, $count:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let replacer = DictionaryReplacer::new($pairs.to_vec());
+                    assert_eq!(replacer.replace_all($text), $expected);
+                    assert_eq!(replacer.match_count($text), $count);
+                }
+            )*
+        };
+    }
+
+    replace_all_tests! {
+        simple: ([("cat", "dog")], "a cat and a cat", "a dog and a dog", 2),
+        longest_match_wins: ([("he", "1"), ("hello", "2")], "hello help", "2 1lp", 2),
+        leftmost_match_wins: ([("abc", "X"), ("bcd", "Y")], "abcd", "Xd", 1),
+        no_overlap: ([("aa", "b")], "aaaaa", "bba", 2),
+        nested_patterns: ([("a", "1"), ("ab", "2"), ("abc", "3")], "abcaba", "321", 3),
+        no_match: ([("x", "y")], "abc", "abc", 0),
+        empty_text: ([("x", "y")], "", "", 0),
+        empty_pattern_ignored: ([("", "!"), ("b", "c")], "abc", "acc", 1),
+        unicode: ([("中文", "Chinese"), ("ハンバーガー", "burger")], "中文ハンバーガー!", "Chineseburger!", 2),
+        censoring: ([("darn", "****"), ("heck", "****")], "darn it, heck", "**** it, ****", 2),
+        first_replacement_kept: ([("a", "1"), ("a", "2")], "aa", "11", 2),
+    }
+
+    #[test]
+    fn replace_first() {
+        let replacer = DictionaryReplacer::new(vec![("he", "1"), ("hello", "2"), ("lp", "3")]);
+        assert_eq!(
+            replacer.replace_first("say hello help"),
+            Some("say 2 help".to_owned())
+        );
+        assert_eq!(replacer.replace_first("help"), Some("1lp".to_owned()));
+        assert_eq!(replacer.replace_first("nothing"), None);
+    }
+}
@@ -0,0 +1,363 @@
+//! A common interface to the string metrics of the module, normalized to [0, 1].
+//!
+//! Each metric is a unit struct implementing `StringDistance`, so a caller can take any of
+//! them as a `&dyn StringDistance` and switch metrics without changing its code. The
+//! distances are normalized by the lengths of the strings, 0 meaning the same strings and 1
+//! nothing in common, and the similarity is 1 minus the distance. Lengths are counted in
+//! chars, and two empty strings are the same.
+//!
+//! ```
+//! use the_algorithms_rust::string::{
+//!     most_similar, Hamming, JaroWinkler, Levenshtein, MlcsSimilarity, StringDistance,
+//! };
+//!
+//! let candidates = ["sitting", "mitten", "bitter", "written"];
+//! let metrics: [&dyn StringDistance; 4] =
+//!     [&Levenshtein, &Hamming, &JaroWinkler, &MlcsSimilarity];
+//! for metric in metrics {
+//!     assert_eq!(most_similar("kitten", &candidates, metric), "mitten");
+//! }
+//! assert_eq!(Levenshtein.distance("kitten", "sitting"), 3.0 / 7.0);
+//! ```
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/String_metric)
+
+use std::collections::HashMap;
+
+use super::{mlcs_len_pair, mlcs_similarity, optimized_levenshtein_distance};
+
+/// A distance between two strings, from 0 (same strings) to 1 (nothing in common)
+pub trait StringDistance {
+    /// The distance between `s1` and `s2`, in [0, 1]
+    fn distance(&self, s1: &str, s2: &str) -> f64;
+
+    /// The similarity of `s1` and `s2`, in [0, 1]: 1 for the same strings
+    fn similarity(&self, s1: &str, s2: &str) -> f64 {
+        1.0 - self.distance(s1, s2)
+    }
+}
+
+/// The Levenshtein distance, divided by the length of the longest string
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Levenshtein;
+
+/// The Damerau-Levenshtein distance, where swapping two adjacent chars is a single edit,
+/// divided by the length of the longest string
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DamerauLevenshtein;
+
+/// The number of positions with different chars, divided by the length of the longest
+/// string, the chars past the end of the shortest one being different
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hamming;
+
+/// One minus the Jaro similarity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Jaro;
+
+/// One minus the Jaro-Winkler similarity, which raises the Jaro similarity of the strings
+/// sharing a prefix of up to 4 chars
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JaroWinkler;
+
+/// One minus twice the LCS length divided by the sum of the lengths
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizedLcs;
+
+/// One minus `mlcs_similarity`, the LCS length divided by the length of the longest string
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MlcsSimilarity;
+
+// the length of the longest of s1 and s2, in chars
+fn max_len(s1: &str, s2: &str) -> usize {
+    s1.chars().count().max(s2.chars().count())
+}
+
+// edits divided by the length of the longest string, 0 for two empty strings
+fn normalized(edits: usize, s1: &str, s2: &str) -> f64 {
+    match max_len(s1, s2) {
+        0 => 0.0,
+        len => edits as f64 / len as f64,
+    }
+}
+
+impl StringDistance for Levenshtein {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        normalized(optimized_levenshtein_distance(s1, s2), s1, s2)
+    }
+}
+
+impl StringDistance for DamerauLevenshtein {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        normalized(damerau_levenshtein(s1, s2), s1, s2)
+    }
+}
+
+impl StringDistance for Hamming {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        let shared = s1.chars().zip(s2.chars()).filter(|(a, b)| a == b).count();
+        normalized(max_len(s1, s2) - shared, s1, s2)
+    }
+}
+
+impl StringDistance for Jaro {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        1.0 - jaro(s1, s2)
+    }
+}
+
+impl StringDistance for JaroWinkler {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        let jaro = jaro(s1, s2);
+        let prefix = s1
+            .chars()
+            .zip(s2.chars())
+            .take(4)
+            .take_while(|(a, b)| a == b)
+            .count();
+        1.0 - (jaro + 0.1 * prefix as f64 * (1.0 - jaro))
+    }
+}
+
+impl StringDistance for NormalizedLcs {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        match s1.chars().count() + s2.chars().count() {
+            0 => 0.0,
+            total => 1.0 - 2.0 * mlcs_len_pair(s1, s2) as f64 / total as f64,
+        }
+    }
+}
+
+impl StringDistance for MlcsSimilarity {
+    fn distance(&self, s1: &str, s2: &str) -> f64 {
+        1.0 - mlcs_similarity(s1, s2)
+    }
+}
+
+/// Finds the candidate most similar to `query` by `metric`
+///
+/// The first of the candidates at the smallest distance is returned.
+///
+/// # Panics
+///
+/// Panics if `candidates` is empty.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{most_similar, DamerauLevenshtein};
+///
+/// let words = ["from", "form", "farm"];
+/// assert_eq!(most_similar("fomr", &words, &DamerauLevenshtein), "form");
+/// ```
+pub fn most_similar<'a>(
+    query: &str,
+    candidates: &[&'a str],
+    metric: &dyn StringDistance,
+) -> &'a str {
+    candidates
+        .iter()
+        .map(|&candidate| (metric.distance(query, candidate), candidate))
+        .reduce(|best, next| if next.0 < best.0 { next } else { best })
+        .map(|(_, candidate)| candidate)
+        .expect("there is at least one candidate")
+}
+
+// the Jaro similarity: the chars of each string matching an equal char of the other one
+// at most half the longest length away, and the number t of these matches not in the same
+// order, give (m / |s1| + m / |s2| + (m - t / 2) / m) / 3 for m matches
+// `jaro_winkler_distance` is not reused: it looks for the matches within half the length
+// of the shortest string, and gives 0 to two empty strings, which are the same here
+fn jaro(s1: &str, s2: &str) -> f64 {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = vec![];
+    for (i, &c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        if let Some(j) = (start..end).find(|&j| !b_matched[j] && b[j] == c) {
+            b_matched[j] = true;
+            a_matches.push(c);
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let out_of_order = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| x != y)
+        .count();
+
+    let m = a_matches.len() as f64;
+    let t = (out_of_order / 2) as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+// the unrestricted Damerau-Levenshtein distance, by the algorithm of Lowrance and Wagner:
+// a transposition may join chars between which others were inserted or deleted
+fn damerau_levenshtein(s1: &str, s2: &str) -> usize {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let (n, m) = (a.len(), b.len());
+    let infinity = n + m;
+
+    // table[i + 1][j + 1] is the distance of the first i chars of a and the first j of b,
+    // the first row and column holding infinity
+    let mut table = vec![vec![0; m + 2]; n + 2];
+    table[0][0] = infinity;
+    for i in 0..=n {
+        table[i + 1][0] = infinity;
+        table[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        table[0][j + 1] = infinity;
+        table[1][j + 1] = j;
+    }
+
+    // the last row where each char of b was seen in a
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+    for i in 1..=n {
+        // the last column of this row where the chars matched
+        let mut last_column = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_column = j;
+                0
+            } else {
+                1
+            };
+            table[i + 1][j + 1] = (table[i][j] + cost)
+                .min(table[i + 1][j] + 1)
+                .min(table[i][j + 1] + 1)
+                .min(table[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    table[n + 1][m + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::assertions::assert_close;
+
+    const METRICS: [&dyn StringDistance; 7] = [
+        &Levenshtein,
+        &DamerauLevenshtein,
+        &Hamming,
+        &Jaro,
+        &JaroWinkler,
+        &NormalizedLcs,
+        &MlcsSimilarity,
+    ];
+
+    macro_rules! distance_tests {
+        ($($name:ident: ($metric:expr, $s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    assert_close($metric.distance(s1, s2), $expected);
+                    assert_close($metric.distance(s2, s1), $expected);
+                    assert_close($metric.similarity(s1, s2), 1.0 - $expected);
+                }
+            )*
+        };
+    }
+
+    distance_tests! {
+        levenshtein: (Levenshtein, "kitten", "sitting", 3.0 / 7.0),
+        levenshtein_unicode: (Levenshtein, "串用于测试", "用于串测试", 2.0 / 5.0),
+        damerau_levenshtein_transposition: (DamerauLevenshtein, "ca", "ac", 1.0 / 2.0),
+        damerau_levenshtein_apart: (DamerauLevenshtein, "ca", "abc", 2.0 / 3.0),
+        damerau_levenshtein_kitten: (DamerauLevenshtein, "kitten", "sitting", 3.0 / 7.0),
+        hamming: (Hamming, "karolin", "kathrin", 3.0 / 7.0),
+        hamming_different_lengths: (Hamming, "abc", "abcde", 2.0 / 5.0),
+        jaro: (Jaro, "MARTHA", "MARHTA", 1.0 - 17.0 / 18.0),
+        jaro_dixon: (Jaro, "DIXON", "DICKSONX", 1.0 - 23.0 / 30.0),
+        jaro_no_match: (Jaro, "abc", "xyz", 1.0),
+        jaro_winkler: (JaroWinkler, "MARTHA", "MARHTA", 1.0 - 0.961_111_111_111_111_1),
+        jaro_winkler_dixon: (JaroWinkler, "DIXON", "DICKSONX", 1.0 - 0.813_333_333_333_333_3),
+        normalized_lcs: (NormalizedLcs, "ABCBDAB", "BDCABA", 1.0 - 8.0 / 13.0),
+        mlcs_similarity: (MlcsSimilarity, "ABCBDAB", "BDCABA", 1.0 - 4.0 / 7.0),
+    }
+
+    #[test]
+    fn same_and_empty_strings() {
+        for metric in METRICS {
+            for s in ["", "a", "kitten", "串用于测试"] {
+                assert_close(metric.distance(s, s), 0.0);
+                assert_close(metric.similarity(s, s), 1.0);
+            }
+            assert_close(metric.distance("", "abc"), 1.0);
+            assert_close(metric.distance("abc", "xyz"), 1.0);
+        }
+    }
+
+    #[test]
+    fn distances_are_normalized() {
+        let words = [
+            "",
+            "a",
+            "ab",
+            "ba",
+            "abc",
+            "kitten",
+            "sitting",
+            "串用于测试",
+            "a🚀b",
+        ];
+        for metric in METRICS {
+            for s1 in words {
+                for s2 in words {
+                    let distance = metric.distance(s1, s2);
+                    assert!((0.0..=1.0).contains(&distance), "{s1:?} {s2:?}");
+                    assert_close(distance, metric.distance(s2, s1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_is_at_most_levenshtein() {
+        let words = [
+            "", "ab", "ba", "abc", "acb", "cab", "ca", "kitten", "sitting",
+        ];
+        for s1 in words {
+            for s2 in words {
+                let damerau = damerau_levenshtein(s1, s2);
+                let levenshtein = optimized_levenshtein_distance(s1, s2);
+                assert!(damerau <= levenshtein, "{s1:?} {s2:?}");
+            }
+        }
+        assert_eq!(damerau_levenshtein("abcdef", "badcfe"), 3);
+    }
+
+    #[test]
+    fn most_similar_candidates() {
+        let candidates = ["apple", "apply", "ample", "maple"];
+        assert_eq!(
+            most_similar("appel", &candidates, &DamerauLevenshtein),
+            "apple"
+        );
+        // a tie keeps the first candidate
+        assert_eq!(most_similar("xyz", &candidates, &Levenshtein), "apple");
+        assert_eq!(most_similar("maple", &candidates, &Hamming), "maple");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one candidate")]
+    fn most_similar_without_candidates() {
+        most_similar("query", &[], &Jaro);
+    }
+}
@@ -18,11 +18,11 @@
 ///
 /// The algorithm runs in O(n) time, where `n` is the length of the input string.
 pub fn duval_algorithm(s: &str) -> Vec<String> {
-    factorize_duval(&s.chars().collect::<Vec<char>>())
+    factorize_duval(s)
 }
 
-/// Helper function that takes a string slice, converts it to a vector of characters,
-/// and then applies the Duval factorization algorithm to find the Lyndon words.
+/// Helper function that applies the Duval factorization algorithm to a string slice
+/// to find the Lyndon words.
 ///
 /// # Arguments
 ///
@@ -31,30 +31,47 @@ pub fn duval_algorithm(s: &str) -> Vec<String> {
 /// # Returns
 ///
 /// A vector of strings, each representing a Lyndon word in the factorization.
-fn factorize_duval(s: &[char]) -> Vec<String> {
+fn factorize_duval(s: &str) -> Vec<String> {
     let mut start = 0;
     let mut factors: Vec<String> = Vec::new();
 
     while start < s.len() {
-        let mut end = start + 1;
-        let mut repeat = start;
-
-        while end < s.len() && s[repeat] <= s[end] {
-            if s[repeat] < s[end] {
-                repeat = start;
-            } else {
-                repeat += 1;
-            }
-            end += 1;
+        let (period, end) = duval_step(s, start);
+        while start + period <= end {
+            factors.push(s[start..start + period].to_string());
+            start += period;
         }
+    }
+
+    factors
+}
 
-        while start <= repeat {
-            factors.push(s[start..start + end - repeat].iter().collect::<String>());
-            start += end - repeat;
+/// Runs one step of Duval's algorithm from `start` and returns `(period, end)`:
+/// `s[start..end]` is of the form `w^k w'` where `w` is a Lyndon word of length
+/// `period`, `w'` a proper prefix of `w` and `k >= 1`.
+///
+/// The factorization goes on from the start of `w'`, the `k` copies of `w` being its next
+/// factors. Positions and lengths are byte offsets, on char boundaries: `start` must be
+/// one before the end of `s`. The chars are read in place, so the step takes O(1) space.
+pub(super) fn duval_step(s: &str, start: usize) -> (usize, usize) {
+    let char_at = |i: usize| s[i..].chars().next().expect("a char starts at the offset");
+    let mut repeat = start;
+    let mut end = start + char_at(start).len_utf8();
+
+    while end < s.len() {
+        let (expected, found) = (char_at(repeat), char_at(end));
+        if expected > found {
+            break;
         }
+        if expected < found {
+            repeat = start;
+        } else {
+            repeat += expected.len_utf8();
+        }
+        end += found.len_utf8();
     }
 
-    factors
+    (end - repeat, end)
 }
 
 #[cfg(test)]
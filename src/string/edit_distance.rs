@@ -0,0 +1,199 @@
+//! Edit distance between two strings where each insertion, deletion and replacement has
+//! its own cost, depending on the chars involved.
+//!
+//! This is the Wagner-Fischer dynamic programming of the Levenshtein distance, which is the
+//! case where every operation costs 1: the distance between the prefixes of i and j chars
+//! is the cheapest of deleting the i-th char, inserting the j-th one, or replacing the
+//! former by the latter, the replacement being free when they are equal. Non-uniform costs
+//! give e.g. a spell checker where replacing a letter by a neighbouring key is cheaper, or
+//! the substitution matrices of bioinformatics.
+//!
+//! Positions are counted in chars.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Wagner%E2%80%93Fischer_algorithm)
+
+/// The costs of the operations of `edit_distance_custom`
+///
+/// # Fields
+/// * `insert_cost` - the cost of inserting a char
+/// * `delete_cost` - the cost of deleting a char
+/// * `replace_cost` - the cost of replacing the first char by the second one, only called on
+///   different chars
+///
+/// The default costs are all 1, those of the Levenshtein distance.
+#[derive(Clone, Copy, Debug)]
+pub struct EditCosts {
+    pub insert_cost: fn(char) -> f64,
+    pub delete_cost: fn(char) -> f64,
+    pub replace_cost: fn(char, char) -> f64,
+}
+
+impl Default for EditCosts {
+    fn default() -> Self {
+        EditCosts {
+            insert_cost: |_| 1.0,
+            delete_cost: |_| 1.0,
+            replace_cost: |_, _| 1.0,
+        }
+    }
+}
+
+/// Computes the cheapest cost of turning `s1` into `s2` by inserting, deleting and
+/// replacing chars, at the prices of `costs`
+///
+/// The costs should not be negative: the cheapest edit would then be unbounded, and the
+/// result is only the cheapest edit touching each char at most once.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{edit_distance_custom, EditCosts};
+///
+/// assert_eq!(edit_distance_custom("kitten", "sitting", &EditCosts::default()), 3.0);
+///
+/// // replacing a vowel by another one is a minor typo
+/// let vowels = EditCosts {
+///     replace_cost: |a, b| if "aeiou".contains(a) && "aeiou".contains(b) { 0.5 } else { 1.0 },
+///     ..EditCosts::default()
+/// };
+/// assert_eq!(edit_distance_custom("kitten", "sitting", &vowels), 2.5);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * m) time and O(m) space, for strings of n and m chars.
+pub fn edit_distance_custom(s1: &str, s2: &str, costs: &EditCosts) -> f64 {
+    let s2: Vec<char> = s2.chars().collect();
+    // row[j] is the distance between the prefix of s1 read so far and the first j chars of s2
+    let mut row: Vec<f64> = Vec::with_capacity(s2.len() + 1);
+    row.push(0.0);
+    for &c2 in &s2 {
+        row.push(row[row.len() - 1] + (costs.insert_cost)(c2));
+    }
+
+    for c1 in s1.chars() {
+        let mut diagonal = row[0];
+        row[0] += (costs.delete_cost)(c1);
+        for (j, &c2) in s2.iter().enumerate() {
+            let replace = if c1 == c2 {
+                diagonal
+            } else {
+                diagonal + (costs.replace_cost)(c1, c2)
+            };
+            diagonal = row[j + 1];
+            row[j + 1] = replace
+                .min(row[j + 1] + (costs.delete_cost)(c1))
+                .min(row[j] + (costs.insert_cost)(c2));
+        }
+    }
+    row[s2.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::assertions::assert_close;
+    use crate::string::edit_script;
+    use crate::string::random_strings::Xorshift;
+
+    // the row of the key on a QWERTY keyboard and its place in the row
+    fn key(c: char) -> Option<(usize, usize)> {
+        ["qwertyuiop", "asdfghjkl", "zxcvbnm"]
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.find(c).map(|place| (row, place)))
+    }
+
+    // replacing a letter by a neighbouring key is half a typo
+    fn keyboard_costs() -> EditCosts {
+        EditCosts {
+            replace_cost: |a, b| match (key(a), key(b)) {
+                (Some((r1, p1)), Some((r2, p2))) if r1.abs_diff(r2) + p1.abs_diff(p2) == 1 => 0.5,
+                _ => 1.0,
+            },
+            ..EditCosts::default()
+        }
+    }
+
+    macro_rules! edit_distance_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    let costs = EditCosts::default();
+                    assert_close(edit_distance_custom(s1, s2, &costs), $expected);
+                    assert_close(edit_distance_custom(s2, s1, &costs), $expected);
+                    assert_close(edit_script(s1, s2).len() as f64, $expected);
+                }
+            )*
+        };
+    }
+
+    edit_distance_tests! {
+        empty: ("", "", 0.0),
+        empty_first: ("", "abc", 3.0),
+        identical: ("abcdef", "abcdef", 0.0),
+        kitten: ("kitten", "sitting", 3.0),
+        flaw: ("flaw", "lawn", 2.0),
+        no_common_char: ("abc", "xyz", 3.0),
+        unicode: ("串用于测试", "用于串测试", 2.0),
+        emoji: ("🚀a🚀", "a🚀b", 2.0),
+    }
+
+    #[test]
+    fn neighbouring_keys_are_cheaper() {
+        let costs = keyboard_costs();
+        // 'q' and 'w' are neighbours in a row, 'a' and 'z' in a column, 'a' and 'm' are not
+        assert_close(edit_distance_custom("qa", "wa", &costs), 0.5);
+        assert_close(edit_distance_custom("qa", "qz", &costs), 0.5);
+        assert_close(edit_distance_custom("qa", "qm", &costs), 1.0);
+        assert_close(edit_distance_custom("hello", "jello", &costs), 0.5);
+        assert_close(edit_distance_custom("hello", "yellow", &costs), 1.5);
+    }
+
+    #[test]
+    fn asymmetric_costs() {
+        // deleting is cheap, inserting is expensive, replacing is never worth it
+        let costs = EditCosts {
+            insert_cost: |_| 3.0,
+            delete_cost: |_| 0.5,
+            replace_cost: |_, _| 10.0,
+        };
+        assert_close(edit_distance_custom("abc", "", &costs), 1.5);
+        assert_close(edit_distance_custom("", "abc", &costs), 9.0);
+        assert_close(edit_distance_custom("abc", "abd", &costs), 3.5);
+        assert_close(edit_distance_custom("abd", "abc", &costs), 3.5);
+        assert_close(edit_distance_custom("abcd", "ad", &costs), 1.0);
+    }
+
+    #[test]
+    fn costs_depending_on_the_chars() {
+        // each char costs its position in the alphabet
+        let costs = EditCosts {
+            insert_cost: |c| f64::from(c as u8 - b'a' + 1),
+            delete_cost: |c| f64::from(c as u8 - b'a' + 1),
+            replace_cost: |a, b| f64::from(a.max(b) as u8 - a.min(b) as u8),
+        };
+        assert_close(edit_distance_custom("a", "b", &costs), 1.0);
+        assert_close(edit_distance_custom("a", "z", &costs), 25.0);
+        assert_close(edit_distance_custom("az", "a", &costs), 26.0);
+        // replacing the chars one by one is cheaper than moving the 'c'
+        assert_close(edit_distance_custom("abc", "cab", &costs), 4.0);
+    }
+
+    #[test]
+    fn random_strings_match_the_levenshtein_distance() {
+        let mut rng = Xorshift::default();
+        for _ in 0..300 {
+            let letters = 1 + rng.below(5);
+            let (s1, s2) = (rng.string_below(30, letters), rng.string_below(30, letters));
+            let expected = edit_script(&s1, &s2).len() as f64;
+            let costs = EditCosts::default();
+            assert_close(edit_distance_custom(&s1, &s2, &costs), expected);
+            // the distance of keyboard typos is at least half the number of edits
+            let typos = edit_distance_custom(&s1, &s2, &keyboard_costs());
+            assert!(expected / 2.0 <= typos && typos <= expected, "{s1} {s2}");
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Longest Common Extension (LCE) queries.
+//!
+//! The longest common extension of the positions `i` and `j` of a string is the length of
+//! the longest common prefix of the suffixes starting at `i` and `j`.
+//!
+//! The string is preprocessed in O(n log n) time:
+//! * its suffix array is built by prefix doubling, each round sorting the suffixes
+//!   with two counting sorts
+//! * the LCP array, holding the longest common prefix of each suffix and the previous one
+//!   in the suffix array, is derived from it with Kasai's algorithm
+//! * a sparse table answers range minimum queries on the LCP array.
+//!
+//! The LCE of two suffixes is then the minimum of the LCP array between their ranks,
+//! found in O(1) time.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Longest_common_prefix_array)
+
+use super::suffix_array::{lcp_array, sort_suffixes};
+use crate::data_structures::RangeMinimumQuery;
+
+/// Answers longest common extension queries on a string, positions being counted in chars
+pub struct LceTable {
+    len: usize,
+    // rank[i] is the position of the suffix starting at i in the suffix array
+    rank: Vec<usize>,
+    // the minimum of lcp[a..b] is the LCE of the suffixes of ranks a - 1 and b - 1
+    lcp: Option<RangeMinimumQuery<usize>>,
+}
+
+impl LceTable {
+    pub fn new(s: &str) -> Self {
+        let chars: Vec<char> = s.chars().collect();
+        let suffix_array = sort_suffixes(&chars);
+        let mut rank = vec![0; chars.len()];
+        for (r, &i) in suffix_array.iter().enumerate() {
+            rank[i] = r;
+        }
+        let lcp = lcp_array(&chars, &suffix_array);
+
+        LceTable {
+            len: chars.len(),
+            rank,
+            lcp: (!lcp.is_empty()).then(|| RangeMinimumQuery::new(&lcp)),
+        }
+    }
+
+    /// Returns the length of the longest common prefix of the suffixes starting
+    /// at the chars `i` and `j`
+    ///
+    /// A position equal to the length of the string stands for the empty suffix.
+    ///
+    /// # Panics
+    ///
+    /// If `i` or `j` is greater than the length of the string.
+    pub fn query(&self, i: usize, j: usize) -> usize {
+        assert!(
+            i <= self.len && j <= self.len,
+            "position out of the string of {} chars",
+            self.len
+        );
+        if i == j {
+            return self.len - i;
+        }
+        if i == self.len || j == self.len {
+            return 0;
+        }
+        let (low, high) = if self.rank[i] < self.rank[j] {
+            (self.rank[i], self.rank[j])
+        } else {
+            (self.rank[j], self.rank[i])
+        };
+        self.lcp
+            .as_ref()
+            .and_then(|lcp| lcp.get_range_min(low + 1, high + 1).ok())
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_lce(s: &[char], i: usize, j: usize) -> usize {
+        s[i..]
+            .iter()
+            .zip(&s[j..])
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    macro_rules! lce_tests {
+        ($($name:ident: $s:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let table = LceTable::new($s);
+                    let chars: Vec<char> = $s.chars().collect();
+                    for i in 0..=chars.len() {
+                        for j in 0..=chars.len() {
+                            assert_eq!(table.query(i, j), naive_lce(&chars, i, j), "({i}, {j})");
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    lce_tests! {
+        empty: "",
+        single_char: "a",
+        banana: "banana",
+        mississippi: "mississippi",
+        repeated_char: "aaaaaaaa",
+        periodic: "abcabcabcab",
+        fibonacci_word: "abaababaabaababaababa",
+        unicode: "串用串用于串用",
+    }
+
+    #[test]
+    fn examples() {
+        let table = LceTable::new("abracadabra");
+        assert_eq!(table.query(0, 7), 4);
+        assert_eq!(table.query(3, 5), 1);
+        assert_eq!(table.query(1, 2), 0);
+        assert_eq!(table.query(4, 4), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "position out of the string")]
+    fn out_of_bounds() {
+        LceTable::new("abc").query(0, 4);
+    }
+}
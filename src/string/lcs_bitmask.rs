@@ -0,0 +1,157 @@
+//! Longest common subsequence length of two strings by bit-parallelism, for a shorter
+//! string of at most 128 chars.
+//!
+//! Along a row of the LCS table of the shorter string (the columns) against the longer one
+//! (the rows), two neighbouring values differ by 0 or 1, so a row is held by a bitmask of
+//! its differences, one bit per column. The bitmask V has a 0 bit where the value goes up,
+//! and the LCS length is the number of 0 bits of the last row. With M the bits of the
+//! columns matching the char of a row, the next row is
+//!
+//! V' = (V + (V & M)) | (V & !M)
+//!
+//! the carries of the addition moving each match to the first column where the value
+//! goes up on the row above. A row is then a few word operations instead of a cell per
+//! column: a `u64` up to 64 columns, a `u128` up to 128, the classic dynamic programming
+//! beyond.
+//!
+//! This is the bit-vector algorithm of Allison and Dix, as simplified by Hyyrö.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+
+use std::collections::HashMap;
+
+use super::lcs_len_pair_with_scratch;
+
+/// Computes the length, in chars, of a longest common subsequence of `s1` and `s2`
+///
+/// The shorter string is held in the bits of a `u64` when it has at most 64 chars, of a
+/// `u128` when it has at most 128, and the longer strings are compared cell by cell by
+/// `lcs_len_pair_with_scratch`.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_bitmask;
+///
+/// assert_eq!(lcs_bitmask("AGGTAB", "GXTXAYB"), 4);
+/// assert_eq!(lcs_bitmask("串用于测试", "用于串测试"), 4);
+/// ```
+///
+/// # Complexity
+///
+/// O(n) time for a shorter string of at most 128 chars, n being the number of chars of the
+/// longer one, O(n * m) time otherwise.
+pub fn lcs_bitmask(s1: &str, s2: &str) -> usize {
+    let (n1, n2) = (s1.chars().count(), s2.chars().count());
+    let (long, short, m) = if n1 >= n2 { (s1, s2, n2) } else { (s2, s1, n1) };
+    match m {
+        0 => 0,
+        1..=64 => lcs_u64(long, short, m),
+        65..=128 => lcs_u128(long, short, m),
+        _ => lcs_len_pair_with_scratch(long, short, &mut vec![]),
+    }
+}
+
+// one function per word, the bits above the m columns being masked out of the count
+macro_rules! bit_parallel_lcs {
+    ($name:ident, $word:ty) => {
+        fn $name(long: &str, short: &str, m: usize) -> usize {
+            // masks[c] has the bit j set when the j-th char of short is c
+            let mut masks: HashMap<char, $word> = HashMap::new();
+            for (j, c) in short.chars().enumerate() {
+                *masks.entry(c).or_insert(0) |= 1 << j;
+            }
+            let columns: $word = <$word>::MAX >> (<$word>::BITS as usize - m);
+
+            let mut v = <$word>::MAX;
+            for c in long.chars() {
+                if let Some(&mask) = masks.get(&c) {
+                    let matches = v & mask;
+                    v = v.wrapping_add(matches) | (v - matches);
+                }
+            }
+            (!v & columns).count_ones() as usize
+        }
+    };
+}
+
+bit_parallel_lcs!(lcs_u64, u64);
+bit_parallel_lcs!(lcs_u128, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::random_strings::Xorshift;
+
+    // the cell by cell dynamic programming, whatever the lengths
+    fn naive_lcs(a: &str, b: &str) -> usize {
+        lcs_len_pair_with_scratch(a, b, &mut vec![])
+    }
+
+    macro_rules! bitmask_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    assert_eq!(lcs_bitmask(s1, s2), $expected);
+                    assert_eq!(lcs_bitmask(s2, s1), $expected);
+                    assert_eq!(naive_lcs(s1, s2), $expected);
+                }
+            )*
+        };
+    }
+
+    bitmask_tests! {
+        empty: ("", "", 0),
+        empty_first: ("", "abc", 0),
+        single_char: ("a", "a", 1),
+        no_common_char: ("abc", "def", 0),
+        identical: ("abcdef", "abcdef", 6),
+        classic: ("AGGTAB", "GXTXAYB", 4),
+        cormen: ("ABCBDAB", "BDCABA", 4),
+        kitten: ("kitten", "sitting", 4),
+        repeated: ("aaaa", "aa", 2),
+        unicode: ("串用于测试", "用于串测试", 4),
+        emoji: ("🚀a🚀b", "a🚀b🚀", 3),
+    }
+
+    #[test]
+    fn every_word_width() {
+        // shorter strings of 1 to 200 chars fill a u64, a u128, or fall back to the cells
+        let long = "the quick brown fox jumps over the lazy dog, ".repeat(6);
+        for m in [1, 2, 31, 63, 64, 65, 100, 127, 128, 129, 200] {
+            let short: String = "pack my box with five dozen liquor jugs "
+                .chars()
+                .cycle()
+                .take(m)
+                .collect();
+            assert_eq!(lcs_bitmask(&long, &short), naive_lcs(&long, &short), "{m}");
+            assert_eq!(lcs_bitmask(&short, &long), naive_lcs(&long, &short), "{m}");
+        }
+    }
+
+    #[test]
+    fn full_words() {
+        // every column of the word matches, the carries leave it
+        for m in [64, 128] {
+            let short = "a".repeat(m);
+            assert_eq!(lcs_bitmask(&short, &short), m);
+            assert_eq!(lcs_bitmask(&"a".repeat(3 * m), &short), m);
+            assert_eq!(lcs_bitmask(&"ab".repeat(m), &short), m);
+        }
+    }
+
+    #[test]
+    fn random_pairs_match_the_naive_lcs() {
+        let mut rng = Xorshift::default();
+        for _ in 0..500 {
+            let letters = 1 + rng.below(6);
+            let (s1, s2) = (
+                rng.string_below(300, letters),
+                rng.string_below(140, letters),
+            );
+            assert_eq!(lcs_bitmask(&s1, &s2), naive_lcs(&s1, &s2), "{s1} {s2}");
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Longest common subsequence of a string and the rotations of another one.
+//!
+//! Two approaches solve this problem:
+//! * running the classic LCS between `s1` and each of the `|s2|` rotations of `s2`,
+//!   and keeping the longest result, in O(|s2| * |s1| * |s2|) time
+//! * running a single LCS between `s1` and the doubled string `s2 + s2`, constrained
+//!   so that the matched positions in `s2 + s2` fit in a window of length `|s2|`.
+//!   Maes' algorithm and its successors maintain the tables of all the windows at once,
+//!   in O(|s1| * |s2| * log |s2|) time.
+//!
+//! The first one is implemented here, as it is simple to get right.
+
+use crate::dynamic_programming::longest_common_subsequence;
+
+/// Finds a longest common subsequence between `s1` and any rotation of `s2`.
+///
+/// When several rotations give a subsequence of the same length, the one of the
+/// smallest rotation (the number of chars of `s2` moved to its end) is returned.
+///
+/// # Complexity
+///
+/// O(|s2| * |s1| * |s2|) time and O(|s1| * |s2|) space.
+// TODO: use the O(|s1| * |s2| * log |s2|) windowed approach on s2 + s2
+pub fn lcs_circular(s1: &str, s2: &str) -> String {
+    let chars: Vec<char> = s2.chars().collect();
+    let mut best = String::new();
+    let mut best_len = 0;
+
+    for shift in 0..chars.len() {
+        let rotation: String = chars[shift..].iter().chain(&chars[..shift]).collect();
+        let lcs = longest_common_subsequence(s1, &rotation);
+        let len = lcs.chars().count();
+        if len > best_len {
+            best = lcs;
+            best_len = len;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! lcs_circular_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(lcs_circular($s1, $s2), $expected);
+                }
+            )*
+        };
+    }
+
+    lcs_circular_tests! {
+        empty: ("", "", ""),
+        empty_first: ("", "abc", ""),
+        empty_second: ("abc", "", ""),
+        same: ("abcd", "abcd", "abcd"),
+        whole_rotation: ("abcd", "cdab", "abcd"),
+        rotation_helps: ("abcde", "deabc", "abcde"),
+        partial: ("axbycz", "czab", "abcz"),
+        no_common: ("abc", "xyz", ""),
+        unicode: ("串用于测试", "测试串用", "串用测试"),
+    }
+
+    #[test]
+    fn at_least_the_plain_lcs() {
+        for (s1, s2) in [
+            ("ABCBDAB", "BDCABA"),
+            ("hello", "lohel"),
+            ("xaybz", "zbxay"),
+        ] {
+            let plain = longest_common_subsequence(s1, s2).chars().count();
+            assert!(lcs_circular(s1, s2).chars().count() >= plain);
+        }
+    }
+}
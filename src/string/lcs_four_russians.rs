@@ -0,0 +1,213 @@
+//! Longest common subsequence length of two ASCII strings by the Method of Four Russians.
+//!
+//! Along a row of the LCS table, two neighbouring values differ by 0 or 1, and so do two
+//! values of a column. The table is filled row by row, each row cut into blocks of t
+//! columns: the differences of a block of the new row only depend on the t differences of
+//! the block above, on the difference entering the block from the left, and on which of
+//! its t chars match the char of the row. These are 2t + 1 bits, so a table of the
+//! 2^(2t + 1) possible blocks, precomputed once, replaces the t cells of a block by a
+//! single lookup. The t match bits of every block and every char are themselves read in a
+//! lookup table indexed by the ASCII code of the char.
+//!
+//! With t about log(n) / 2 for a string of n chars, the precomputed table has O(n) entries
+//! and takes O(n log n) time to build, and the m rows of n / t blocks take
+//! O(m * n / log n) time instead of the O(m * n) of the classic dynamic programming.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Method_of_Four_Russians),
+//! [Masek and Paterson](https://doi.org/10.1016/0022-0000(80)90002-1)
+
+use super::mlcs_len_pair;
+
+/// The widest block, whose table has 2^17 entries
+const MAX_BLOCK: usize = 8;
+
+/// Computes the length of a longest common subsequence of `s1` and `s2`
+///
+/// The method only applies to ASCII strings, the other ones are compared char by char
+/// by `mlcs_len_pair`, i.e. by the bit-parallel `lcs_bitmask`.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_four_russians;
+///
+/// assert_eq!(lcs_four_russians("AGGTAB", "GXTXAYB"), 4);
+/// assert_eq!(lcs_four_russians("串用于测试", "用于串测试"), 4);
+/// ```
+///
+/// # Complexity
+///
+/// O(|s1| * |s2| / log |s1|) time and O(|s1| * σ / log |s1|) space for ASCII strings,
+/// σ being the number of distinct chars of `s2`.
+pub fn lcs_four_russians(s1: &str, s2: &str) -> usize {
+    if !s1.is_ascii() || !s2.is_ascii() {
+        return mlcs_len_pair(s1, s2);
+    }
+    let (columns, rows) = (s1.as_bytes(), s2.as_bytes());
+    if columns.is_empty() || rows.is_empty() {
+        return 0;
+    }
+
+    let t = block_width(columns.len());
+    let blocks = columns.len().div_ceil(t);
+    let table = block_table(t);
+
+    // match_masks[c][b] has its bit k set when the k-th char of the block b is c,
+    // only filled for the chars of the rows
+    let mut match_masks: Vec<Vec<u16>> = vec![vec![]; 128];
+    for &c in rows {
+        if match_masks[usize::from(c)].is_empty() {
+            match_masks[usize::from(c)] = columns
+                .chunks(t)
+                .map(|block| {
+                    block
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &x)| x == c)
+                        .fold(0, |mask, (k, _)| mask | 1 << k)
+                })
+                .collect();
+        }
+    }
+
+    // the horizontal differences of the current row, t bits per block, the row above the
+    // table being all zeros; the last block may have padding columns, which never match
+    // and so keep a difference of 0
+    let mut row = vec![0u16; blocks];
+    for &c in rows {
+        let masks = &match_masks[usize::from(c)];
+        // the vertical difference entering the block from the left, 0 on the first column
+        let mut left = 0;
+        for (b, block) in row.iter_mut().enumerate() {
+            let index = (usize::from(masks[b]) << (t + 1)) | (usize::from(*block) << 1) | left;
+            let entry = table[index];
+            *block = entry >> 1;
+            left = usize::from(entry & 1);
+        }
+    }
+
+    row.iter().map(|block| block.count_ones() as usize).sum()
+}
+
+/// The number of columns of a block, about half the bits of n
+fn block_width(n: usize) -> usize {
+    let bits = (usize::BITS - n.leading_zeros()) as usize;
+    bits.div_ceil(2).clamp(1, MAX_BLOCK)
+}
+
+/// For every block of t columns, the new horizontal differences and the vertical
+/// difference leaving the block
+///
+/// The index of a block is its match bits, then the horizontal differences of the row
+/// above, then the vertical difference entering it, from the highest bits to the lowest.
+/// The entry is the new horizontal differences, then the vertical difference leaving it.
+fn block_table(t: usize) -> Vec<u16> {
+    (0..1usize << (2 * t + 1))
+        .map(|index| {
+            let left = (index & 1) as u16;
+            let above = index >> 1 & ((1 << t) - 1);
+            let matches = index >> (t + 1);
+
+            // the values of the block relative to the value above its left column:
+            // up for the row above, current for the new row
+            let (mut up, mut current) = (0u16, left);
+            let mut differences = 0;
+            for k in 0..t {
+                let up_left = up;
+                up += (above >> k & 1) as u16;
+                let value = if matches >> k & 1 == 1 {
+                    up_left + 1
+                } else {
+                    up.max(current)
+                };
+                differences |= (value - current) << k;
+                current = value;
+            }
+            differences << 1 | (current - up)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::longest_common_subsequence;
+    use crate::string::random_strings::Xorshift;
+
+    fn lcs_len(a: &str, b: &str) -> usize {
+        longest_common_subsequence(a, b).chars().count()
+    }
+
+    macro_rules! four_russians_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    assert_eq!(lcs_four_russians(s1, s2), $expected);
+                    assert_eq!(lcs_four_russians(s2, s1), $expected);
+                }
+            )*
+        };
+    }
+
+    four_russians_tests! {
+        empty: ("", "", 0),
+        empty_first: ("", "abc", 0),
+        single_char: ("a", "a", 1),
+        no_common_char: ("abc", "def", 0),
+        identical: ("abcdef", "abcdef", 6),
+        classic: ("AGGTAB", "GXTXAYB", 4),
+        cormen: ("ABCBDAB", "BDCABA", 4),
+        repeated: ("aaaa", "aa", 2),
+        unicode: ("串用于测试", "用于串测试", 4),
+        mixed_ascii_and_unicode: ("abc串", "串abc", 3),
+    }
+
+    #[test]
+    fn every_block_width() {
+        // strings of 1 to 300 chars use blocks of 1 to 5 columns, with and without padding
+        let s2 = "the quick brown fox jumps over the lazy dog";
+        for n in [1, 2, 3, 4, 5, 7, 8, 15, 16, 31, 63, 64, 100, 255, 256, 300] {
+            let s1: String = "pack my box with five dozen liquor jugs "
+                .chars()
+                .cycle()
+                .take(n)
+                .collect();
+            assert_eq!(lcs_four_russians(&s1, s2), lcs_len(&s1, s2), "{n}");
+        }
+    }
+
+    #[test]
+    fn random_pairs_match_the_dynamic_programming() {
+        let mut rng = Xorshift::default();
+        for _ in 0..500 {
+            let letters = 1 + rng.below(6);
+            let (s1, s2) = (rng.string_below(60, letters), rng.string_below(60, letters));
+            assert_eq!(lcs_four_russians(&s1, &s2), lcs_len(&s1, &s2), "{s1} {s2}");
+        }
+    }
+
+    #[test]
+    fn widest_blocks() {
+        // 2^15 columns give the widest blocks
+        let s1 = "abcd".repeat(1 << 13);
+        let s2 = "dcba".repeat(8);
+        assert_eq!(block_width(s1.len()), MAX_BLOCK);
+        assert_eq!(lcs_four_russians(&s1, &s2), mlcs_len_pair(&s1, &s2));
+    }
+
+    #[test]
+    fn the_table_matches_the_cells() {
+        // a single block of the whole string, against one char
+        for t in 1..=4 {
+            let table = block_table(t);
+            assert_eq!(table.len(), 1 << (2 * t + 1));
+            // no difference above and no match: nothing changes
+            assert_eq!(table[0], 0);
+            // a match on the first column of a row of zeros: the value goes up there,
+            // and the whole row ends up one above the row above
+            assert_eq!(table[1 << (t + 1)], 1 << 1 | 1);
+        }
+    }
+}
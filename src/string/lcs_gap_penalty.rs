@@ -0,0 +1,155 @@
+//! Longest common subsequence scored as a global alignment with gap penalties.
+//!
+//! The two strings are aligned column by column: a column either matches two equal chars,
+//! scoring +1, or holds a single char of one of the strings against a gap, scoring
+//! `gap_penalty`. Two different chars are never aligned together. The best score is found
+//! by the Needleman-Wunsch dynamic programming with these scores, and the matched columns
+//! of an optimal alignment form a common subsequence.
+//!
+//! With k matches, an alignment of strings of m and n chars has m + n - 2k gaps, so its
+//! score is k + (m + n - 2k) * `gap_penalty`. For a penalty of 0 it is the LCS length, and
+//! for a negative one it still grows with k: the matched columns are a longest common
+//! subsequence, and the penalty only lowers the score. A positive penalty rewards the gaps
+//! and prefers fewer matches.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Needleman%E2%80%93Wunsch_algorithm)
+
+/// Aligns `s1` and `s2`, a match scoring 1 and a gap scoring `gap_penalty`
+///
+/// # Returns
+///
+/// The score of an optimal alignment, and the alignment as a string: one char per column,
+/// the matched char or `'-'` for a gap. Removing the `'-'` gives the matched subsequence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_with_gap_penalty;
+///
+/// assert_eq!(lcs_with_gap_penalty("ABCD", "ACD", -1), (2, "A-CD".to_string()));
+/// ```
+///
+/// # Complexity
+///
+/// O(|s1| * |s2|) time and space.
+pub fn lcs_with_gap_penalty(s1: &str, s2: &str, gap_penalty: i64) -> (i64, String) {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    // score[i][j] is the best score aligning a[..i] and b[..j]
+    let mut score = vec![vec![0; n + 1]; m + 1];
+    for i in 1..=m {
+        score[i][0] = score[i - 1][0] + gap_penalty;
+    }
+    for j in 1..=n {
+        score[0][j] = score[0][j - 1] + gap_penalty;
+    }
+    for i in 1..=m {
+        for j in 1..=n {
+            let gap = score[i - 1][j].max(score[i][j - 1]) + gap_penalty;
+            score[i][j] = if a[i - 1] == b[j - 1] {
+                gap.max(score[i - 1][j - 1] + 1)
+            } else {
+                gap
+            };
+        }
+    }
+
+    // the columns are found back from the end, preferring matches
+    let mut columns = Vec::with_capacity(m + n);
+    let (mut i, mut j) = (m, n);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && score[i][j] == score[i - 1][j - 1] + 1 {
+            columns.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && score[i][j] == score[i - 1][j] + gap_penalty {
+            columns.push('-');
+            i -= 1;
+        } else {
+            columns.push('-');
+            j -= 1;
+        }
+    }
+
+    (score[m][n], columns.into_iter().rev().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::longest_common_subsequence;
+
+    // the best score over every alignment, enumerated recursively
+    fn reference_score(a: &[char], b: &[char], gap_penalty: i64) -> i64 {
+        match (a, b) {
+            ([], _) | (_, []) => (a.len() + b.len()) as i64 * gap_penalty,
+            ([x, a_rest @ ..], [y, b_rest @ ..]) => {
+                let gap_in_b = reference_score(a_rest, b, gap_penalty);
+                let gap_in_a = reference_score(a, b_rest, gap_penalty);
+                let gaps = gap_in_b.max(gap_in_a) + gap_penalty;
+                if x == y {
+                    gaps.max(reference_score(a_rest, b_rest, gap_penalty) + 1)
+                } else {
+                    gaps
+                }
+            }
+        }
+    }
+
+    macro_rules! gap_penalty_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $gap_penalty:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2, expected): (&str, &str, (i64, &str)) = ($s1, $s2, $expected);
+                    let (score, alignment) = lcs_with_gap_penalty(s1, s2, $gap_penalty);
+                    assert_eq!((score, alignment.as_str()), expected);
+                }
+            )*
+        };
+    }
+
+    gap_penalty_tests! {
+        empty: ("", "", -1, (0, "")),
+        empty_first: ("", "abc", -1, (-3, "---")),
+        identical: ("abc", "abc", -1, (3, "abc")),
+        disjoint: ("ab", "cd", -1, (-4, "----")),
+        one_gap: ("ABCD", "ACD", -1, (2, "A-CD")),
+        no_penalty: ("ABCBDAB", "BDCABA", 0, (4, "-B-C-B-A-")),
+        penalty_of_two: ("ABCBDAB", "BDCABA", -2, (-6, "-B-C-B-A-")),
+        mismatch_is_two_gaps: ("axc", "ayc", -1, (0, "a--c")),
+        unicode: ("串用于", "用串于", -1, (0, "-串-于")),
+    }
+
+    #[test]
+    fn matches_the_reference() {
+        for (s1, s2) in [
+            ("ABCBDAB", "BDCABA"),
+            ("AGGTAB", "GXTXAYB"),
+            ("abcab", "bacba"),
+            ("aaaa", "aa"),
+            ("kitten", "sitting"),
+            ("", "x"),
+        ] {
+            let (a, b): (Vec<char>, Vec<char>) = (s1.chars().collect(), s2.chars().collect());
+            for gap_penalty in [0, -1, -3] {
+                let (score, alignment) = lcs_with_gap_penalty(s1, s2, gap_penalty);
+                assert_eq!(score, reference_score(&a, &b, gap_penalty), "{s1} {s2}");
+                // the matched columns are a longest common subsequence
+                let matched: String = alignment.chars().filter(|&c| c != '-').collect();
+                let lcs_len = longest_common_subsequence(s1, s2).chars().count();
+                assert_eq!(matched.chars().count(), lcs_len, "{s1} {s2}");
+                let gaps = (a.len() + b.len() - 2 * lcs_len) as i64;
+                assert_eq!(score, lcs_len as i64 + gaps * gap_penalty);
+                assert_eq!(alignment.chars().count(), lcs_len + gaps as usize);
+            }
+        }
+    }
+
+    #[test]
+    fn positive_penalty_rewards_gaps() {
+        assert_eq!(lcs_with_gap_penalty("ab", "ab", 1), (4, "----".to_string()));
+    }
+}
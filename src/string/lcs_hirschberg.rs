@@ -0,0 +1,155 @@
+//! Longest common subsequence of two strings in linear space, by Hirschberg's algorithm.
+//!
+//! The last row of the LCS table only needs the previous row to be computed. Splitting
+//! `s1` in two halves, the lengths of the LCS of its first half with every prefix of `s2`,
+//! and of its second half with every suffix of `s2`, are two such rows. An LCS of the
+//! whole strings is made of an LCS of each half with the part of `s2` on its side of some
+//! split, and the best split is the one maximizing the sum of the two rows. Both halves
+//! are then solved the same way, recursively.
+//!
+//! Each level of the recursion fills tables whose areas add up to half the area of the
+//! level above, so the time stays O(|s1| * |s2|), while the space is only the rows, the
+//! recursion being O(log |s1|) deep.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Hirschberg%27s_algorithm)
+
+/// Finds a longest common subsequence of `s1` and `s2`
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_hirschberg;
+///
+/// assert_eq!(lcs_hirschberg("AGGTAB", "GXTXAYB"), "GTAB");
+/// ```
+///
+/// # Complexity
+///
+/// O(|s1| * |s2|) time and O(|s1| + |s2|) space, the lengths counted in chars.
+pub fn lcs_hirschberg(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let mut lcs = String::new();
+    hirschberg(&a, &b, &mut lcs);
+    lcs
+}
+
+// appends an LCS of a and b to lcs
+fn hirschberg(a: &[char], b: &[char], lcs: &mut String) {
+    // with a single char on one side, the LCS is that char if the other side has it
+    match (a, b) {
+        ([], _) | (_, []) => return,
+        ([c], other) | (other, [c]) => {
+            if other.contains(c) {
+                lcs.push(*c);
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let (top, bottom) = a.split_at(a.len() / 2);
+    // forward[j] is the LCS length of top and b[..j]
+    let forward = last_row(top.iter(), b.iter());
+    // backward[k] is the LCS length of bottom and the last k chars of b
+    let backward = last_row(bottom.iter().rev(), b.iter().rev());
+    let split = (0..=b.len())
+        .max_by_key(|&j| (forward[j] + backward[b.len() - j], std::cmp::Reverse(j)))
+        .unwrap_or(0);
+
+    hirschberg(top, &b[..split], lcs);
+    hirschberg(bottom, &b[split..], lcs);
+}
+
+// the last row of the LCS table of a and b: row[j] is the LCS length of a and the
+// first j chars of b
+fn last_row<'a>(
+    a: impl Iterator<Item = &'a char>,
+    b: impl Iterator<Item = &'a char> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; b.clone().count() + 1];
+    for x in a {
+        let mut diagonal = 0;
+        for (j, y) in b.clone().enumerate() {
+            let up = row[j + 1];
+            row[j + 1] = if x == y { diagonal + 1 } else { up.max(row[j]) };
+            diagonal = up;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::{is_subsequence, longest_common_subsequence};
+    use crate::string::random_strings::Xorshift;
+
+    fn check(s1: &str, s2: &str) {
+        let result = lcs_hirschberg(s1, s2);
+        let expected = longest_common_subsequence(s1, s2);
+        assert_eq!(
+            result.chars().count(),
+            expected.chars().count(),
+            "{s1:?} {s2:?}"
+        );
+        assert!(is_subsequence(&result, s1), "{result:?} {s1:?}");
+        assert!(is_subsequence(&result, s2), "{result:?} {s2:?}");
+    }
+
+    macro_rules! hirschberg_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    assert_eq!(lcs_hirschberg(s1, s2), $expected);
+                    check(s1, s2);
+                    check(s2, s1);
+                }
+            )*
+        };
+    }
+
+    hirschberg_tests! {
+        empty: ("", "", ""),
+        empty_first: ("", "abc", ""),
+        empty_second: ("abc", "", ""),
+        single_char: ("a", "a", "a"),
+        no_common_char: ("abc", "def", ""),
+        identical: ("abcdef", "abcdef", "abcdef"),
+        classic: ("AGGTAB", "GXTXAYB", "GTAB"),
+        repeated: ("aaaa", "aa", "aa"),
+        unicode: ("串用于测试", "用于串测试", "用于测试"),
+        emoji: ("🚀a🚀b", "a🚀b🚀", "a🚀b"),
+    }
+
+    #[test]
+    fn random_pairs_match_the_full_table() {
+        let mut rng = Xorshift::default();
+        for _ in 0..1000 {
+            let letters = 1 + rng.below(5);
+            let (s1, s2) = (rng.string_below(25, letters), rng.string_below(25, letters));
+            check(&s1, &s2);
+        }
+    }
+
+    #[test]
+    fn base_cases() {
+        // a single char against a longer string, on either side, and at any position
+        for other in ["a", "xa", "ax", "xxaxx", "xyz", ""] {
+            let expected = if other.contains('a') { "a" } else { "" };
+            assert_eq!(lcs_hirschberg("a", other), expected, "{other:?}");
+            assert_eq!(lcs_hirschberg(other, "a"), expected, "{other:?}");
+        }
+        assert_eq!(lcs_hirschberg("串", "用于串测试"), "串");
+        assert_eq!(lcs_hirschberg("用于串测试", ""), "");
+    }
+
+    #[test]
+    fn long_strings() {
+        let s1 = "abcde".repeat(200);
+        let s2 = "edcba".repeat(200);
+        check(&s1, &s2);
+    }
+}
@@ -0,0 +1,163 @@
+//! Longest common subsequence of two sequences with few matching pairs, by the
+//! Hunt-Szymanski algorithm, the one behind the original `diff`.
+//!
+//! The matching pairs (i, j), where `a[i] == b[j]`, are read row by row, and for each
+//! length k the smallest position of `b` where a common subsequence of length k can end is
+//! kept in `thresholds`, which is increasing. A pair extends the longest subsequence ending
+//! before its column, found by a binary search, and lowers the threshold of the next
+//! length. The pairs of a row are read by decreasing column, so that a row matches a
+//! single position of `b`. Each threshold remembers the pair it came from, and the pair its
+//! predecessor, which gives the subsequence back.
+//!
+//! Only the r matching pairs are visited, which makes it much faster than the O(n * m)
+//! dynamic programming on sequences like the lines of two files, where most lines occur
+//! once or twice.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Hunt%E2%80%93Szymanski_algorithm)
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Finds a longest common subsequence of `a` and `b`
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_hunt_szymanski;
+///
+/// let old = ["fn main() {", "    let x = 1;", "    println!(\"{x}\");", "}"];
+/// let new = ["fn main() {", "    let x = 2;", "    println!(\"{x}\");", "}"];
+/// assert_eq!(
+///     lcs_hunt_szymanski(&old, &new),
+///     ["fn main() {", "    println!(\"{x}\");", "}"]
+/// );
+/// ```
+///
+/// # Complexity
+///
+/// O((r + n + m) log m) time and O(r + n + m) space, for r matching pairs between
+/// sequences of n and m items.
+pub fn lcs_hunt_szymanski<T: Eq + Hash + Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    // the positions of each item in b, increasing
+    let mut occurrences: HashMap<&T, Vec<usize>> = HashMap::new();
+    for (j, item) in b.iter().enumerate() {
+        occurrences.entry(item).or_default().push(j);
+    }
+
+    // pairs[p] is a matching pair (i, j) and the index of the pair before it in the
+    // subsequence, thresholds[k] the smallest column where a subsequence of length k + 1
+    // ends, and ends[k] the index of its last pair
+    let mut pairs: Vec<(usize, usize, Option<usize>)> = vec![];
+    let mut thresholds: Vec<usize> = vec![];
+    let mut ends: Vec<usize> = vec![];
+    for (i, item) in a.iter().enumerate() {
+        let Some(columns) = occurrences.get(item) else {
+            continue;
+        };
+        for &j in columns.iter().rev() {
+            let k = thresholds.partition_point(|&t| t < j);
+            if k < thresholds.len() && thresholds[k] == j {
+                continue;
+            }
+            let previous = k.checked_sub(1).map(|k| ends[k]);
+            pairs.push((i, j, previous));
+            if k == thresholds.len() {
+                thresholds.push(j);
+                ends.push(pairs.len() - 1);
+            } else {
+                thresholds[k] = j;
+                ends[k] = pairs.len() - 1;
+            }
+        }
+    }
+
+    let mut lcs = vec![];
+    let mut pair = ends.last().copied();
+    while let Some(p) = pair {
+        let (i, _, previous) = pairs[p];
+        lcs.push(a[i].clone());
+        pair = previous;
+    }
+    lcs.reverse();
+    lcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::is_subsequence_slice;
+    use crate::string::random_strings::Xorshift;
+
+    // the LCS length by the classic dynamic programming
+    fn dp_lcs_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+        let mut row = vec![0; b.len() + 1];
+        for x in a {
+            let mut diagonal = 0;
+            for (j, y) in b.iter().enumerate() {
+                let up = row[j + 1];
+                row[j + 1] = if x == y { diagonal + 1 } else { up.max(row[j]) };
+                diagonal = up;
+            }
+        }
+        row[b.len()]
+    }
+
+    fn check<T: Eq + Hash + Ord + Clone + std::fmt::Debug>(a: &[T], b: &[T]) {
+        let lcs = lcs_hunt_szymanski(a, b);
+        assert_eq!(lcs.len(), dp_lcs_len(a, b), "{a:?} {b:?}");
+        assert!(is_subsequence_slice(&lcs, a), "{lcs:?} {a:?}");
+        assert!(is_subsequence_slice(&lcs, b), "{lcs:?} {b:?}");
+    }
+
+    macro_rules! hunt_szymanski_tests {
+        ($($name:ident: ($a:expr, $b:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let a: Vec<char> = $a.chars().collect();
+                    let b: Vec<char> = $b.chars().collect();
+                    let lcs: String = lcs_hunt_szymanski(&a, &b).into_iter().collect();
+                    assert_eq!(lcs, $expected);
+                    check(&a, &b);
+                    check(&b, &a);
+                }
+            )*
+        };
+    }
+
+    hunt_szymanski_tests! {
+        empty: ("", "", ""),
+        empty_first: ("", "abc", ""),
+        empty_second: ("abc", "", ""),
+        single_char: ("a", "a", "a"),
+        no_common_char: ("abc", "def", ""),
+        identical: ("abcdef", "abcdef", "abcdef"),
+        classic: ("AGGTAB", "GXTXAYB", "GTAB"),
+        repeated: ("aaaa", "aa", "aa"),
+        unicode: ("串用于测试", "用于串测试", "用于测试"),
+    }
+
+    #[test]
+    fn lines_of_files() {
+        let old = vec!["use std::io;", "", "fn main() {", "    run();", "}"];
+        let new = vec!["use std::fs;", "use std::io;", "", "fn main() {", "}", ""];
+        assert_eq!(
+            lcs_hunt_szymanski(&old, &new),
+            ["use std::io;", "", "fn main() {", "}"]
+        );
+    }
+
+    #[test]
+    fn random_token_sequences_match_the_dynamic_programming() {
+        let mut rng = Xorshift::default();
+        for _ in 0..500 {
+            let tokens = 1 + rng.below(20);
+            let mut sequence = || -> Vec<u64> {
+                let len = rng.below(60);
+                (0..len).map(|_| rng.below(tokens)).collect()
+            };
+            let (a, b) = (sequence(), sequence());
+            check(&a, &b);
+        }
+    }
+}
@@ -74,7 +74,7 @@ pub fn naive_levenshtein_distance(string1: &str, string2: &str) -> usize {
 ///
 /// The Levenshtein distance between the two input strings.
 /// For a detailed explanation, check the example on [Wikipedia](https://en.wikipedia.org/wiki/Levenshtein_distance).
-/// This function iterates over the bytes in the string, so it may not behave entirely as expected for non-ASCII strings.
+/// The strings are compared char by char, and the distance is counted in chars.
 ///
 /// Note that this implementation utilizes an optimized dynamic programming approach, significantly reducing the space complexity from O(nm) to O(n), where n and m are the lengths of `string1` and `string2`.
 ///
@@ -88,9 +88,9 @@ pub fn naive_levenshtein_distance(string1: &str, string2: &str) -> usize {
 /// where n and m are lengths of `string1` and `string2`.
 pub fn optimized_levenshtein_distance(string1: &str, string2: &str) -> usize {
     if string1.is_empty() {
-        return string2.len();
+        return string2.chars().count();
     }
-    let l1 = string1.len();
+    let l1 = string1.chars().count();
     let mut prev_dist: Vec<usize> = (0..=l1).collect();
 
     for (row, c2) in string2.chars().enumerate() {
@@ -124,6 +124,170 @@ fn _min3<T: Ord>(a: T, b: T, c: T) -> T {
     min(a, min(b, c))
 }
 
+/// A single-character edit, positions being counted in chars
+///
+/// The operations of a script are applied one after the other, so each position refers
+/// to the string as edited by the previous operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// Inserts `ch` before the char at `pos`, or at the end when `pos` is the length
+    Insert { pos: usize, ch: char },
+    /// Deletes the char at `pos`
+    Delete { pos: usize },
+    /// Replaces the char `old` at `pos` by `new`
+    Replace { pos: usize, old: char, new: char },
+}
+
+/// Computes a shortest list of edits turning `s1` into `s2`
+///
+/// The script holds as many operations as the Levenshtein distance between the strings,
+/// counted in chars. It is built from the end of the strings, so its positions are
+/// decreasing and each of them is also a position in `s1`.
+///
+/// # Complexity
+///
+/// - Time complexity: O(nm),
+/// - Space complexity: O(nm),
+///
+/// where n and m are the numbers of chars of `s1` and `s2`.
+pub fn edit_script(s1: &str, s2: &str) -> Vec<EditOp> {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let (n, m) = (a.len(), b.len());
+    // distances[i][j] is the distance between the first i chars of s1 and the first j of s2
+    let mut distances = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=m {
+        distances[0][j] = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            distances[i][j] = _min3(
+                distances[i - 1][j - 1] + cost,
+                distances[i - 1][j] + 1,
+                distances[i][j - 1] + 1,
+            );
+        }
+    }
+
+    let mut script = vec![];
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0
+            && j > 0
+            && distances[i][j] == distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1])
+        {
+            if a[i - 1] != b[j - 1] {
+                script.push(EditOp::Replace {
+                    pos: i - 1,
+                    old: a[i - 1],
+                    new: b[j - 1],
+                });
+            }
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && distances[i][j] == distances[i - 1][j] + 1 {
+            script.push(EditOp::Delete { pos: i - 1 });
+            i -= 1;
+        } else {
+            script.push(EditOp::Insert {
+                pos: i,
+                ch: b[j - 1],
+            });
+            j -= 1;
+        }
+    }
+
+    debug_assert_eq!(apply_edit_script(s1, &script), s2);
+    script
+}
+
+/// Applies the operations of `script` to `s`, in order
+///
+/// # Panics
+///
+/// Panics if an operation refers to a position out of the edited string, or if a
+/// `Replace` does not find its `old` char.
+pub fn apply_edit_script(s: &str, script: &[EditOp]) -> String {
+    let mut chars: Vec<char> = s.chars().collect();
+    for &op in script {
+        match op {
+            EditOp::Insert { pos, ch } => {
+                assert!(pos <= chars.len(), "cannot insert at {pos}");
+                chars.insert(pos, ch);
+            }
+            EditOp::Delete { pos } => {
+                assert!(pos < chars.len(), "cannot delete at {pos}");
+                chars.remove(pos);
+            }
+            EditOp::Replace { pos, old, new } => {
+                assert_eq!(chars.get(pos), Some(&old), "cannot replace at {pos}");
+                chars[pos] = new;
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Chains two scripts: applying the result gives the same string as applying
+/// `first`, then `second`
+///
+/// The operations of `first` undone or overwritten by the ones of `second` which directly
+/// follow them are merged, e.g. an insertion followed by the deletion of the inserted char
+/// cancel each other.
+pub fn compose_edit_scripts(first: &[EditOp], second: &[EditOp]) -> Vec<EditOp> {
+    let mut script: Vec<EditOp> = Vec::with_capacity(first.len() + second.len());
+    for &op in first.iter().chain(second) {
+        let merged = match (script.last().copied(), op) {
+            (Some(EditOp::Insert { pos, .. }), EditOp::Delete { pos: deleted })
+                if pos == deleted =>
+            {
+                Some(None)
+            }
+            (
+                Some(EditOp::Insert { pos, ch }),
+                EditOp::Replace {
+                    pos: replaced,
+                    old,
+                    new,
+                },
+            ) if pos == replaced && ch == old => Some(Some(EditOp::Insert { pos, ch: new })),
+            (
+                Some(EditOp::Replace { pos, old, new }),
+                EditOp::Replace {
+                    pos: replaced,
+                    old: again,
+                    new: newer,
+                },
+            ) if pos == replaced && new == again => {
+                Some((old != newer).then_some(EditOp::Replace {
+                    pos,
+                    old,
+                    new: newer,
+                }))
+            }
+            (Some(EditOp::Replace { pos, .. }), EditOp::Delete { pos: deleted })
+                if pos == deleted =>
+            {
+                Some(Some(op))
+            }
+            _ => None,
+        };
+        match merged {
+            // the last operation and op become a single one, or nothing
+            Some(replacement) => {
+                script.pop();
+                script.extend(replacement);
+            }
+            None => script.push(op),
+        }
+    }
+    script
+}
+
 #[cfg(test)]
 mod tests {
     const LEVENSHTEIN_DISTANCE_TEST_CASES: &[(&str, &str, usize)] = &[
@@ -161,4 +325,108 @@ mod tests {
 
     levenshtein_distance_tests!(naive_levenshtein_distance);
     levenshtein_distance_tests!(optimized_levenshtein_distance);
+
+    mod edit_script {
+        use super::super::*;
+        use super::LEVENSHTEIN_DISTANCE_TEST_CASES;
+
+        #[test]
+        fn shortest_script() {
+            for &(s1, s2, distance) in LEVENSHTEIN_DISTANCE_TEST_CASES {
+                for (from, to) in [(s1, s2), (s2, s1)] {
+                    let script = edit_script(from, to);
+                    assert_eq!(script.len(), distance, "{from} -> {to}");
+                    assert_eq!(apply_edit_script(from, &script), to);
+                }
+            }
+        }
+
+        #[test]
+        fn operations() {
+            assert_eq!(
+                edit_script("horse", "ros"),
+                [
+                    EditOp::Delete { pos: 4 },
+                    EditOp::Delete { pos: 2 },
+                    EditOp::Replace {
+                        pos: 0,
+                        old: 'h',
+                        new: 'r'
+                    },
+                ]
+            );
+            assert_eq!(
+                edit_script("ac", "abc"),
+                [EditOp::Insert { pos: 1, ch: 'b' }]
+            );
+            assert!(edit_script("same", "same").is_empty());
+        }
+
+        #[test]
+        fn unicode() {
+            let script = edit_script("串用于", "串测于试");
+            assert_eq!(script.len(), 2);
+            assert_eq!(apply_edit_script("串用于", &script), "串测于试");
+            assert_eq!(optimized_levenshtein_distance("串用于", "串测于试"), 2);
+            assert_eq!(optimized_levenshtein_distance("", "串测"), 2);
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot delete at 3")]
+        fn delete_out_of_the_string() {
+            apply_edit_script("abc", &[EditOp::Delete { pos: 3 }]);
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot replace at 0")]
+        fn replace_of_another_char() {
+            apply_edit_script(
+                "abc",
+                &[EditOp::Replace {
+                    pos: 0,
+                    old: 'x',
+                    new: 'y',
+                }],
+            );
+        }
+
+        #[test]
+        fn composed_scripts_chain_the_edits() {
+            let words = ["kitten", "sitting", "", "mitten", "smitten", "sit"];
+            for s1 in words {
+                for s2 in words {
+                    for s3 in words {
+                        let first = edit_script(s1, s2);
+                        let second = edit_script(s2, s3);
+                        let composed = compose_edit_scripts(&first, &second);
+                        assert_eq!(apply_edit_script(s1, &composed), s3, "{s1} {s2} {s3}");
+                        assert!(composed.len() <= first.len() + second.len());
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn composition_merges_the_edits_undone() {
+            let insert = EditOp::Insert { pos: 1, ch: 'x' };
+            assert!(compose_edit_scripts(&[insert], &[EditOp::Delete { pos: 1 }]).is_empty());
+            let replace = |old, new| EditOp::Replace { pos: 1, old, new };
+            assert_eq!(
+                compose_edit_scripts(&[insert], &[replace('x', 'y')]),
+                [EditOp::Insert { pos: 1, ch: 'y' }]
+            );
+            assert_eq!(
+                compose_edit_scripts(&[replace('a', 'b')], &[replace('b', 'c')]),
+                [replace('a', 'c')]
+            );
+            assert!(compose_edit_scripts(&[replace('a', 'b')], &[replace('b', 'a')]).is_empty());
+            // the insertion is cancelled, then the replacement followed by the deletion merge
+            let script = compose_edit_scripts(
+                &[replace('a', 'b'), insert],
+                &[EditOp::Delete { pos: 1 }, EditOp::Delete { pos: 1 }],
+            );
+            assert_eq!(script, [EditOp::Delete { pos: 1 }]);
+            assert_eq!(apply_edit_script("zaq", &script), "zq");
+        }
+    }
 }
@@ -0,0 +1,184 @@
+//! Longest alternating subsequence, also known as the longest zig-zag subsequence.
+//!
+//! A sequence is alternating when its consecutive elements go alternately up and down,
+//! e.g. `a[0] < a[1] > a[2] < a[3] ...` ("starts up") or `a[0] > a[1] < a[2] > a[3] ...`
+//! ("starts down"). Sequences of length 0 or 1 are alternating in both ways.
+
+/// The relation between the first two elements of an alternating sequence
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alternation {
+    /// `a[0] < a[1] > a[2] < ...`
+    StartsUp,
+    /// `a[0] > a[1] < a[2] > ...`
+    StartsDown,
+}
+
+/// Finds the longest alternating subsequence whose first move is given by `alternation`.
+///
+/// The sequence is scanned greedily: while waiting for an up move, a smaller element
+/// replaces the last one taken, since a lower valley can only make the next peak easier
+/// to find (and symmetrically for down moves).
+///
+/// # Complexity
+///
+/// O(n) time and space.
+pub fn longest_alternating_subsequence_with<T: Ord + Clone>(
+    seq: &[T],
+    alternation: Alternation,
+) -> Vec<T> {
+    let mut taken: Vec<usize> = Vec::new();
+    let mut going_up = alternation == Alternation::StartsUp;
+
+    for (i, value) in seq.iter().enumerate() {
+        let Some(&last) = taken.last() else {
+            taken.push(i);
+            continue;
+        };
+        if (going_up && *value > seq[last]) || (!going_up && *value < seq[last]) {
+            taken.push(i);
+            going_up = !going_up;
+        } else if (going_up && *value < seq[last]) || (!going_up && *value > seq[last]) {
+            *taken.last_mut().unwrap() = i;
+        }
+    }
+
+    taken.into_iter().map(|i| seq[i].clone()).collect()
+}
+
+/// Finds the longest alternating subsequence of `seq`, starting either up or down.
+///
+/// When both variants have the same length, the one starting up is returned.
+pub fn longest_alternating_subsequence<T: Ord + Clone>(seq: &[T]) -> Vec<T> {
+    let up = longest_alternating_subsequence_with(seq, Alternation::StartsUp);
+    let down = longest_alternating_subsequence_with(seq, Alternation::StartsDown);
+    if down.len() > up.len() {
+        down
+    } else {
+        up
+    }
+}
+
+/// Computes the length of the longest alternating subsequence of `seq`.
+///
+/// `up` (resp. `down`) is the length of the longest alternating subsequence of the prefix
+/// read so far whose last move goes up (resp. down).
+///
+/// # Complexity
+///
+/// O(n) time and O(1) space.
+pub fn longest_alternating_subsequence_length<T: Ord>(seq: &[T]) -> usize {
+    if seq.is_empty() {
+        return 0;
+    }
+
+    let (mut up, mut down) = (1, 1);
+    for pair in seq.windows(2) {
+        if pair[1] > pair[0] {
+            up = down + 1;
+        } else if pair[1] < pair[0] {
+            down = up + 1;
+        }
+    }
+
+    up.max(down)
+}
+
+/// Finds the longest alternating subsequence of the chars of `s`, chars being compared
+/// by their code point.
+pub fn longest_alternating_subsequence_str(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    longest_alternating_subsequence(&chars)
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::is_subsequence_slice;
+
+    fn is_alternating<T: Ord>(seq: &[T]) -> bool {
+        seq.windows(3)
+            .all(|w| (w[0] < w[1] && w[1] > w[2]) || (w[0] > w[1] && w[1] < w[2]))
+            && seq.windows(2).all(|w| w[0] != w[1])
+    }
+
+    macro_rules! test_alternating {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (seq, expected): (&[i32], &[i32]) = $inputs;
+                    let result = longest_alternating_subsequence(seq);
+                    assert_eq!(result, expected);
+                    assert_eq!(longest_alternating_subsequence_length(seq), expected.len());
+                    assert!(is_alternating(&result));
+                    assert!(is_subsequence_slice(&result, seq));
+                }
+            )*
+        }
+    }
+
+    test_alternating! {
+        empty: (&[], &[]),
+        single: (&[7], &[7]),
+        constant: (&[3, 3, 3], &[3]),
+        increasing: (&[1, 2, 3, 4], &[1, 4]),
+        decreasing: (&[4, 3, 2, 1], &[4, 1]),
+        already_alternating: (&[1, 5, 4, 9, 2], &[1, 5, 4, 9, 2]),
+        monotone_runs: (&[10, 22, 9, 33, 49, 50, 31, 60], &[10, 22, 9, 50, 31, 60]),
+        with_plateaus: (&[1, 7, 7, 4, 9, 2, 5], &[1, 7, 4, 9, 2, 5]),
+        better_down: (&[5, 1, 5, 1], &[5, 1, 5, 1]),
+    }
+
+    #[test]
+    fn fixed_directions() {
+        let seq = [3, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(
+            longest_alternating_subsequence_with(&seq, Alternation::StartsUp),
+            vec![1, 4, 1, 9, 2, 6]
+        );
+        assert_eq!(
+            longest_alternating_subsequence_with(&seq, Alternation::StartsDown),
+            vec![3, 1, 4, 1, 9, 2, 6]
+        );
+        assert_eq!(longest_alternating_subsequence_length(&seq), 7);
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(longest_alternating_subsequence_str("abcba"), "aca");
+        assert_eq!(longest_alternating_subsequence_str("zaza"), "zaza");
+        assert_eq!(longest_alternating_subsequence_str(""), "");
+    }
+
+    #[test]
+    fn length_matches_brute_force() {
+        let seqs: [&[u8]; 5] = [
+            &[2, 2, 1, 3, 3, 0, 4],
+            &[1, 3, 2, 2, 5, 4, 4, 6],
+            &[9, 8, 8, 7, 9, 1, 1],
+            &[0, 1, 0, 1, 1, 0],
+            &[5, 4, 6, 3, 7, 2, 8],
+        ];
+        for seq in seqs {
+            let brute = (0u32..1 << seq.len())
+                .map(|mask| {
+                    (0..seq.len())
+                        .filter(|i| mask >> i & 1 == 1)
+                        .map(|i| seq[i])
+                        .collect::<Vec<u8>>()
+                })
+                .filter(|sub| is_alternating(sub))
+                .map(|sub| sub.len())
+                .max()
+                .unwrap();
+            assert_eq!(
+                longest_alternating_subsequence_length(seq),
+                brute,
+                "{seq:?}"
+            );
+            assert_eq!(longest_alternating_subsequence(seq).len(), brute, "{seq:?}");
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Length and number of the longest common subsequences of two strings, by the classic
+//! dynamic programming.
+//!
+//! The LCS itself, with the traceback of the table, is
+//! `dynamic_programming::longest_common_subsequence`, whose table holds the LCS lengths of
+//! the prefixes. The functions here read the suffix table of the MLCS heuristic instead:
+//! `score_matrix(s1, s2, None)[i][j]` is the LCS length of `s1[i..]` and `s2[j..]`, and the
+//! tests check both tables against each other.
+//!
+//! The length alone only needs the previous row of the table, in linear space: `lcs_length`
+//! is the dynamic programming of `lcs_len_pair_with_scratch`, which `lcs_bitmask` also
+//! falls back to beyond 128 chars.
+//!
+//! The same table counts the distinct longest subsequences in `count_distinct_lcs`. Counting
+//! the paths of the traceback would count a subsequence once per way of matching it, so the
+//! subsequences are counted by their first char instead: those of the suffixes `(i, j)`
+//! starting with c are c followed by a longest subsequence of the suffixes after the first
+//! c of each string, whenever that still makes a longest one.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+
+use std::collections::HashSet;
+
+use super::lcs_len_pair_with_scratch;
+use super::multiple_longest_common_subsequence::score_matrix;
+
+/// Computes the length, in chars, of a longest common subsequence of `s1` and `s2`
+///
+/// This is `lcs_len_pair_with_scratch` with a row of its own: a single row of the table is
+/// kept, as long as the shorter string, whichever it is.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_length;
+///
+/// assert_eq!(lcs_length("AGGTAB", "GXTXAYB"), 4);
+/// assert_eq!(lcs_length("串用于测试", "用于串测试"), 4);
+/// assert_eq!(lcs_length("", "abc"), 0);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * m) time and O(min(n, m)) space, for strings of n and m chars.
+pub fn lcs_length(s1: &str, s2: &str) -> usize {
+    lcs_len_pair_with_scratch(s1, s2, &mut vec![])
+}
+
+/// Counts the distinct longest common subsequences of `s1` and `s2`, compared char by char
+///
+/// Two subsequences are the same when they have the same chars, wherever they are matched.
+/// Strings without a common char have a single one, the empty string.
+///
+/// # Returns
+///
+/// The number of distinct subsequences, `u128::MAX` when there are at least as many.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::count_distinct_lcs;
+///
+/// // "BCBA", "BCAB" and "BDAB"
+/// assert_eq!(count_distinct_lcs("ABCBDAB", "BDCABA"), 3);
+/// // "aa" is matched in 3 ways in "aaa", but counted once
+/// assert_eq!(count_distinct_lcs("aaa", "aa"), 1);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * m * k) time and O(n * m) space, for strings of n and m chars sharing k letters.
+pub fn count_distinct_lcs(s1: &str, s2: &str) -> u128 {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let table = score_matrix(&a, &b, None);
+
+    let in_b: HashSet<char> = b.iter().copied().collect();
+    let mut alphabet: Vec<char> = a.iter().copied().filter(|c| in_b.contains(c)).collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    // next_a[k][i] is the first position at or after i of alphabet[k] in a, None if there
+    // is none, and next_b the same in b
+    let next_a: Vec<Vec<Option<usize>>> = alphabet.iter().map(|&c| next_positions(&a, c)).collect();
+    let next_b: Vec<Vec<Option<usize>>> = alphabet.iter().map(|&c| next_positions(&b, c)).collect();
+
+    // counts[i][j] is the number of distinct LCS of a[i..] and b[j..], the empty
+    // subsequence being the only one when their LCS length is 0
+    let mut counts = vec![vec![1u128; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            if table[i][j] == 0 {
+                continue;
+            }
+            counts[i][j] = next_a
+                .iter()
+                .zip(&next_b)
+                .filter_map(|(next_a, next_b)| Some((next_a[i]? + 1, next_b[j]? + 1)))
+                .filter(|&(p, q)| table[p][q] + 1 == table[i][j])
+                .fold(0, |count: u128, (p, q)| count.saturating_add(counts[p][q]));
+        }
+    }
+    counts[0][0]
+}
+
+// the first position at or after each position of s where ch occurs, None after the last
+// one, with a last entry for the end of s
+fn next_positions(s: &[char], ch: char) -> Vec<Option<usize>> {
+    let mut next = vec![None; s.len() + 1];
+    for i in (0..s.len()).rev() {
+        next[i] = if s[i] == ch { Some(i) } else { next[i + 1] };
+    }
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::{is_subsequence, longest_common_subsequence};
+    use crate::string::random_strings::Xorshift;
+
+    macro_rules! lcs_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    let lcs = longest_common_subsequence(s1, s2);
+                    assert_eq!(lcs.chars().count(), $expected);
+                    assert!(is_subsequence(&lcs, s1) && is_subsequence(&lcs, s2));
+                    let table = score_matrix(&s1.chars().collect::<Vec<_>>(), &s2.chars().collect::<Vec<_>>(), None);
+                    assert_eq!(table[0][0], $expected as u64);
+                    assert_eq!(lcs_length(s1, s2), $expected);
+                    assert_eq!(lcs_length(s2, s1), $expected);
+                }
+            )*
+        };
+    }
+
+    lcs_tests! {
+        both_empty: ("", "", 0),
+        first_empty: ("", "abc", 0),
+        second_empty: ("abc", "", 0),
+        identical: ("abcdef", "abcdef", 6),
+        no_common_char: ("abc", "def", 0),
+        classic: ("AGGTAB", "GXTXAYB", 4),
+        cormen: ("ABCBDAB", "BDCABA", 4),
+        kitten: ("kitten", "sitting", 4),
+        prefix: ("abc", "abcdef", 3),
+        repeated: ("aaaa", "aa", 2),
+        unicode: ("串用于测试", "用于串测试", 4),
+        emoji: ("🚀a🚀b", "a🚀b🚀", 3),
+        combining_marks: ("e\u{301}x", "xe\u{301}", 2),
+    }
+
+    #[test]
+    fn length_matches_the_full_table() {
+        let mut rng = Xorshift::default();
+        let mut string =
+            |len: u64, letters: u64| -> Vec<char> { rng.string(len, letters).chars().collect() };
+        // short strings over few letters, then a few long ones, up to 3000 chars
+        let mut pairs: Vec<(Vec<char>, Vec<char>)> = vec![];
+        for n in 0..200 {
+            let letters = 1 + n % 6;
+            pairs.push((string(n % 37, letters), string(n % 23, letters)));
+        }
+        for (n, m) in [(3000, 500), (700, 3000), (2000, 2000)] {
+            pairs.push((string(n, 4), string(m, 4)));
+        }
+        for (a, b) in pairs {
+            let (s1, s2): (String, String) = (a.iter().collect(), b.iter().collect());
+            let expected = score_matrix(&a, &b, None)[0][0] as usize;
+            assert_eq!(lcs_length(&s1, &s2), expected, "{s1} {s2}");
+            assert_eq!(lcs_length(&s2, &s1), expected, "{s1} {s2}");
+        }
+    }
+    // the distinct LCS of a and b, by enumerating the subsequences of a
+    fn brute_force_count(a: &str, b: &str) -> u128 {
+        let a: Vec<char> = a.chars().collect();
+        let common: HashSet<String> = (0..1u32 << a.len())
+            .map(|mask| {
+                (0..a.len())
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .map(|i| a[i])
+                    .collect::<String>()
+            })
+            .filter(|sub| is_subsequence(sub, b))
+            .collect();
+        let len = common
+            .iter()
+            .map(|sub| sub.chars().count())
+            .max()
+            .unwrap_or(0);
+        common
+            .iter()
+            .filter(|sub| sub.chars().count() == len)
+            .count() as u128
+    }
+
+    macro_rules! count_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2): (&str, &str) = ($s1, $s2);
+                    assert_eq!(count_distinct_lcs(s1, s2), $expected);
+                    assert_eq!(count_distinct_lcs(s2, s1), $expected);
+                }
+            )*
+        };
+    }
+
+    count_tests! {
+        count_cormen: ("ABCBDAB", "BDCABA", 3),
+        count_both_empty: ("", "", 1),
+        count_first_empty: ("", "abc", 1),
+        count_no_common_char: ("abc", "def", 1),
+        count_identical: ("abcdef", "abcdef", 1),
+        // "ab" and "ac"
+        count_swapped_end: ("abc", "acb", 2),
+        // "a", "b" and "c"
+        count_reversed: ("abc", "cba", 3),
+        count_repeated: ("aaa", "aa", 1),
+        // "aba" and "bab"
+        count_alternating: ("abab", "baba", 2),
+        count_unicode: ("串用于", "于用串", 3),
+    }
+
+    #[test]
+    fn count_matches_the_brute_force() {
+        let mut rng = Xorshift::default();
+        for _ in 0..500 {
+            let letters = 1 + rng.below(3);
+            let (s1, s2) = (rng.string_below(9, letters), rng.string_below(9, letters));
+            assert_eq!(
+                count_distinct_lcs(&s1, &s2),
+                brute_force_count(&s1, &s2),
+                "{s1} {s2}"
+            );
+        }
+    }
+
+    #[test]
+    fn count_saturates() {
+        // k blocks of two letters of their own, in opposite orders: either letter of each
+        // block gives a distinct LCS, 2^k in all
+        let blocks = |k: u32| -> (String, String) {
+            let letters: Vec<char> = (0..2 * k)
+                .map(|n| char::from_u32(0x4e00 + n).unwrap())
+                .collect();
+            let s1 = letters
+                .chunks(2)
+                .flat_map(|pair| [pair[0], pair[1]])
+                .collect();
+            let s2 = letters
+                .chunks(2)
+                .flat_map(|pair| [pair[1], pair[0]])
+                .collect();
+            (s1, s2)
+        };
+        let (s1, s2) = blocks(10);
+        assert_eq!(count_distinct_lcs(&s1, &s2), 1 << 10);
+        let (s1, s2) = blocks(127);
+        assert_eq!(count_distinct_lcs(&s1, &s2), 1 << 127);
+        let (s1, s2) = blocks(130);
+        assert_eq!(count_distinct_lcs(&s1, &s2), u128::MAX);
+    }
+}
@@ -0,0 +1,475 @@
+//! Longest common *substring* of a set of strings.
+//!
+//! A substring is a contiguous run of characters, which is what separates this problem
+//! from the longest common *subsequence* (see `multiple_longest_common_subsequence`),
+//! where the matched characters may be scattered. For `"ABCDEF"` and `"BCDFGH"` the
+//! longest common substring is `"BCD"`, whereas the longest common subsequence is `"BCDF"`.
+//!
+//! Three approaches are provided:
+//! * `longest_common_substring_pair` uses the classic O(n * m) dynamic programming table
+//!   (kept to a single rolling row) and is the simplest choice for two strings.
+//! * `longest_common_substring_all` builds a generalised suffix array over all strings,
+//!   joined with unique separators, and scans it together with its LCP array. It runs in
+//!   O(N log N) where N is the total length of the inputs.
+//! * `multi_longest_common_substring` binary searches the length of the answer, checking
+//!   each length with rolling hashes of the windows of every string. It runs in
+//!   O(N log m) expected time, m being the length of the shortest string.
+//!
+//! The last two return the same substring, which the tests check on random inputs. The
+//! suffix array has a worst-case bound and is the choice for inputs that could be chosen
+//! to make hashes collide. The hashes only hold the windows of two strings at a time
+//! instead of several arrays over the joined text, and their binary search ends sooner
+//! when one string is short, so they suit many strings, or strings of very unequal
+//! lengths.
+//!
+//! All functions work on `char`s, so multi-byte characters are handled correctly.
+
+use super::suffix_array::{lcp_array, sort_suffixes};
+use std::collections::{HashMap, VecDeque};
+
+// the rolling hashes are computed modulo the Mersenne prime 2^61 - 1, in a base larger
+// than every code point
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 0x11_0001;
+
+/// Finds the longest common substring of two strings using dynamic programming.
+///
+/// `row[j + 1]` holds the length of the longest common suffix of `s1[..=i]` and `s2[..=j]`,
+/// the best cell seen so far gives the answer.
+///
+/// # Arguments
+///
+/// * `s1` - The first input string.
+/// * `s2` - The second input string.
+///
+/// # Returns
+///
+/// The longest substring shared by both strings. When several candidates have the same
+/// length, the one ending first in `s1` is returned. An empty string is returned when the
+/// strings have no character in common. Its length in chars is
+/// `dynamic_programming::longest_common_substring`.
+pub fn longest_common_substring_pair(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+
+    let mut row = vec![0usize; b.len() + 1];
+    let mut best_len = 0;
+    let mut best_end = 0;
+
+    for (i, &ca) in a.iter().enumerate() {
+        // iterating backwards so that row[j] still holds the value of the previous row
+        for (j, &cb) in b.iter().enumerate().rev() {
+            row[j + 1] = if ca == cb { row[j] + 1 } else { 0 };
+            if row[j + 1] > best_len {
+                best_len = row[j + 1];
+                best_end = i + 1;
+            }
+        }
+    }
+
+    a[best_end - best_len..best_end].iter().collect()
+}
+
+/// Finds the longest substring common to every string of `strings`.
+///
+/// The strings are concatenated as `s_0 #_0 s_1 #_1 ... s_{k-1} #_{k-1}` where each `#_i` is
+/// a separator that occurs nowhere else, so no common prefix of two suffixes can cross the
+/// end of a string. The longest common substring is then the largest minimum LCP value over
+/// a window of consecutive suffixes (in suffix array order) containing at least one suffix
+/// of every string, which a sliding window with a monotonic deque finds in linear time.
+///
+/// # Arguments
+///
+/// * `strings` - The strings among which the common substring is searched.
+///
+/// # Returns
+///
+/// The longest substring shared by all strings. Among equally long candidates, the
+/// lexicographically smallest one is returned. An empty slice, or strings without any
+/// common character, give an empty string.
+pub fn longest_common_substring_all(strings: &[&str]) -> String {
+    match strings.len() {
+        0 => return String::new(),
+        1 => return strings[0].to_string(),
+        _ => {}
+    }
+
+    let k = strings.len();
+    let chains: Vec<Vec<char>> = strings.iter().map(|s| s.chars().collect()).collect();
+
+    // ranks of the characters, shifted so that 0..k are free for the separators
+    let mut alphabet: Vec<char> = chains.iter().flatten().copied().collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    let mut text: Vec<usize> = Vec::new();
+    // owner[i] is the index of the string the position i belongs to
+    let mut owner: Vec<usize> = Vec::new();
+    for (idx, chain) in chains.iter().enumerate() {
+        for c in chain {
+            text.push(k + alphabet.binary_search(c).unwrap());
+            owner.push(idx);
+        }
+        text.push(idx);
+        owner.push(idx);
+    }
+
+    let sa = sort_suffixes(&text);
+    let lcp = lcp_array(&text, &sa);
+
+    // sliding window over the suffix array, `count[i]` is the number of suffixes of
+    // the string i inside the window, `covered` the number of strings represented
+    let mut count = vec![0usize; k];
+    let mut covered = 0;
+    // indices of the lcp array, with increasing lcp values
+    let mut window_min: VecDeque<usize> = VecDeque::new();
+    let mut best_len = 0;
+    let mut best_start = 0;
+    let mut left = 0;
+
+    for right in 0..sa.len() {
+        let r_owner = owner[sa[right]];
+        count[r_owner] += 1;
+        if count[r_owner] == 1 {
+            covered += 1;
+        }
+        // lcp[right] is the common prefix length of sa[right - 1] and sa[right]
+        if right > left {
+            while window_min.back().is_some_and(|&b| lcp[b] >= lcp[right]) {
+                window_min.pop_back();
+            }
+            window_min.push_back(right);
+        }
+
+        while covered == k {
+            if let Some(&m) = window_min.front() {
+                if lcp[m] > best_len {
+                    best_len = lcp[m];
+                    best_start = sa[m];
+                }
+            }
+            let l_owner = owner[sa[left]];
+            count[l_owner] -= 1;
+            if count[l_owner] == 0 {
+                covered -= 1;
+            }
+            left += 1;
+            // the lcp between sa[left - 1] and sa[left] leaves the window
+            while window_min.front().is_some_and(|&f| f <= left) {
+                window_min.pop_front();
+            }
+        }
+    }
+
+    text[best_start..best_start + best_len]
+        .iter()
+        .map(|&c| alphabet[c - k])
+        .collect()
+}
+
+/// Finds the longest substring common to every string of `chains`.
+///
+/// A substring of length l common to all strings has a common substring of every length
+/// below l, so the largest length having one is found by binary search. A length is
+/// checked by hashing the windows of that length of every string: a window of the first
+/// string is kept while each other string has a window of the same hash, the windows of
+/// equal hashes being compared char by char so that a collision is never taken as a
+/// match.
+///
+/// # Arguments
+///
+/// * `chains` - The strings among which the common substring is searched.
+///
+/// # Returns
+///
+/// The longest substring shared by all strings, the lexicographically smallest one among
+/// equally long candidates, as `longest_common_substring_all`. An empty slice, or strings
+/// without any common character, give an empty string.
+///
+/// Unlike `longest_common_substring_all`, its time is only expected: the module
+/// documentation tells when to use which.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::multi_longest_common_substring;
+///
+/// assert_eq!(
+///     multi_longest_common_substring(&["xabcdey", "zzabcdq", "abcdabc"]),
+///     "abcd"
+/// );
+/// assert_eq!(multi_longest_common_substring(&["abc", "def"]), "");
+/// ```
+pub fn multi_longest_common_substring(chains: &[&str]) -> String {
+    longest_common_window(chains, window_hashes)
+}
+
+/// `multi_longest_common_substring`, the windows being hashed by `hash`.
+fn longest_common_window(chains: &[&str], hash: fn(&[char], usize) -> Vec<u64>) -> String {
+    let chains: Vec<Vec<char>> = chains.iter().map(|s| s.chars().collect()).collect();
+    let Some(shortest) = chains.iter().map(Vec::len).min() else {
+        return String::new();
+    };
+
+    // there is a common substring of lo chars, and none longer than hi chars
+    let (mut lo, mut hi) = (0, shortest);
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if common_windows(&chains, mid, hash).is_empty() {
+            hi = mid - 1;
+        } else {
+            lo = mid;
+        }
+    }
+    if lo == 0 {
+        return String::new();
+    }
+    common_windows(&chains, lo, hash)
+        .into_iter()
+        .map(|start| &chains[0][start..start + lo])
+        .min()
+        .map_or_else(String::new, |window| window.iter().collect())
+}
+
+/// The starts of the windows of `len > 0` chars of the first string occurring in every
+/// other string, the windows of equal hashes being compared char by char.
+fn common_windows(
+    chains: &[Vec<char>],
+    len: usize,
+    hash: fn(&[char], usize) -> Vec<u64>,
+) -> Vec<usize> {
+    let first = &chains[0];
+    let hashes = hash(first, len);
+    let mut starts: Vec<usize> = (0..hashes.len()).collect();
+    for other in &chains[1..] {
+        let mut windows: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (start, h) in hash(other, len).into_iter().enumerate() {
+            windows.entry(h).or_default().push(start);
+        }
+        starts.retain(|&s| {
+            windows.get(&hashes[s]).is_some_and(|candidates| {
+                candidates
+                    .iter()
+                    .any(|&t| other[t..t + len] == first[s..s + len])
+            })
+        });
+        if starts.is_empty() {
+            break;
+        }
+    }
+    starts
+}
+
+/// The polynomial hashes of the windows of `len > 0` chars of `s`, by increasing start,
+/// each one computed from the previous one in O(1).
+fn window_hashes(s: &[char], len: usize) -> Vec<u64> {
+    let mul = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(MODULUS)) as u64;
+    // the weight of the char leaving the window
+    let leaving = (1..len).fold(1, |power, _| mul(power, BASE));
+    let mut hashes = Vec::with_capacity((s.len() + 1).saturating_sub(len));
+    let mut hash = 0;
+    for (i, &c) in s.iter().enumerate() {
+        if i >= len {
+            hash = (hash + MODULUS - mul(u64::from(s[i - len]), leaving)) % MODULUS;
+        }
+        hash = (mul(hash, BASE) + u64::from(c)) % MODULUS;
+        if i + 1 >= len {
+            hashes.push(hash);
+        }
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::multiple_longest_common_subsequence::fixtures;
+    use super::*;
+    use crate::string::random_strings::Xorshift;
+
+    macro_rules! test_longest_common_substring_pair {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2, expected) = $inputs;
+                    assert_eq!(longest_common_substring_pair(s1, s2), expected);
+                    assert_eq!(
+                        longest_common_substring_all(&[s1, s2]).chars().count(),
+                        expected.chars().count()
+                    );
+                }
+            )*
+        }
+    }
+
+    test_longest_common_substring_pair! {
+        pair_empty_strings: ("", "", ""),
+        pair_one_empty: ("", "abc", ""),
+        pair_no_common: ("abc", "xyz", ""),
+        pair_substring_not_subsequence: ("ABCDEF", "BCDFGH", "BCD"),
+        pair_identical: ("GeeksforGeeks", "GeeksforGeeks", "GeeksforGeeks"),
+        pair_overlapping: ("abcdxyz", "xyzabcd", "abcd"),
+        pair_unicode: ("串用于测试展示", "展示测试用于", "用于"),
+        pair_repeated_chars: ("aaaaaaaaaa", "aaa", "aaa"),
+    }
+
+    macro_rules! test_longest_common_substring_all {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (strings, expected) = $inputs;
+                    assert_eq!(longest_common_substring_all(&strings), expected);
+                }
+            )*
+        }
+    }
+
+    test_longest_common_substring_all! {
+        all_empty_input: ([] as [&str; 0], ""),
+        all_single_string: (["abc"], "abc"),
+        all_substring_not_subsequence: (["ABCDEF", "BCDFGH"], "BCD"),
+        all_three_strings: (["xabcdey", "zzabcdq", "abcdabc"], "abcd"),
+        all_no_common: (["abc", "def", "ghi"], ""),
+        all_one_empty: (["abc", "", "abc"], ""),
+        all_ties_smallest: (["xyab", "abxy"], "ab"),
+        all_unicode: (["🚀串用于测试", "测试串用于🚀", "用于测试"], "测试"),
+        all_identical: (["banana", "banana", "banana"], "banana"),
+    }
+
+    macro_rules! test_multi_longest_common_substring {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (strings, expected) = $inputs;
+                    assert_eq!(multi_longest_common_substring(&strings), expected);
+                    assert_eq!(longest_common_substring_all(&strings), expected);
+                }
+            )*
+        }
+    }
+
+    test_multi_longest_common_substring! {
+        multi_empty_input: ([] as [&str; 0], ""),
+        multi_single_string: (["abc"], "abc"),
+        multi_three_strings: (["xabcdey", "zzabcdq", "abcdabc"], "abcd"),
+        multi_single_char: (["xay", "bac", "cab", "a"], "a"),
+        multi_no_common: (["abc", "def", "ghi"], ""),
+        multi_one_empty: (["abc", "", "abc"], ""),
+        multi_ties_smallest: (["xyab", "abxy"], "ab"),
+        multi_unicode: (["🚀串用于测试", "测试串用于🚀", "用于测试"], "测试"),
+        multi_unicode_pair: (["串用于测试展示", "展示测试用于"], "展示"),
+        multi_emoji: (["🚀🚀a🚀", "a🚀🚀", "🚀🚀"], "🚀🚀"),
+        multi_repeated_chars: (["aaaaaaaaaa", "aaa", "aaaa"], "aaa"),
+    }
+
+    #[test]
+    fn windows_sliding_along_the_string() {
+        let s: Vec<char> = "abcabc".chars().collect();
+        for len in 1..=s.len() {
+            let hashes = window_hashes(&s, len);
+            assert_eq!(hashes.len(), s.len() - len + 1);
+            // the rolled hashes are those of the windows hashed on their own
+            for (start, &hash) in hashes.iter().enumerate() {
+                assert_eq!(window_hashes(&s[start..start + len], len), [hash]);
+            }
+        }
+        assert_eq!(window_hashes(&s, 3)[0], window_hashes(&s, 3)[3]);
+        assert!(window_hashes(&s, 7).is_empty());
+    }
+
+    #[test]
+    fn collisions_are_not_matches() {
+        // every window has the same hash, only the comparison of the chars tells them apart
+        let collide: fn(&[char], usize) -> Vec<u64> =
+            |s, len| vec![0; (s.len() + 1).saturating_sub(len)];
+        for (strings, expected) in [
+            (["xabcdey", "zzabcdq", "abcdabc"].as_slice(), "abcd"),
+            (&["abc", "def", "ghi"], ""),
+            (&["mississippi", "missouri", "sip"], "i"),
+        ] {
+            assert_eq!(longest_common_window(strings, collide), expected);
+        }
+    }
+
+    #[test]
+    fn multi_matches_suffix_array_on_random_inputs() {
+        let mut rng = Xorshift::default();
+        for _ in 0..300 {
+            let letters = 1 + rng.below(4);
+            let strings: Vec<String> = (0..=rng.below(4))
+                .map(|_| rng.string_below(20, letters))
+                .collect();
+            let strings: Vec<&str> = strings.iter().map(String::as_str).collect();
+            assert_eq!(
+                multi_longest_common_substring(&strings),
+                longest_common_substring_all(&strings),
+                "{strings:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn multi_matches_suffix_array_on_many_unequal_strings() {
+        let mut rng = Xorshift::default();
+        for _ in 0..20 {
+            let letters = 1 + rng.below(3);
+            let core = rng.string_below(8, letters);
+            // a short string among long ones sharing it, many of them
+            let mut strings = vec![core.clone()];
+            for _ in 0..30 {
+                let (prefix, suffix) = (
+                    rng.string_below(200, letters),
+                    rng.string_below(200, letters),
+                );
+                strings.push(format!("{prefix}{core}{suffix}"));
+            }
+            let strings: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let expected = longest_common_substring_all(&strings);
+            assert_eq!(multi_longest_common_substring(&strings), expected);
+            // the short string is in all the others
+            assert_eq!(expected, core);
+        }
+    }
+
+    #[test]
+    fn multi_matches_suffix_array_on_mlcs_fixtures() {
+        for fixture in &fixtures::FIXTURES {
+            let expected = longest_common_substring_all(fixture.input);
+            assert_eq!(
+                multi_longest_common_substring(fixture.input),
+                expected,
+                "{}",
+                fixture.name
+            );
+            assert!(
+                fixture.input.iter().all(|s| s.contains(&expected)),
+                "{}",
+                fixture.name
+            );
+        }
+    }
+
+    #[test]
+    fn all_matches_pair_on_many_inputs() {
+        let words = [
+            "mississippi",
+            "missouri",
+            "pepper",
+            "sip",
+            "",
+            "ppi",
+            "issi",
+        ];
+        for a in words {
+            for b in words {
+                assert_eq!(
+                    longest_common_substring_all(&[a, b]).len(),
+                    longest_common_substring_pair(a, b).len(),
+                    "{a} / {b}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Lyndon words and related string canonicalization tools.
+//!
+//! A Lyndon word is a non-empty string that is strictly smaller (lexicographically) than
+//! all of its nontrivial rotations. Every string has a unique factorization
+//! `s = w_1 w_2 ... w_k` into Lyndon words with `w_1 >= w_2 >= ... >= w_k`
+//! (Chen-Fox-Lyndon theorem), which Duval's algorithm computes in linear time.
+//!
+//! All functions compare `char`s, so they work on arbitrary Unicode text.
+
+use super::duval_algorithm::duval_step;
+
+/// Factorizes a string into its Lyndon words using Duval's algorithm.
+///
+/// Unlike `duval_algorithm`, the factors are returned as slices of the input, by the same
+/// steps.
+///
+/// # Complexity
+///
+/// O(n) time and O(1) extra space besides the returned factors: the steps read the chars
+/// of `s` in place, at byte offsets, and the factors are sliced at these offsets.
+pub fn lyndon_factorization(s: &str) -> Vec<&str> {
+    let mut factors = Vec::new();
+
+    let mut start = 0;
+    while start < s.len() {
+        let (period, end) = duval_step(s, start);
+        // the scanned part is made of repetitions of the same Lyndon word of `period` bytes
+        while start + period <= end {
+            factors.push(&s[start..start + period]);
+            start += period;
+        }
+    }
+
+    factors
+}
+
+/// Returns the length, in chars, of the longest prefix of `s` which is a Lyndon word.
+///
+/// This is the length of the first factor of the Lyndon factorization, 0 for an empty string.
+pub fn lyndon_word_length(s: &str) -> usize {
+    if s.is_empty() {
+        return 0;
+    }
+    s[..duval_step(s, 0).0].chars().count()
+}
+
+/// Checks if `s` is a Lyndon word, i.e. a non-empty string strictly smaller than all its
+/// nontrivial rotations.
+pub fn is_lyndon_word(s: &str) -> bool {
+    !s.is_empty() && lyndon_word_length(s) == s.chars().count()
+}
+
+/// Computes the lexicographically smallest rotation of `s` using Booth's algorithm.
+///
+/// A rotation is in general not a substring of `s`, so an owned `String` is returned.
+///
+/// # Complexity
+///
+/// O(n) time and space.
+pub fn smallest_rotation(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let start = least_rotation(&chars);
+    chars[start..].iter().chain(&chars[..start]).collect()
+}
+
+/// Booth's algorithm: returns the index at which the least rotation of `chars` starts.
+/// A failure function is computed over the doubled string, `k` being the start of the
+/// best rotation found so far.
+fn least_rotation(chars: &[char]) -> usize {
+    let n = chars.len();
+    let at = |i: usize| chars[i % n];
+    let mut failure: Vec<Option<usize>> = vec![None; 2 * n];
+    let mut k = 0;
+
+    for j in 1..2 * n {
+        let c = at(j);
+        let mut i = failure[j - k - 1];
+        while let Some(fi) = i {
+            if c == at(k + fi + 1) {
+                break;
+            }
+            if c < at(k + fi + 1) {
+                k = j - fi - 1;
+            }
+            i = failure[fi];
+        }
+        match i {
+            Some(fi) => failure[j - k] = Some(fi + 1),
+            None if c != at(k) => {
+                // here i == None, so the comparison is made with chars[k]
+                if c < at(k) {
+                    k = j;
+                }
+                failure[j - k] = None;
+            }
+            None => failure[j - k] = Some(0),
+        }
+    }
+
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_lyndon_factorization {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (text, expected) = $inputs;
+                    let factors = lyndon_factorization(text);
+                    assert_eq!(factors, expected);
+                    assert_eq!(factors.concat(), text);
+                    assert!(factors.iter().all(|f| is_lyndon_word(f)));
+                    assert!(factors.windows(2).all(|w| w[0] >= w[1]));
+                }
+            )*
+        }
+    }
+
+    test_lyndon_factorization! {
+        empty: ("", Vec::<&str>::new()),
+        single_char: ("x", vec!["x"]),
+        repeating_with_suffix: ("abcdabcdababc", vec!["abcd", "abcd", "ababc"]),
+        single_repeating_char: ("aaa", vec!["a", "a", "a"]),
+        whole_word: ("ababb", vec!["ababb"]),
+        palindrome: ("racecar", vec!["r", "acecar"]),
+        decreasing: ("cba", vec!["c", "b", "a"]),
+        unicode: ("αβγαβ", vec!["αβγ", "αβ"]),
+        emoji: ("🏠a🏠", vec!["🏠", "a🏠"]),
+    }
+
+    #[test]
+    fn matches_duval_algorithm() {
+        use crate::string::duval_algorithm;
+        for s in ["abcxabc", "a1b2c3a1", "zyxwvu", "abababab", "അഅഅ"] {
+            assert_eq!(lyndon_factorization(s), duval_algorithm(s));
+        }
+    }
+
+    #[test]
+    fn lyndon_words() {
+        assert!(is_lyndon_word("a"));
+        assert!(is_lyndon_word("ab"));
+        assert!(is_lyndon_word("aab"));
+        assert!(is_lyndon_word("abacb"));
+        assert!(!is_lyndon_word(""));
+        assert!(!is_lyndon_word("aa"));
+        assert!(!is_lyndon_word("ba"));
+        assert!(!is_lyndon_word("abab"));
+        assert!(!is_lyndon_word("aba"));
+    }
+
+    #[test]
+    fn longest_lyndon_prefix() {
+        assert_eq!(lyndon_word_length(""), 0);
+        assert_eq!(lyndon_word_length("aaa"), 1);
+        assert_eq!(lyndon_word_length("abcdabcdababc"), 4);
+        assert_eq!(lyndon_word_length("ababbab"), 5);
+        assert_eq!(lyndon_word_length("串用"), 2);
+    }
+
+    #[test]
+    fn smallest_rotations() {
+        assert_eq!(smallest_rotation(""), "");
+        assert_eq!(smallest_rotation("a"), "a");
+        assert_eq!(smallest_rotation("bca"), "abc");
+        assert_eq!(smallest_rotation("baaa"), "aaab");
+        assert_eq!(smallest_rotation("abab"), "abab");
+        assert_eq!(smallest_rotation("cabcab"), "abcabc");
+        assert_eq!(smallest_rotation("🏠a🏠"), "a🏠🏠");
+    }
+
+    #[test]
+    fn smallest_rotation_matches_naive() {
+        let words = [
+            "bbaaccaadd",
+            "dcba",
+            "aabaa",
+            "abcabcabd",
+            "zzzyzzz",
+            "ccbcb",
+        ];
+        for w in words {
+            let chars: Vec<char> = w.chars().collect();
+            let naive = (0..chars.len())
+                .map(|i| chars[i..].iter().chain(&chars[..i]).collect::<String>())
+                .min()
+                .unwrap();
+            assert_eq!(smallest_rotation(w), naive);
+        }
+    }
+}
@@ -54,8 +54,7 @@ pub fn manacher(s: String) -> String {
         radius += 1;
         // 2: Checking palindrome.
         // Need to care about overflow usize.
-        while i >= radius && i + radius <= chars.len() - 1 && chars[i - radius] == chars[i + radius]
-        {
+        while i >= radius && i + radius < chars.len() && chars[i - radius] == chars[i + radius] {
             length_of_palindrome[i] += 2;
             radius += 1;
         }
@@ -75,9 +74,193 @@ pub fn manacher(s: String) -> String {
     answer.replace('#', "")
 }
 
+/// The length of the longest palindrome centered on each char and between each pair of
+/// consecutive chars of `s`, counted in chars
+///
+/// # Returns
+///
+/// 2n - 1 lengths for a string of n chars, empty for an empty string: the one centered on
+/// the first char, then between the first two chars, on the second char, and so on.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::all_palindromic_substrings_lengths;
+///
+/// assert_eq!(all_palindromic_substrings_lengths("abba"), [1, 0, 1, 4, 1, 0, 1]);
+/// ```
+///
+/// # Complexity
+///
+/// O(n) time and space.
+pub fn all_palindromic_substrings_lengths(s: &str) -> Vec<usize> {
+    let chars: Vec<char> = s.chars().collect();
+    if chars.is_empty() {
+        return vec![];
+    }
+    let radii = palindrome_radii(&chars);
+    // the centers before the first char and after the last one are always empty
+    radii[1..radii.len() - 1].to_vec()
+}
+
+/// Finds the longest palindromic substring of `s`, the first one if there are several
+///
+/// The palindromes are searched among the chars of `s`, not its bytes, so multi-byte
+/// chars are compared whole, and the result is a slice of `s` at char boundaries.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::longest_palindromic_substring;
+///
+/// assert_eq!(longest_palindromic_substring("x🏠a🏠y"), "🏠a🏠");
+/// ```
+///
+/// # Complexity
+///
+/// O(n) time and space for a string of n chars.
+pub fn longest_palindromic_substring(s: &str) -> &str {
+    let chars: Vec<char> = s.chars().collect();
+    let radii = palindrome_radii(&chars);
+    let Some((center, &len)) = radii.iter().enumerate().rev().max_by_key(|&(_, &len)| len) else {
+        return s;
+    };
+    // the center 2i + 1 is the char i, and the center 2i is before it
+    let start = (center - len) / 2;
+    let byte_offset = |i: usize| {
+        s.char_indices()
+            .nth(i)
+            .map_or(s.len(), |(offset, _)| offset)
+    };
+    &s[byte_offset(start)..byte_offset(start + len)]
+}
+
+/// Manacher's algorithm over the chars separated by gaps, a gap before, between and after
+/// each char, a gap matching a gap and never a char
+/// Returns for each of the 2n + 1 positions the length of the longest palindrome of
+/// `chars` centered there
+fn palindrome_radii(chars: &[char]) -> Vec<usize> {
+    let separated: Vec<Option<char>> = std::iter::once(None)
+        .chain(chars.iter().flat_map(|&c| [Some(c), None]))
+        .collect();
+    let n = separated.len();
+    let mut radii = vec![0; n];
+    // the palindrome reaching furthest to the right so far, by its center and right end
+    let (mut center, mut right) = (0, 0);
+    for i in 0..n {
+        let mut radius = if i < right {
+            radii[2 * center - i].min(right - i)
+        } else {
+            0
+        };
+        while i > radius
+            && i + radius + 1 < n
+            && separated[i - radius - 1] == separated[i + radius + 1]
+        {
+            radius += 1;
+        }
+        radii[i] = radius;
+        if i + radius > right {
+            center = i;
+            right = i + radius;
+        }
+    }
+    radii
+}
+
 #[cfg(test)]
 mod tests {
-    use super::manacher;
+    use super::*;
+
+    fn is_palindrome(chars: &[char]) -> bool {
+        chars.iter().eq(chars.iter().rev())
+    }
+
+    // the first longest palindrome, among every substring
+    fn brute_force(s: &str) -> String {
+        let chars: Vec<char> = s.chars().collect();
+        let mut best: &[char] = &[];
+        for start in 0..chars.len() {
+            for end in start + 1..=chars.len() {
+                if end - start > best.len() && is_palindrome(&chars[start..end]) {
+                    best = &chars[start..end];
+                }
+            }
+        }
+        best.iter().collect()
+    }
+
+    macro_rules! longest_palindrome_tests {
+        ($($name:ident: ($input:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (input, expected): (&str, &str) = ($input, $expected);
+                    assert_eq!(longest_palindromic_substring(input), expected);
+                    assert_eq!(brute_force(input), expected);
+                }
+            )*
+        };
+    }
+
+    longest_palindrome_tests! {
+        empty: ("", ""),
+        single_char: ("a", "a"),
+        odd: ("babad", "bab"),
+        even: ("cbbd", "bb"),
+        whole_string: ("racecar", "racecar"),
+        no_repeated_char: ("ac", "a"),
+        multi_byte_chars: ("午前中", "午"),
+        multi_byte_palindrome: ("午前午", "午前午"),
+        emoji: ("🏠a🏠", "🏠a🏠"),
+        mixed_widths: ("xé🏠éy", "é🏠é"),
+        same_first_byte: ("éè", "é"),
+        separator_chars: ("#a#b", "#a#"),
+    }
+
+    #[test]
+    fn lengths_at_each_center() {
+        assert_eq!(all_palindromic_substrings_lengths(""), []);
+        assert_eq!(all_palindromic_substrings_lengths("a"), [1]);
+        assert_eq!(all_palindromic_substrings_lengths("aba"), [1, 0, 3, 0, 1]);
+        assert_eq!(all_palindromic_substrings_lengths("🏠a🏠"), [1, 0, 3, 0, 1]);
+        assert_eq!(
+            all_palindromic_substrings_lengths("午前中"),
+            [1, 0, 1, 0, 1]
+        );
+        assert_eq!(
+            all_palindromic_substrings_lengths("aaaa"),
+            [1, 2, 3, 4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn lengths_match_the_brute_force() {
+        for s in ["abacabad", "串用串用串", "aabbaa🏠", "xyz", "abbabba"] {
+            let chars: Vec<char> = s.chars().collect();
+            let lengths = all_palindromic_substrings_lengths(s);
+            assert_eq!(lengths.len(), 2 * chars.len() - 1);
+            for (center, &len) in lengths.iter().enumerate() {
+                // a palindrome of odd length on a char, of even length between two chars
+                assert_eq!(len % 2, 1 - center % 2, "{s} {center}");
+                let start = (center + 1 - len) / 2;
+                assert!(is_palindrome(&chars[start..start + len]));
+                // it cannot be extended on both sides
+                if start > 0 && start + len < chars.len() {
+                    assert_ne!(chars[start - 1], chars[start + len], "{s} {center}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn result_is_a_slice_of_the_input() {
+        let s = "ab午前午cd";
+        let result = longest_palindromic_substring(s);
+        assert_eq!(result, "午前午");
+        let offset = result.as_ptr() as usize - s.as_ptr() as usize;
+        assert_eq!(offset, 2);
+    }
 
     #[test]
     fn get_longest_palindrome_by_manacher() {
@@ -0,0 +1,131 @@
+//! Minimal absent words (MAWs) of a string.
+//!
+//! A word w over the alphabet of s is a minimal absent word of s when w does not occur in s,
+//! but every proper factor of w does, i.e. w = a u b with a u and u b occurring in s.
+//! Only the chars of s are considered, otherwise every other char would be a MAW.
+//!
+//! The words are read from the suffix automaton of s, in O(n * σ) time for σ distinct chars:
+//! when a state p has no transition by b while its suffix link does, the shortest word of p
+//! followed by b is a MAW, and every MAW is found this way.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Absent_word),
+//! Crochemore, Mignosi, Restivo, "Automata and forbidden words" (1998)
+
+use super::SuffixAutomaton;
+
+/// Finds the minimal absent words of `s` of at most `max_length` chars
+///
+/// # Arguments
+///
+/// * `s` - The string whose absent words are searched.
+/// * `max_length` - The length of the longest MAW to report.
+///
+/// # Returns
+///
+/// * The MAWs, sorted by length and then lexicographically.
+pub fn minimal_absent_words(s: &str, max_length: usize) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let automaton = SuffixAutomaton::new(s);
+    let states = automaton.states();
+    let mut alphabet = chars.clone();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    let mut words: Vec<String> = vec![];
+    for state in states {
+        let Some(link) = state.link else {
+            // every char of the alphabet follows the empty word
+            continue;
+        };
+        let link = &states[link];
+        if link.len + 2 > max_length {
+            continue;
+        }
+        for &b in &alphabet {
+            if !state.next.contains_key(&b) && link.next.contains_key(&b) {
+                // the shortest word of the state: a followed by the longest word of its link
+                let start = state.first_end - link.len;
+                let mut word: String = chars[start..=state.first_end].iter().collect();
+                word.push(b);
+                words.push(word);
+            }
+        }
+    }
+
+    words.sort_unstable_by(|a, b| a.chars().count().cmp(&b.chars().count()).then(a.cmp(b)));
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every word over the alphabet of s of at most max_length chars which is absent
+    // from s while its prefix and suffix of one char less occur in s
+    fn naive_minimal_absent_words(s: &str, max_length: usize) -> Vec<String> {
+        let mut alphabet: Vec<char> = s.chars().collect();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        let mut words: Vec<String> = vec![String::new()];
+        let mut maws = vec![];
+        for _ in 0..max_length {
+            let mut longer = vec![];
+            for word in &words {
+                for &c in &alphabet {
+                    let mut w = word.clone();
+                    w.push(c);
+                    if s.contains(&w) {
+                        longer.push(w);
+                    } else if s.contains(&w[c.len_utf8()..]) {
+                        maws.push(w);
+                    }
+                }
+            }
+            words = longer;
+        }
+        maws.sort_unstable_by(|a, b| a.chars().count().cmp(&b.chars().count()).then(a.cmp(b)));
+        maws
+    }
+
+    macro_rules! maw_tests {
+        ($($name:ident: ($s:expr, $max_length:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let expected: Vec<&str> = $expected.to_vec();
+                    assert_eq!(minimal_absent_words($s, $max_length), expected);
+                    assert_eq!(naive_minimal_absent_words($s, $max_length), expected);
+                }
+            )*
+        };
+    }
+
+    maw_tests! {
+        empty: ("", 5, []),
+        single_char: ("a", 5, ["aa"]),
+        same_chars: ("aaaa", 10, ["aaaaa"]),
+        two_chars: ("ab", 5, ["aa", "ba", "bb"]),
+        abaab: ("abaab", 5, ["bb", "aaa", "bab", "aaba"]),
+        limited_length: ("abaab", 3, ["bb", "aaa", "bab"]),
+        nothing_short_enough: ("abaab", 1, []),
+        unicode: ("串用串", 5, ["串串", "用用", "用串用"]),
+    }
+
+    #[test]
+    fn maws_are_absent_and_minimal() {
+        let s = "abracadabra mississippi banana";
+        let maws = minimal_absent_words(s, 6);
+        assert!(!maws.is_empty());
+        for w in &maws {
+            assert!(!s.contains(w.as_str()), "{w} occurs");
+            let first = w.chars().next().unwrap();
+            let last = w.chars().last().unwrap();
+            assert!(s.contains(&w[first.len_utf8()..]), "{w} is not minimal");
+            assert!(
+                s.contains(&w[..w.len() - last.len_utf8()]),
+                "{w} is not minimal"
+            );
+        }
+        assert_eq!(maws, naive_minimal_absent_words(s, 6));
+    }
+}
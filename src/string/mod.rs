@@ -1,55 +1,152 @@
 mod aho_corasick;
 mod anagram;
+mod approximate_match;
+#[cfg(test)]
+mod assertions;
 mod autocomplete_using_trie;
+mod batch_mlcs;
+mod boyer_moore_horspool;
 mod boyer_moore_search;
 mod burrows_wheeler_transform;
+mod clustering;
+mod compression;
+mod dictionary_replacer;
+mod distance_trait;
 mod duval_algorithm;
+mod edit_distance;
 mod hamming_distance;
 mod isogram;
 mod isomorphism;
 mod jaro_winkler_distance;
 mod knuth_morris_pratt;
+mod lce;
+mod lcs_bitmask;
+mod lcs_circular;
+mod lcs_four_russians;
+mod lcs_gap_penalty;
+mod lcs_hirschberg;
+mod lcs_hunt_szymanski;
 mod levenshtein_distance;
 mod lipogram;
+mod longest_alternating_subsequence;
+mod longest_common_subsequence;
+mod longest_common_substring;
+mod lyndon;
 mod manacher;
+mod minimal_absent_words;
 mod multiple_longest_common_subsequence;
 mod palindrome;
 mod pangram;
+mod periodicity;
+mod progressive_alignment;
 mod rabin_karp;
+#[cfg(test)]
+mod random_strings;
+mod regex_simple;
 mod reverse;
+mod rolling_lcs;
+mod rotation;
 mod run_length_encoding;
+mod sequence_profile;
+mod shortest_common_supersequence;
 mod shortest_palindrome;
+mod substitution_matrix;
 mod suffix_array;
 mod suffix_array_manber_myers;
+mod suffix_automaton;
 mod suffix_tree;
+mod tandem_repeat;
+mod trie;
+mod wildcard_match;
 mod z_algorithm;
 
 pub use self::aho_corasick::AhoCorasick;
-pub use self::anagram::check_anagram;
+pub use self::anagram::{are_anagrams, check_anagram, group_anagrams};
+pub use self::approximate_match::{approximate_match, approximate_subsequence_match};
 pub use self::autocomplete_using_trie::Autocomplete;
+pub use self::batch_mlcs::{mlcs_batch, mlcs_batch_with_config};
+pub use self::boyer_moore_horspool::{bmh_search, bmh_search_str};
 pub use self::boyer_moore_search::boyer_moore_search;
 pub use self::burrows_wheeler_transform::{
     burrows_wheeler_transform, inv_burrows_wheeler_transform,
 };
+pub use self::clustering::{newick_to_string, upgma, NewickTree};
+pub use self::compression::{compression_ratio, kolmogorov_approximation, CompressionMethod};
+pub use self::dictionary_replacer::DictionaryReplacer;
+pub use self::distance_trait::{
+    most_similar, DamerauLevenshtein, Hamming, Jaro, JaroWinkler, Levenshtein, MlcsSimilarity,
+    NormalizedLcs, StringDistance,
+};
 pub use self::duval_algorithm::duval_algorithm;
+pub use self::edit_distance::{edit_distance_custom, EditCosts};
 pub use self::hamming_distance::hamming_distance;
 pub use self::isogram::is_isogram;
 pub use self::isomorphism::is_isomorphic;
 pub use self::jaro_winkler_distance::jaro_winkler_distance;
 pub use self::knuth_morris_pratt::knuth_morris_pratt;
-pub use self::levenshtein_distance::{naive_levenshtein_distance, optimized_levenshtein_distance};
+pub use self::lce::LceTable;
+pub use self::lcs_bitmask::lcs_bitmask;
+pub use self::lcs_circular::lcs_circular;
+pub use self::lcs_four_russians::lcs_four_russians;
+pub use self::lcs_gap_penalty::lcs_with_gap_penalty;
+pub use self::lcs_hirschberg::lcs_hirschberg;
+pub use self::lcs_hunt_szymanski::lcs_hunt_szymanski;
+pub use self::levenshtein_distance::{
+    apply_edit_script, compose_edit_scripts, edit_script, naive_levenshtein_distance,
+    optimized_levenshtein_distance, EditOp,
+};
 pub use self::lipogram::is_lipogram;
-pub use self::manacher::manacher;
-pub use self::multiple_longest_common_subsequence::multiple_longest_common_subsequence;
+pub use self::longest_alternating_subsequence::{
+    longest_alternating_subsequence, longest_alternating_subsequence_length,
+    longest_alternating_subsequence_str, longest_alternating_subsequence_with, Alternation,
+};
+pub use self::longest_common_subsequence::{count_distinct_lcs, lcs_length};
+pub use self::longest_common_substring::{
+    longest_common_substring_all, longest_common_substring_pair, multi_longest_common_substring,
+};
+pub use self::lyndon::{
+    is_lyndon_word, lyndon_factorization, lyndon_word_length, smallest_rotation,
+};
+pub use self::manacher::{
+    all_palindromic_substrings_lengths, longest_palindromic_substring, manacher,
+};
+pub use self::minimal_absent_words::minimal_absent_words;
+#[cfg(feature = "hda")]
+pub use self::multiple_longest_common_subsequence::mlcs_parallel;
+pub use self::multiple_longest_common_subsequence::{
+    dedup_similar, estimate_memory, lcs3, lcs3_with_limit, lcs_len_pair_with_scratch,
+    lcs_prefix_matrix, mlcs_approx, mlcs_at_least, mlcs_bytes, mlcs_cancellable, mlcs_chars,
+    mlcs_collapse_runs, mlcs_collapse_whitespace, mlcs_columns, mlcs_complement, mlcs_distance,
+    mlcs_exact, mlcs_ignoring, mlcs_into_string_reuse, mlcs_len_pair, mlcs_must_include,
+    mlcs_owned, mlcs_sequences, mlcs_similarity, mlcs_substitution, mlcs_with_max_alphabet,
+    mlcs_with_stats, multiple_longest_common_subsequence, pairwise_similarity_matrix,
+    top_k_common_subsequences, weighted_multiple_lcs, windowed_mlcs, AlphabetOrder, Context,
+    MemoryEstimate, MlcsBuilder, MlcsConfig, MlcsError, MlcsInput, MlcsOutcome, PreprocessedCorpus,
+    SearchStats, SharedContext, LCS3_DEFAULT_LIMIT,
+};
 pub use self::palindrome::is_palindrome;
 pub use self::pangram::is_pangram;
 pub use self::pangram::PangramStatus;
+pub use self::periodicity::{all_periods, is_periodic, minimal_period, primitive_root};
+pub use self::progressive_alignment::progressive_alignment;
 pub use self::rabin_karp::rabin_karp;
+pub use self::regex_simple::regex_match;
 pub use self::reverse::reverse;
+pub use self::rolling_lcs::RollingLcs;
+pub use self::rotation::{is_rotation, rotation_index};
 pub use self::run_length_encoding::{run_length_decoding, run_length_encoding};
+pub use self::sequence_profile::{SequenceProfile, SequenceProfileError};
+pub use self::shortest_common_supersequence::{
+    multiple_shortest_common_supersequence_length, shortest_common_supersequence, ScsBounds,
+};
 pub use self::shortest_palindrome::shortest_palindrome;
+pub use self::substitution_matrix::SubstitutionMatrix;
 pub use self::suffix_array::generate_suffix_array;
 pub use self::suffix_array_manber_myers::generate_suffix_array_manber_myers;
+pub use self::suffix_automaton::{count_distinct_substrings, SuffixAutomaton};
 pub use self::suffix_tree::{Node, SuffixTree};
+pub use self::tandem_repeat::{find_tandem_repeats, has_square, TandemRepeat};
+pub use self::trie::Trie;
+pub use self::wildcard_match::{wildcard_find, wildcard_match};
 pub use self::z_algorithm::match_pattern;
 pub use self::z_algorithm::z_array;
@@ -0,0 +1,60 @@
+//! Bounds on the length of the subsequence, used to cut the search short.
+//!
+//! The largest f among the starting points bounds the length of any common subsequence,
+//! since every common subsequence starts with one of them. As soon as a point reaches that
+//! bound, no other point can do better and the search stops.
+//!
+//! The deepest point reached so far, a starting point or a point of the previous bands,
+//! is kept as an incumbent: a point whose f is below the g of the incumbent cannot lead to
+//! a longer subsequence and is not expanded. When the search ends, the incumbent is
+//! returned if it is deeper than the goal.
+
+use super::QueueNode;
+use std::collections::BinaryHeap;
+
+type Point = Vec<Option<usize>>;
+
+pub(super) struct Bounds {
+    upper: u64,
+    incumbent: Option<(u64, Point)>,
+}
+
+impl Bounds {
+    pub(super) fn new(queue: &BinaryHeap<QueueNode>) -> Self {
+        let mut bounds = Bounds {
+            upper: queue.iter().map(|node| node.f).max().unwrap_or(0),
+            incumbent: None,
+        };
+        bounds.update(queue.iter().map(|node| (node.f - node.h, &node.point)));
+        bounds
+    }
+
+    /// Checks if a point with this g matches as many chars as possible
+    pub(super) fn reached(&self, g: u64) -> bool {
+        g >= self.upper
+    }
+
+    /// Checks if no successor of the node can lead further than the incumbent
+    pub(super) fn prunes(&self, node: &QueueNode) -> bool {
+        self.incumbent.as_ref().is_some_and(|(g, _)| node.f < *g)
+    }
+
+    /// Keeps the deepest of the points reached by a band
+    pub(super) fn update<'a>(&mut self, points: impl Iterator<Item = (u64, &'a Point)>) {
+        for (g, point) in points {
+            if self.incumbent.as_ref().is_none_or(|(best, _)| g > *best) {
+                self.incumbent = Some((g, point.clone()));
+            }
+        }
+    }
+
+    /// Chooses the point the subsequence is rebuilt from, between the goal of the search
+    /// and the incumbent
+    pub(super) fn best(self, goal: Option<Point>, g: impl Fn(&Point) -> u64) -> Option<Point> {
+        match (goal, self.incumbent) {
+            (Some(goal), Some((best, incumbent))) if best > g(&goal) => Some(incumbent),
+            (Some(goal), _) => Some(goal),
+            (None, incumbent) => incumbent.map(|(_, point)| point),
+        }
+    }
+}
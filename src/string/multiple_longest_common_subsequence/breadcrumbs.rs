@@ -0,0 +1,117 @@
+//! Compact storage of the search tree, used when
+//! `MlcsConfig::low_memory_reconstruction` is set.
+//!
+//! The default search keeps three maps keyed by points, `parents` also holding a copy of
+//! the parent point as its value. Here every point is stored once, as the key of `ids`,
+//! and everything the search and the reconstruction need is kept in a fixed-size
+//! breadcrumb: the id of the parent, the char matched at the point and its costs.
+//! Walking the breadcrumbs from a point yields the same subsequence as walking `parents`.
+
+use std::collections::HashMap;
+
+type Point = Vec<Option<usize>>;
+
+// parent : the id of the parent, None for the root
+// ch : the char matched at the point, None for the root
+// gain : the number of times ch is repeated in the subsequence
+// g, f : the costs of the point
+#[derive(Clone, Debug)]
+struct Breadcrumb<T> {
+    parent: Option<usize>,
+    ch: Option<T>,
+    gain: u64,
+    g: u64,
+    f: u64,
+}
+
+/// The search tree as an arena of breadcrumbs, the root having id 0
+#[derive(Debug)]
+pub(super) struct Breadcrumbs<T> {
+    ids: HashMap<Point, usize>,
+    crumbs: Vec<Breadcrumb<T>>,
+}
+
+impl<T: Clone> Breadcrumbs<T> {
+    /// A tree holding only the root, the impossible point of dimension `d`
+    pub(super) fn new(d: usize) -> Self {
+        let root = Breadcrumb {
+            parent: None,
+            ch: None,
+            gain: 0,
+            g: 0,
+            f: 0,
+        };
+        Breadcrumbs {
+            ids: HashMap::from([(vec![None; d], 0)]),
+            crumbs: vec![root],
+        }
+    }
+
+    /// The number of points in the tree, the root included
+    pub(super) fn len(&self) -> usize {
+        self.crumbs.len()
+    }
+
+    pub(super) fn g(&self, p: &Point) -> u64 {
+        self.crumbs[self.ids[p]].g
+    }
+
+    pub(super) fn f(&self, p: &Point) -> u64 {
+        self.crumbs[self.ids[p]].f
+    }
+
+    /// Marks `parent` as the parent of `q`, replacing any previous breadcrumb of `q`
+    pub(super) fn insert(
+        &mut self,
+        q: Point,
+        parent: &Point,
+        ch: Option<T>,
+        gain: u64,
+        g: u64,
+        f: u64,
+    ) {
+        let crumb = Breadcrumb {
+            parent: Some(self.ids[parent]),
+            ch,
+            gain,
+            g,
+            f,
+        };
+        match self.ids.get(&q) {
+            Some(&id) => self.crumbs[id] = crumb,
+            None => {
+                self.ids.insert(q, self.crumbs.len());
+                self.crumbs.push(crumb);
+            }
+        }
+    }
+
+    /// Appends to `buf` the chars matched from the root down to `p`, each repeated
+    /// `gain` times
+    pub(super) fn sequence_into(&self, p: &Point, buf: &mut impl Extend<T>) {
+        let mut chars = vec![];
+        for crumb in self.path(p) {
+            if let Some(ch) = &crumb.ch {
+                for _ in 0..crumb.gain {
+                    chars.push(ch.clone());
+                }
+            }
+        }
+        buf.extend(chars.into_iter().rev());
+    }
+
+    /// The length of the subsequence ending at `p`
+    pub(super) fn depth(&self, p: &Point) -> u64 {
+        self.path(p).map(|crumb| crumb.gain).sum()
+    }
+
+    // the breadcrumbs from p up to the root, the root excluded
+    fn path(&self, p: &Point) -> impl Iterator<Item = &Breadcrumb<T>> {
+        let mut id = Some(self.ids[p]);
+        std::iter::from_fn(move || {
+            let crumb = &self.crumbs[id?];
+            id = crumb.parent;
+            crumb.parent.map(|_| crumb)
+        })
+    }
+}
@@ -0,0 +1,241 @@
+//! A builder gathering the options of an MLCS search.
+//!
+//! Its defaults are the ones of `multiple_longest_common_subsequence`, each method
+//! changing a single option.
+
+use super::{prepare_chains, to_chains, AlphabetOrder, MlcsConfig, Prepared, SearchStats};
+use std::time::Duration;
+
+/// Configures and runs an MLCS search
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use the_algorithms_rust::string::MlcsBuilder;
+///
+/// let outcome = MlcsBuilder::new(&["ABC", "ac", "bAC"])
+///     .width(10)
+///     .case_insensitive(true)
+///     .timeout(Duration::from_secs(10))
+///     .run();
+/// assert_eq!(outcome.subsequence, "ac");
+/// assert!(outcome.is_complete());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MlcsBuilder<'a> {
+    chains: &'a [&'a str],
+    config: MlcsConfig,
+    case_insensitive: bool,
+    alphabet_order: AlphabetOrder,
+}
+
+/// The result of a search run by `MlcsBuilder`
+///
+/// # Fields
+/// * `subsequence` - the common subsequence found, a longest one unless the search
+///   was interrupted
+/// * `stats` - the statistics of the search
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MlcsOutcome {
+    pub subsequence: String,
+    pub stats: SearchStats,
+}
+
+impl MlcsOutcome {
+    /// Whether the search ran to its end, without reaching `max_nodes` or `timeout`
+    pub fn is_complete(&self) -> bool {
+        !self.stats.interrupted
+    }
+}
+
+impl<'a> MlcsBuilder<'a> {
+    pub fn new(chains: &'a [&'a str]) -> Self {
+        MlcsBuilder {
+            chains,
+            config: MlcsConfig::default(),
+            case_insensitive: false,
+            alphabet_order: AlphabetOrder::default(),
+        }
+    }
+
+    /// Sets `MlcsConfig::width`
+    pub fn width(mut self, width: u64) -> Self {
+        self.config.width = width;
+        self
+    }
+
+    /// Sets `MlcsConfig::low_memory_reconstruction`
+    pub fn low_memory_reconstruction(mut self, low_memory_reconstruction: bool) -> Self {
+        self.config.low_memory_reconstruction = low_memory_reconstruction;
+        self
+    }
+
+    /// Compares the chars ignoring their case, the subsequence being returned in lowercase
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Tries the letters in `alphabet_order`, which decides the longest subsequence
+    /// returned when there are several
+    pub fn alphabet_order(mut self, alphabet_order: AlphabetOrder) -> Self {
+        self.alphabet_order = alphabet_order;
+        self
+    }
+
+    /// Sets `MlcsConfig::timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets `MlcsConfig::max_nodes`
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.config.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Runs the search
+    pub fn run(&self) -> MlcsOutcome {
+        let chains = if self.case_insensitive {
+            let lowered: Vec<String> = self.chains.iter().map(|s| s.to_lowercase()).collect();
+            let lowered: Vec<&str> = lowered.iter().map(String::as_str).collect();
+            to_chains(&lowered)
+        } else {
+            to_chains(self.chains)
+        };
+        // the answer of the shortcut is the only longest subsequence, whatever the order
+        let (subsequence, stats) = match prepare_chains(chains) {
+            Prepared::Solved(mlcs) => (mlcs.iter().collect(), SearchStats::solved(&mlcs)),
+            Prepared::Search(mut ctx) => {
+                ctx.sort_alphabet(self.alphabet_order);
+                ctx.solve_with_stats(&self.config)
+            }
+        };
+        MlcsOutcome { subsequence, stats }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, multiple_longest_common_subsequence, Context};
+    use super::*;
+
+    #[test]
+    fn defaults_are_the_plain_search() {
+        let inputs: [&[&str]; 5] = [
+            fixtures::LONG.input,
+            fixtures::MEDIUM_PLUS.input,
+            &["ABC", "AC", "BAC"],
+            &["abc", "def"],
+            &["", "abc"],
+        ];
+        for input in inputs {
+            let outcome = MlcsBuilder::new(input).run();
+            assert_eq!(
+                outcome.subsequence,
+                multiple_longest_common_subsequence(&input.to_vec())
+            );
+            assert!(outcome.is_complete());
+        }
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let input = ["HeLLo WoRLD", "hello world", "HELLO, WORLD"];
+        assert_eq!(MlcsBuilder::new(&input).run().subsequence, " ");
+        let outcome = MlcsBuilder::new(&input).case_insensitive(true).run();
+        assert_eq!(outcome.subsequence, "hello world");
+    }
+
+    #[test]
+    fn width_and_low_memory() {
+        let input = fixtures::MEDIUM_PLUS.input;
+        let outcome = MlcsBuilder::new(input)
+            .width(0)
+            .low_memory_reconstruction(true)
+            .run();
+        let config = MlcsConfig {
+            width: 0,
+            ..MlcsConfig::default()
+        };
+        assert_eq!(outcome.subsequence, Context::new(input).solve(&config));
+    }
+
+    #[test]
+    fn alphabet_order() {
+        // "za" and "zb" are both longest, and 'a' and 'b' follow the same 'z'
+        let input = ["zba", "zzab"];
+        let by_code_point = MlcsBuilder::new(&input).run();
+        assert_eq!(by_code_point.subsequence, "za");
+        let outcome = MlcsBuilder::new(&input)
+            .alphabet_order(AlphabetOrder::CodePoint)
+            .run();
+        assert_eq!(outcome, by_code_point);
+        // 'b' comes first in "zba", the smallest string
+        let outcome = MlcsBuilder::new(&input)
+            .alphabet_order(AlphabetOrder::FirstOccurrence)
+            .run();
+        assert_eq!(outcome.subsequence, "zb");
+    }
+
+    #[test]
+    fn same_output_for_every_order_of_the_strings() {
+        let strings = fixtures::synthetic(5, 30, 3, 7);
+        let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+        for alphabet_order in [AlphabetOrder::CodePoint, AlphabetOrder::FirstOccurrence] {
+            let expected = MlcsBuilder::new(&input)
+                .alphabet_order(alphabet_order)
+                .run();
+            for shift in 1..input.len() {
+                let mut rotated = input.clone();
+                rotated.rotate_left(shift);
+                let outcome = MlcsBuilder::new(&rotated)
+                    .alphabet_order(alphabet_order)
+                    .run();
+                assert_eq!(outcome.subsequence, expected.subsequence, "{rotated:?}");
+            }
+            let reversed: Vec<&str> = input.iter().rev().copied().collect();
+            let outcome = MlcsBuilder::new(&reversed)
+                .alphabet_order(alphabet_order)
+                .run();
+            assert_eq!(outcome.subsequence, expected.subsequence);
+        }
+    }
+
+    #[test]
+    fn zero_timeout_interrupts() {
+        let outcome = MlcsBuilder::new(fixtures::LONG.input)
+            .timeout(Duration::ZERO)
+            .run();
+        assert!(!outcome.is_complete());
+        // the deepest starting point
+        assert_eq!(outcome.subsequence.chars().count(), 1);
+    }
+
+    #[test]
+    fn max_nodes_gives_a_common_subsequence() {
+        let strings = fixtures::synthetic(6, 40, 4, 11);
+        let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+        let full = MlcsBuilder::new(&input).run();
+        let outcome = MlcsBuilder::new(&input).max_nodes(40).run();
+        assert!(!outcome.is_complete());
+        assert!(!outcome.subsequence.is_empty());
+        assert!(outcome.subsequence.len() < full.subsequence.len());
+        fixtures::assert_valid_mlcs(&outcome.subsequence, &input, 1);
+        // the points of the last band are recorded before the search is interrupted
+        assert!(outcome.stats.nodes_expanded <= 40 + full.stats.max_queue_len);
+    }
+
+    #[test]
+    fn limits_above_the_work_done_change_nothing() {
+        let input = fixtures::MEDIUM_PLUS.input;
+        let full = MlcsBuilder::new(input).run();
+        let outcome = MlcsBuilder::new(input)
+            .max_nodes(full.stats.nodes_expanded)
+            .timeout(Duration::from_hours(1))
+            .run();
+        assert_eq!(outcome, full);
+    }
+}
@@ -0,0 +1,281 @@
+//! MLCS containing a required pattern as a subsequence.
+//!
+//! The states of the search are the points of the MLCS-A* search together with the number
+//! of chars of the pattern already matched. The pattern is matched greedily, each of its
+//! chars as soon as it occurs in the subsequence, and a subsequence contains the pattern
+//! exactly when this greedy matching reaches its end. A state is dropped as soon as the
+//! rest of the pattern no longer fits in the rest of some string.
+//!
+//! There is no band here: the states are expanded best first, and a state which matched
+//! the whole pattern is also queued as finished, with its g as f. The heuristic bounding
+//! from above the chars left to match, the first finished state taken from the queue is a
+//! longest common subsequence containing the pattern. The search being exact, it expands
+//! many more points than `multiple_longest_common_subsequence` on long strings.
+
+use super::{add_costs, Context};
+use std::collections::{BinaryHeap, HashMap};
+
+type Point = Vec<Option<usize>>;
+
+// the fields are compared in this order, the finished states coming first among equal f
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    f: u64,
+    finished: bool,
+    g: u64,
+    point: Point,
+    matched: usize,
+}
+
+/// Finds a longest common subsequence of `chains` having `required` as a subsequence
+///
+/// # Returns
+///
+/// None if no common subsequence contains `required`, i.e. if `required` is not a common
+/// subsequence itself.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_must_include;
+///
+/// // "BCBA" and "BCAB" are as long, but do not contain "DA"
+/// assert_eq!(
+///     mlcs_must_include(&["ABCBDAB", "BDCABA"], "DA"),
+///     Some("BDAB".to_string())
+/// );
+/// // "bc" is longer, but the only common subsequence with an 'a' is "a"
+/// assert_eq!(mlcs_must_include(&["abc", "bca"], "a"), Some("a".to_string()));
+/// assert_eq!(mlcs_must_include(&["abc", "bca"], "ab"), None);
+/// ```
+pub fn mlcs_must_include(chains: &[&str], required: &str) -> Option<String> {
+    let ctx = Context::new(chains);
+    let required: Vec<char> = required.chars().collect();
+    let mut search = ConstrainedSearch {
+        latest_starts: ctx
+            .chains
+            .iter()
+            .map(|s| latest_starts(s, &required))
+            .collect(),
+        ctx: &ctx,
+        required: &required,
+        g: HashMap::new(),
+        parents: HashMap::new(),
+        queue: BinaryHeap::new(),
+    };
+
+    let root = vec![None; ctx.d];
+    if !search.fits(&root, 0) {
+        return None;
+    }
+    if required.is_empty() {
+        search.queue.push(Node {
+            f: 0,
+            finished: true,
+            g: 0,
+            point: root.clone(),
+            matched: 0,
+        });
+    }
+    search.expand(&root, 0, 0);
+
+    while let Some(node) = search.queue.pop() {
+        if node.finished {
+            return Some(search.common_seq(node.point, node.matched));
+        }
+        // the state was reached again with a longer prefix after being queued
+        if search.g[&(node.point.clone(), node.matched)] > node.g {
+            continue;
+        }
+        search.expand(&node.point, node.matched, node.g);
+    }
+    None
+}
+
+// starts[k] is the last position from which required[k..] is a subsequence of s,
+// None if it is not a subsequence of s at all
+fn latest_starts(s: &[char], required: &[char]) -> Vec<Option<usize>> {
+    let mut starts = vec![None; required.len() + 1];
+    starts[required.len()] = Some(s.len());
+    for k in (0..required.len()).rev() {
+        starts[k] = starts[k + 1].and_then(|end| s[..end].iter().rposition(|&c| c == required[k]));
+    }
+    starts
+}
+
+// g : for each state, the length of the longest prefix reaching it
+// latest_starts : for each string, see `latest_starts`
+// parents : the state each state was reached from, the root having none
+struct ConstrainedSearch<'a> {
+    ctx: &'a Context,
+    required: &'a [char],
+    latest_starts: Vec<Vec<Option<usize>>>,
+    g: HashMap<(Point, usize), u64>,
+    parents: HashMap<(Point, usize), (Point, usize)>,
+    queue: BinaryHeap<Node>,
+}
+
+impl ConstrainedSearch<'_> {
+    // whether required[matched..] fits after the point in every string
+    fn fits(&self, point: &[Option<usize>], matched: usize) -> bool {
+        point
+            .iter()
+            .zip(&self.latest_starts)
+            .all(|(p, starts)| starts[matched].is_some_and(|start| p.map_or(0, |p| p + 1) <= start))
+    }
+
+    // queues the successors of a state, the root having the starting points as successors
+    fn expand(&mut self, point: &Point, matched: usize, g: u64) {
+        let successors = if point.iter().all(Option::is_none) {
+            self.ctx.get_starting_p()
+        } else {
+            self.ctx.get_successors(point)
+        };
+        // the g of the successors
+        let g = add_costs(g, 1);
+        for q in successors {
+            let Some(ch) = self.ctx.matched_char(&q) else {
+                continue;
+            };
+            let q_matched = matched + usize::from(self.required.get(matched) == Some(&ch));
+            if !self.fits(&q, q_matched) {
+                continue;
+            }
+            let key = (q.clone(), q_matched);
+            if self.g.get(&key).is_some_and(|&known| known >= g) {
+                continue;
+            }
+            self.g.insert(key.clone(), g);
+            self.parents.insert(key, (point.clone(), matched));
+            if q_matched == self.required.len() {
+                self.queue.push(Node {
+                    f: g,
+                    finished: true,
+                    g,
+                    point: q.clone(),
+                    matched: q_matched,
+                });
+            }
+            self.queue.push(Node {
+                f: add_costs(g, self.ctx.heuristic(&q)),
+                finished: false,
+                g,
+                point: q,
+                matched: q_matched,
+            });
+        }
+    }
+
+    // ascend back up the states to form the common subsequence
+    fn common_seq(&self, point: Point, matched: usize) -> String {
+        let mut common_subsequence = vec![];
+        let mut state = (point, matched);
+        while let Some(parent) = self.parents.get(&state) {
+            common_subsequence.extend(self.ctx.matched_char(&state.0));
+            state = parent.clone();
+        }
+        common_subsequence.iter().rev().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_exact};
+    use super::*;
+    use crate::dynamic_programming::is_subsequence;
+
+    // the length of the longest subsequence of the first string common to all of them and
+    // containing required, every subsequence being enumerated
+    fn brute_force(chains: &[&str], required: &str) -> Option<usize> {
+        let first: Vec<char> = chains[0].chars().collect();
+        (0..1u32 << first.len())
+            .filter_map(|mask| {
+                let sub: String = (0..first.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| first[i])
+                    .collect();
+                let valid = is_subsequence(required, &sub)
+                    && chains.iter().all(|s| is_subsequence(&sub, s));
+                valid.then_some(mask.count_ones() as usize)
+            })
+            .max()
+    }
+
+    fn check(chains: &[&str], required: &str) {
+        let result = mlcs_must_include(chains, required);
+        assert_eq!(
+            result.as_ref().map(|r| r.chars().count()),
+            brute_force(chains, required),
+            "{chains:?} {required:?}"
+        );
+        if let Some(result) = result {
+            assert!(is_subsequence(required, &result), "{result:?}");
+            fixtures::assert_valid_mlcs(&result, chains, 0);
+        }
+    }
+
+    #[test]
+    fn matches_the_brute_force() {
+        let inputs: [&[&str]; 5] = [
+            &["ABCBDAB", "BDCABA"],
+            &["abcab", "bacba", "cabab"],
+            &["aabba", "babab", "abbaa"],
+            &["串用于测试", "用于串测试", "测试串用于"],
+            &["xaybz", "yxazb", "xyabz"],
+        ];
+        for input in inputs {
+            for required in [
+                "", "a", "b", "ab", "ba", "aa", "bb", "aba", "DA", "CA", "测串", "xz", "yb",
+            ] {
+                check(input, required);
+            }
+        }
+    }
+
+    #[test]
+    fn short_strings() {
+        let strings = ["", "a", "b", "ab", "ba", "aab", "aba", "bba", "abab"];
+        for s1 in strings {
+            for s2 in strings {
+                for required in ["", "a", "ab", "ba", "bb"] {
+                    check(&[s1, s2], required);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_required() {
+        for input in [
+            ["ABCBDAB", "BDCABA"].as_slice(),
+            &["abcab", "bacba", "cabab"],
+            &["abc", "def"],
+        ] {
+            let result = mlcs_must_include(input, "").unwrap();
+            assert_eq!(result.chars().count(), mlcs_exact(input).chars().count());
+        }
+    }
+
+    #[test]
+    fn required_pattern_not_common() {
+        assert_eq!(mlcs_must_include(&["abc", "acb"], "bc"), None);
+        assert_eq!(mlcs_must_include(&["abc", "abc"], "d"), None);
+        assert_eq!(mlcs_must_include(&["abc", "abc"], "abcd"), None);
+    }
+
+    #[test]
+    fn whole_string_required() {
+        assert_eq!(
+            mlcs_must_include(&["abc", "xaybzc"], "abc"),
+            Some("abc".to_string())
+        );
+        assert_eq!(mlcs_must_include(&["abc"], "ac"), Some("abc".to_string()));
+    }
+
+    #[test]
+    fn no_string() {
+        assert_eq!(mlcs_must_include(&[], ""), Some(String::new()));
+        assert_eq!(mlcs_must_include(&[], "a"), None);
+        assert_eq!(mlcs_must_include(&["", ""], ""), Some(String::new()));
+    }
+}
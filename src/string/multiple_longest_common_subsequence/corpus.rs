@@ -0,0 +1,415 @@
+//! Preprocessing of a fixed set of reference strings, so that many MLCS queries
+//! against that set only have to compute the tables involving the query string.
+//!
+//! The tables can be written to disk with `save` and read back with `load`.
+//! The file layout is a small versioned binary format, all integers being little endian:
+//!
+//! ```text
+//! magic   : b"MLCSCORP"
+//! version : u32
+//! d       : u64, the number of strings, then for each string its length (u64) and chars (u32)
+//! alphabet: u64 length, then the chars (u32)
+//! mt      : for each letter, for each string, one u64 per position (u64::MAX for None)
+//! ms      : for each pair of strings i < j, sorted by i then j, the (n_i + 1) * (n_j + 1)
+//!           suffix table (u64)
+//! ```
+
+use super::{
+    get_alphabet, matrices_score, mt_table, next_positions, pair_count, pair_index, score_matrix,
+    shortcut, Context, MlcsConfig, NextPositions, SuffixTable,
+};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+const MAGIC: &[u8; 8] = b"MLCSCORP";
+const VERSION: u32 = 3;
+const NONE: u64 = u64::MAX;
+
+/// The preprocessing tables of a corpus of reference strings
+///
+/// # Fields
+/// * `chains` - the corpus strings
+/// * `alphabet` - the letters common to all corpus strings
+/// * `mt` - the lookup table of each letter of `alphabet`, for each corpus string
+/// * `ms` - the suffix tables between each pair of corpus strings i < j, see `pair_index`
+///
+/// The strings and the tables are shared with the search of each query, never copied.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PreprocessedCorpus {
+    chains: Vec<Arc<[char]>>,
+    alphabet: Vec<char>,
+    mt: Vec<Vec<NextPositions>>,
+    ms: Vec<SuffixTable>,
+}
+
+impl PreprocessedCorpus {
+    /// Computes the lookup tables and the pairwise suffix tables of the corpus
+    pub fn build(strings: &[&str]) -> Self {
+        let chains: Vec<Vec<char>> = strings.iter().map(|s| s.chars().collect()).collect();
+        let alphabet = get_alphabet(&chains);
+        let mt = mt_table(&chains, &alphabet);
+        let ms = matrices_score(&chains);
+
+        PreprocessedCorpus {
+            chains: chains.into_iter().map(Into::into).collect(),
+            alphabet,
+            mt,
+            ms,
+        }
+    }
+
+    /// Finds one of the longest common subsequences among the corpus strings and `query`
+    /// Gives the same result as `multiple_longest_common_subsequence` called with the
+    /// corpus strings followed by `query`, but only the tables involving `query` are computed,
+    /// and none when `shortcut` finds the subsequence without a search
+    pub fn mlcs_with(&self, query: &str) -> String {
+        let dc = self.chains.len();
+        let mut chains = self.chains.clone();
+        chains.push(query.chars().collect::<Vec<char>>().into());
+        if let Some(mlcs) = shortcut(&chains) {
+            return mlcs.into_iter().collect();
+        }
+        let query = &chains[dc];
+        let d = dc + 1;
+
+        // the letters of the corpus alphabet that also appear in the query
+        let letters: Vec<(Option<usize>, char)> = if dc == 0 {
+            let mut own = query.to_vec();
+            own.sort_unstable();
+            own.dedup();
+            own.into_iter().map(|ch| (None, ch)).collect()
+        } else {
+            self.alphabet
+                .iter()
+                .enumerate()
+                .filter(|(_, ch)| query.contains(ch))
+                .map(|(k, &ch)| (Some(k), ch))
+                .collect()
+        };
+
+        let mut alphabet = Vec::with_capacity(letters.len());
+        let mut mt = Vec::with_capacity(letters.len());
+        for (k, ch) in letters {
+            let mut columns = k.map_or_else(Vec::new, |k| self.mt[k].clone());
+            columns.push(next_positions(query, &ch).into());
+            alphabet.push(ch);
+            mt.push(columns);
+        }
+
+        // the query comes last, only the pairs (i, dc) are new
+        let mut ms = Vec::with_capacity(pair_count(d));
+        for i in 0..d {
+            for j in (i + 1)..d {
+                if j < dc {
+                    ms.push(self.ms[pair_index(i, j, dc)].clone());
+                } else {
+                    ms.push(score_matrix(&chains[i], &chains[j], None).into());
+                }
+            }
+        }
+
+        Context::from_tables(chains, alphabet, ms, mt).solve(&MlcsConfig::default())
+    }
+
+    /// Writes the tables to `path`, in the format described in the module documentation
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        w.write_all(MAGIC)?;
+        w.write_all(&VERSION.to_le_bytes())?;
+
+        write_u64(&mut w, self.chains.len() as u64)?;
+        for chain in &self.chains {
+            write_chars(&mut w, chain)?;
+        }
+        write_chars(&mut w, &self.alphabet)?;
+
+        for letter in &self.mt {
+            for column in letter {
+                for pos in column.iter() {
+                    write_u64(&mut w, pos.map_or(NONE, |p| p as u64))?;
+                }
+            }
+        }
+        for matrix in &self.ms {
+            for row in matrix.iter() {
+                for &value in row {
+                    write_u64(&mut w, value)?;
+                }
+            }
+        }
+
+        w.flush()
+    }
+
+    /// Reads tables previously written by `save`
+    /// Fails with `io::ErrorKind::InvalidData` if the file was not written by `save`
+    /// or was written with an incompatible layout version
+    /// The alphabet and lookup tables are checked against the strings, and the suffix
+    /// tables bounded by the lengths of the suffixes, so that a damaged file cannot make
+    /// the search go out of the strings
+    /// The numbers of strings and of tables are checked against the length of the file
+    /// before anything is allocated for them
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let mut r = BufReader::new(file);
+
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a preprocessed corpus file"));
+        }
+        let mut version = [0u8; 4];
+        r.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        if version != VERSION {
+            return Err(invalid_data(&format!(
+                "unsupported corpus format version {version}, expected {VERSION}"
+            )));
+        }
+
+        let d = read_len(&mut r)?;
+        // each string takes at least the 8 bytes of its length
+        if d as u64 > remaining(&mut r, file_len)? / 8 {
+            return Err(invalid_data("more strings than the file can hold"));
+        }
+        let chains = (0..d)
+            .map(|_| read_chars(&mut r))
+            .collect::<io::Result<Vec<_>>>()?;
+        let alphabet = read_chars(&mut r)?;
+
+        let mut mt = Vec::with_capacity(alphabet.len());
+        for _ in &alphabet {
+            let mut letter = Vec::with_capacity(d);
+            for chain in &chains {
+                let column = (0..chain.len())
+                    .map(|_| read_u64(&mut r).map(|pos| (pos != NONE).then_some(pos as usize)))
+                    .collect::<io::Result<Vec<_>>>()?;
+                letter.push(column.into());
+            }
+            mt.push(letter);
+        }
+
+        // each suffix table takes at least the 8 bytes of its last value
+        let room = remaining(&mut r, file_len)? / 8;
+        let pairs = d
+            .checked_mul(d.saturating_sub(1))
+            .map(|pairs| pairs / 2)
+            .filter(|&pairs| pairs as u64 <= room)
+            .ok_or_else(|| invalid_data("more suffix tables than the file can hold"))?;
+        let mut ms: Vec<SuffixTable> = Vec::with_capacity(pairs);
+        for (i, s1) in chains.iter().enumerate() {
+            for s2 in &chains[i + 1..] {
+                let matrix = (0..=s1.len())
+                    .map(|_| (0..=s2.len()).map(|_| read_u64(&mut r)).collect())
+                    .collect::<io::Result<Vec<Vec<u64>>>>()?;
+                ms.push(matrix.into());
+            }
+        }
+
+        // the whole file must have been consumed
+        if r.read(&mut [0u8])? != 0 {
+            return Err(invalid_data("trailing data after the corpus tables"));
+        }
+        // the search trusts the tables, which must describe the strings
+        if alphabet != get_alphabet(&chains) || mt != mt_table(&chains, &alphabet) {
+            return Err(invalid_data(
+                "the lookup tables do not match the corpus strings",
+            ));
+        }
+        let bounded = |i: usize, j: usize| {
+            ms[pair_index(i, j, d)].iter().enumerate().all(|(p, row)| {
+                let rest = chains[i].len() - p;
+                row.iter()
+                    .enumerate()
+                    .all(|(q, &value)| value <= rest.min(chains[j].len() - q) as u64)
+            })
+        };
+        if !(0..d).all(|i| ((i + 1)..d).all(|j| bounded(i, j))) {
+            return Err(invalid_data(
+                "the suffix tables do not match the corpus strings",
+            ));
+        }
+
+        Ok(PreprocessedCorpus {
+            chains: chains.into_iter().map(Into::into).collect(),
+            alphabet,
+            mt,
+            ms,
+        })
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// the bytes of the file left to read
+fn remaining(r: &mut BufReader<File>, file_len: u64) -> io::Result<u64> {
+    Ok(file_len.saturating_sub(r.stream_position()?))
+}
+
+fn write_u64(w: &mut impl Write, value: u64) -> io::Result<()> {
+    w.write_all(&value.to_le_bytes())
+}
+
+fn write_chars(w: &mut impl Write, chars: &[char]) -> io::Result<()> {
+    write_u64(w, chars.len() as u64)?;
+    for &c in chars {
+        w.write_all(&u32::from(c).to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn read_u64(r: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_len(r: &mut impl Read) -> io::Result<usize> {
+    usize::try_from(read_u64(r)?).map_err(|_| invalid_data("length does not fit in memory"))
+}
+
+fn read_chars(r: &mut impl Read) -> io::Result<Vec<char>> {
+    let len = read_len(r)?;
+    let mut chars = Vec::new();
+    for _ in 0..len {
+        let mut buf = [0u8; 4];
+        r.read_exact(&mut buf)?;
+        let c = char::from_u32(u32::from_le_bytes(buf)).ok_or_else(|| invalid_data("bad char"))?;
+        chars.push(c);
+    }
+    Ok(chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::multiple_longest_common_subsequence;
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("mlcs_corpus_{}_{name}.bin", std::process::id()))
+    }
+
+    const CORPUS: [&str; 4] = ["gxt#xayb", "abgt#ab", "gyayt#ahjb", "gyayjjjt#ab"];
+
+    #[test]
+    fn mlcs_with_matches_direct_call() {
+        let corpus = PreprocessedCorpus::build(&CORPUS);
+        for query in ["gyayt#ahhhhb", "ygaytp#pppahjb", "xyz", "", "串g用t于"] {
+            let mut all = CORPUS.to_vec();
+            all.push(query);
+            assert_eq!(
+                corpus.mlcs_with(query),
+                multiple_longest_common_subsequence(&all),
+                "query {query}"
+            );
+        }
+    }
+
+    #[test]
+    fn queries_answered_without_search() {
+        // the suffix table of the query and a string of 5000 chars would take 200 MB
+        let long = "ab".repeat(2500);
+        let corpus = PreprocessedCorpus::build(&[&long]);
+        let query = "ba".repeat(2000);
+        assert_eq!(corpus.mlcs_with(&query), query);
+        assert_eq!(corpus.mlcs_with(&"x".repeat(5000)), "");
+        assert_eq!(corpus.mlcs_with(&"ax".repeat(5000)), "a".repeat(2500));
+    }
+
+    #[test]
+    fn empty_corpus() {
+        let corpus = PreprocessedCorpus::build(&[]);
+        assert_eq!(
+            corpus.mlcs_with("abc"),
+            multiple_longest_common_subsequence(&vec!["abc"])
+        );
+    }
+
+    #[test]
+    fn save_load_round_trip() {
+        let path = temp_file("round_trip");
+        let corpus = PreprocessedCorpus::build(&["串用于测试🚀", "串用于测🚀试", "abc串于"]);
+        corpus.save(&path).unwrap();
+        let loaded = PreprocessedCorpus::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, corpus);
+        assert_eq!(loaded.mlcs_with("串于试🚀"), corpus.mlcs_with("串于试🚀"));
+    }
+
+    #[test]
+    fn version_mismatch_is_rejected() {
+        let path = temp_file("version");
+        PreprocessedCorpus::build(&CORPUS).save(&path).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(VERSION + 1).to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn truncated_or_foreign_files_are_rejected() {
+        let path = temp_file("truncated");
+        PreprocessedCorpus::build(&CORPUS).save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+
+        std::fs::write(&path, &bytes[..bytes.len() - 3]).unwrap();
+        assert!(PreprocessedCorpus::load(&path).is_err());
+
+        std::fs::write(&path, b"definitely not a corpus").unwrap();
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn counts_beyond_the_file_are_rejected() {
+        let path = temp_file("counts");
+        let header = |d: u64| [&MAGIC[..], &VERSION.to_le_bytes(), &d.to_le_bytes()].concat();
+
+        // more strings than 8 bytes each
+        std::fs::write(&path, header(u64::MAX >> 4)).unwrap();
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // a million empty strings and an empty alphabet in 8 MB, which would need
+        // 5 * 10^11 suffix tables
+        let d = 1_000_000;
+        let mut bytes = header(d);
+        bytes.resize(bytes.len() + 8 * (d as usize + 1), 0);
+        std::fs::write(&path, bytes).unwrap();
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("suffix tables"), "{err}");
+    }
+
+    #[test]
+    fn damaged_tables_are_rejected() {
+        let path = temp_file("damaged");
+        let mut corpus = PreprocessedCorpus::build(&CORPUS);
+        Arc::get_mut(&mut corpus.mt[0][0]).unwrap()[0] = Some(999);
+        corpus.save(&path).unwrap();
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // the last value of the last suffix table, the LCS of two empty suffixes
+        PreprocessedCorpus::build(&CORPUS).save(&path).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        let end = bytes.len();
+        bytes[end - 8..].copy_from_slice(&u64::MAX.to_le_bytes());
+        std::fs::write(&path, bytes).unwrap();
+        let err = PreprocessedCorpus::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,167 @@
+//! Errors of the MLCS search, for callers propagating them with `?`.
+
+/// Errors that can prevent an MLCS search from giving a meaningful result
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MlcsError {
+    /// No string was given
+    EmptyInput,
+    /// A single string was given, it is its own longest common subsequence
+    SingleString,
+    /// No char occurs in every string, so the only common subsequence is empty
+    NoCommonAlphabet,
+    /// The search would need more memory than allowed (see `estimate_memory`)
+    InputTooLarge {
+        estimated_bytes: usize,
+        limit: usize,
+    },
+    /// More letters occur in every string than allowed, each needing its own lookup table
+    AlphabetTooLarge { letters: usize, limit: usize },
+    /// The number of weight vectors is not the number of strings
+    WeightsCount { strings: usize, weights: usize },
+    /// A string does not have one weight per char
+    WeightsLength {
+        string: usize,
+        chars: usize,
+        weights: usize,
+    },
+    /// A weight is negative, infinite or NaN
+    InvalidWeight { string: usize, position: usize },
+    /// The weights of a string add up to more than the search can count
+    WeightsTooLarge { string: usize },
+    /// The search reached `max_nodes` or `timeout` before its end, `subsequence` being the
+    /// common subsequence found so far, maybe not a longest one
+    Interrupted { subsequence: String },
+    /// An invariant of the search was broken
+    Internal(String),
+}
+
+impl std::fmt::Display for MlcsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MlcsError::EmptyInput => write!(f, "at least two strings are needed, got none"),
+            MlcsError::SingleString => write!(f, "at least two strings are needed, got one"),
+            MlcsError::NoCommonAlphabet => write!(f, "no char is shared by every string"),
+            MlcsError::InputTooLarge {
+                estimated_bytes,
+                limit,
+            } => write!(
+                f,
+                "the search would need about {estimated_bytes} bytes, over the limit of {limit}"
+            ),
+            MlcsError::AlphabetTooLarge { letters, limit } => write!(
+                f,
+                "{letters} letters occur in every string, over the limit of {limit}"
+            ),
+            MlcsError::WeightsCount { strings, weights } => {
+                write!(
+                    f,
+                    "{weights} weight vectors were given for {strings} strings"
+                )
+            }
+            MlcsError::WeightsLength {
+                string,
+                chars,
+                weights,
+            } => write!(f, "string {string} has {chars} chars but {weights} weights"),
+            MlcsError::InvalidWeight { string, position } => write!(
+                f,
+                "the weight of char {position} of string {string} is negative or not finite"
+            ),
+            MlcsError::WeightsTooLarge { string } => {
+                write!(f, "the weights of string {string} are too large to add up")
+            }
+            MlcsError::Interrupted { subsequence } => write!(
+                f,
+                "the search was interrupted, the best subsequence found has {} chars",
+                subsequence.chars().count()
+            ),
+            MlcsError::Internal(msg) => write!(f, "internal error in the MLCS search: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MlcsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn display() {
+        let cases = [
+            (
+                MlcsError::EmptyInput,
+                "at least two strings are needed, got none",
+            ),
+            (
+                MlcsError::SingleString,
+                "at least two strings are needed, got one",
+            ),
+            (
+                MlcsError::NoCommonAlphabet,
+                "no char is shared by every string",
+            ),
+            (
+                MlcsError::InputTooLarge {
+                    estimated_bytes: 4096,
+                    limit: 1024,
+                },
+                "the search would need about 4096 bytes, over the limit of 1024",
+            ),
+            (
+                MlcsError::AlphabetTooLarge {
+                    letters: 5000,
+                    limit: 256,
+                },
+                "5000 letters occur in every string, over the limit of 256",
+            ),
+            (
+                MlcsError::WeightsCount {
+                    strings: 3,
+                    weights: 2,
+                },
+                "2 weight vectors were given for 3 strings",
+            ),
+            (
+                MlcsError::WeightsLength {
+                    string: 1,
+                    chars: 4,
+                    weights: 3,
+                },
+                "string 1 has 4 chars but 3 weights",
+            ),
+            (
+                MlcsError::InvalidWeight {
+                    string: 0,
+                    position: 2,
+                },
+                "the weight of char 2 of string 0 is negative or not finite",
+            ),
+            (
+                MlcsError::WeightsTooLarge { string: 1 },
+                "the weights of string 1 are too large to add up",
+            ),
+            (
+                MlcsError::Interrupted {
+                    subsequence: "串ab".to_owned(),
+                },
+                "the search was interrupted, the best subsequence found has 3 chars",
+            ),
+            (
+                MlcsError::Internal("unknown parent".to_owned()),
+                "internal error in the MLCS search: unknown parent",
+            ),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn boxed() {
+        let boxed: Box<dyn Error> = Box::new(MlcsError::EmptyInput);
+        assert!(boxed.source().is_none());
+        assert_eq!(boxed.to_string(), MlcsError::EmptyInput.to_string());
+    }
+}
@@ -0,0 +1,127 @@
+//! Shared inputs for the MLCS tests and benchmarks.
+//!
+//! This file is a module of the crate when testing, and is included with `#[path]`
+//! by `benches/mlcs.rs`, so both measure and check exactly the same inputs.
+
+/// A named input with the subsequence the search is expected to return
+///
+/// `expected_len` is the length of `expected` in chars, kept apart so that the checks of
+/// validity still hold once the search returns another subsequence, at least as long.
+pub struct Fixture {
+    pub name: &'static str,
+    pub input: &'static [&'static str],
+    pub expected: &'static str,
+    pub expected_len: usize,
+}
+
+pub const LONG: Fixture = Fixture {
+    name: "long",
+    input: &[
+        "qwertyuiop$asd$fgh$jkl;zxcvbnmqwert|yuiop1234567890-0",
+        "qwertyuiopasdfghj$kl;zx$cvbnmqwe$rtyu|iop,1234567890-0",
+    ],
+    expected: "qwertyuiopasdfgh$kl;zxcvbnmqwertyuiop1234567890-0",
+    expected_len: 49,
+};
+
+pub const MEDIUM_PLUS: Fixture = Fixture {
+    name: "medium_plus",
+    input: &[
+        "=串-用2于测试2展示测中测中0shgksjklkjlj测测🚀测测串文|",
+        "=串-串用2于测2试测中ss中0展示测测l中🚀文|串",
+        "=串-用2于测试2展67中中0xs中中中kkljhkkh示中测🚀测|测文|",
+        "=串-|用2于ss串试056u展xx🚀示中lj测ggk测|ss文|",
+        "=串-用2于-测22中中中uyty试串lj展gkks中示🚀测测s|测中文|b",
+        "=串-用2于测s-试2中中0中hgtihlkk展串🚀中示s中|文|",
+        "=2串2中2中2中s用-于0t测🚀j试展示测s测hkkkg测中中串文|l",
+        "=2串2中2中2中s用-于0测🚀试展示测s中k中l串文|",
+        "=2串2中2中2中s用ur-于0测🚀试展示测jkjljkkllkskg中串文|;",
+        "=2串2中2中2中s用u-ur于0测🚀试展jll示测gks中中串文|0",
+        "=2串2中2中2中s用-uurr于0测🚀试kl展示测s测中中串文|8",
+        "=2中2中s用-于0测🚀试展示测jsjhg测测中串文|",
+        "=2串2中2中2中s用-于0rttru测ljjgjh🚀试示测s测测中中串文|",
+        "=2串2中2中2中s用-于0gjg测lu🚀试展示测s测测中中串文|6",
+        "=2中22s-于0测🚀展测j测ljy中中串文|",
+        "=2串2中2中2中s用-jklkjll于hgj0测🚀试展示测s测中中串文|",
+        "=2串2中2中2中s用-于0g🚀试展示测s测中中lj串文|",
+        "=2串2中2中2中s用-于hj0试展示测sghhjjhgjl测测中串文|",
+        "=2串2中2中2中s用-于0h🚀试展示测sj测中jkl中串文|",
+        "=2串2中2中2中s用-于0j🚀试展示测gjgjsjk测串文|",
+        "=2串2中2中2中s用-于kj0🚀试展示测jjjlks中串文|",
+        "=2串2中2中2中s用-于0l🚀试展示fdj测l测中中串文|",
+        "=2串2中2中2中s用-于0🚀kl试展测测djkhdd中文|",
+        "=2串2中2中2中s用-于0试展示测s测fdljh中中串文|",
+        "=2串2中2中2中s用-于0测l🚀l试展示lshd测测中中串文|",
+        "=2串2中2中2中s用-于0测🚀jk试展示sf测测中中串文|",
+        "=串用2串2中🚀2-中于0测试中lk展中ks中23文|串",
+    ],
+    expected: "=2于测文|",
+    expected_len: 6,
+};
+
+pub const MIX: Fixture = Fixture {
+    name: "mix",
+    input: &[
+        "=串-用2于测试2展示测中测中0ss测测🚀测测串文|",
+        "=串-串用2于测2试测中ss中0展示测测中🚀文|串",
+        "=串-用2于测试2展中中0xs中中中示中测🚀测|测文",
+        "=串-|用2于ss串试0展xx🚀示中测测|ss文",
+        "=串-用2于-测22中中中试串展s中示🚀测测s|测中文",
+        "=串用2于测s-试2中中0中展串🚀中示s中|文",
+        "=2串2中2中2中s用-于0测🚀试展示测s测测中中串文|",
+        "=串用2串2中🚀2-中于0测试中展中示s中文|串",
+        "=串2🚀用1于-2测2中20中试s中展s示中文测|测测测测串",
+    ],
+    expected: "=串用于试展示中文",
+    expected_len: 9,
+};
+
+/// The fixtures measured by the benchmarks
+pub const FIXTURES: [Fixture; 3] = [LONG, MIX, MEDIUM_PLUS];
+
+/// Checks that `solve` still returns the expected subsequence of `fixture`
+/// so that a benchmark can't silently measure a broken build
+pub fn assert_fixture(fixture: &Fixture, solve: impl Fn(&[&str]) -> String) {
+    let result = solve(fixture.input);
+    assert_valid_mlcs(&result, fixture.input, fixture.expected_len);
+    assert_eq!(
+        result, fixture.expected,
+        "fixture `{}` gives another subsequence, as long and as valid",
+        fixture.name
+    );
+}
+
+/// Checks that `result` is a common subsequence of `inputs` of at least `min_len` chars
+pub fn assert_valid_mlcs(result: &str, inputs: &[&str], min_len: usize) {
+    for input in inputs {
+        let mut chars = input.chars();
+        assert!(
+            result.chars().all(|c| chars.any(|x| x == c)),
+            "{result:?} is not a subsequence of {input:?}"
+        );
+    }
+    let len = result.chars().count();
+    assert!(
+        len >= min_len,
+        "{result:?} has {len} chars, a longest common subsequence has at least {min_len}"
+    );
+}
+
+/// Generates `d` pseudo-random strings of length `n` over the first `k` lowercase letters
+/// The output only depends on the arguments (xorshift generator seeded by `seed`)
+pub fn synthetic(d: usize, n: usize, k: u8, seed: u64) -> Vec<String> {
+    let mut state = seed.max(1);
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    (0..d)
+        .map(|_| {
+            (0..n)
+                .map(|_| char::from(b'a' + (next() % u64::from(k)) as u8))
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,260 @@
+//! Parallel search in the style of HDA* (hash distributed A*), behind the `hda` feature.
+//!
+//! Each point is owned by one worker, chosen by hashing the point. For every band:
+//! * each worker expands the points of the band it owns, and sends each successor,
+//!   batched per destination, to the owner of that successor through a channel
+//! * each worker merges the successors it owns, computing the heuristic of each point
+//!   once, the most expensive part of the search on inputs made of many strings
+//! * back on the calling thread, the successors are recorded in band order, a point
+//!   generated again being only recorded again when its new parent is deeper.
+//!
+//! The bounds of the serial search (see `Bounds`) are applied the same way: the points
+//! pruned by the incumbent are not expanded, and the recording stops at the first point
+//! reaching the upper bound. The limits of `MlcsConfig` are only checked between bands.
+//!
+//! A successor is identified by the rank of its parent in the band and by the index of its
+//! letter in the alphabet, which is exactly the order in which the serial search generates
+//! them. Recording the successors in that order gives the same search tree, so the returned
+//! subsequence is the same as the one of `Context::solve`, for any number of threads. As the
+//! bands are synchronous, the search ends on the calling thread as soon as a point reaches
+//! the upper bound or the queue is empty, and no worker is left running.
+
+use super::{band_threshold, collect_layer, Bounds, Context, MlcsConfig, QueueNode, SearchStats};
+use std::cmp::max;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Instant;
+
+type Point = Vec<Option<usize>>;
+
+// the order of generation of a successor: (rank of the parent in the band, letter index)
+type Key = (usize, usize);
+
+type Batch = Vec<(Key, Point)>;
+
+// a generated successor, with its heuristic and whether it is its first generation
+type Generation = (Key, Point, u64, bool);
+
+/// The worker owning the point p
+fn owner(p: &Point, threads: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    p.hash(&mut hasher);
+    (hasher.finish() % threads as u64) as usize
+}
+
+/// Runs the band search of `Context::search`, the expansion of each band
+/// being shared among `threads` workers
+pub(super) fn search(
+    ctx: &mut Context,
+    config: &MlcsConfig,
+    threads: usize,
+    stats: &mut SearchStats,
+) -> Option<Point> {
+    let threads = threads.max(1);
+    // empty strings have no common char, the root is the only point
+    if ctx.chains.iter().all(|chain| chain.is_empty()) {
+        return None;
+    }
+    let deadline = config.timeout.map(|timeout| Instant::now() + timeout);
+    let mut queue: BinaryHeap<QueueNode> = ctx.init_queue(config);
+    let mut bounds = Bounds::new(&queue);
+
+    let goal = loop {
+        if queue.is_empty() {
+            break None;
+        }
+        if ctx.exceeds_limits(config, deadline) {
+            stats.interrupted = true;
+            break None;
+        }
+        stats.bands += 1;
+        stats.max_queue_len = max(stats.max_queue_len, queue.len());
+
+        let y = band_threshold(&queue, config.width);
+        let current_layer = collect_layer(&mut queue, y, &ctx.order);
+
+        let expanded: Vec<(usize, &Point)> = current_layer
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !bounds.prunes(node))
+            .map(|(rank, node)| (rank, &node.point))
+            .collect();
+        let successors = expand(ctx, &expanded, threads);
+
+        let mut next_points = Vec::with_capacity(successors.len());
+        let mut reached = None;
+        for ((rank, _), q, h, first) in successors {
+            let parent = &current_layer[rank].point;
+            if !first && ctx.g_through(parent, &q) <= ctx.g_of(&q) {
+                continue;
+            }
+            ctx.record_successor(parent.clone(), q.clone(), h);
+            if bounds.reached(ctx.g_of(&q)) {
+                reached = Some(q);
+                break;
+            }
+            if first {
+                next_points.push((q, h));
+            }
+        }
+
+        if reached.is_some() {
+            break reached;
+        }
+        bounds.update(next_points.iter().map(|(q, _)| (ctx.g_of(q), q)));
+        queue = next_points
+            .into_iter()
+            .map(|(point, h)| QueueNode {
+                f: ctx.f_of(&point),
+                h,
+                point,
+            })
+            .collect();
+    };
+
+    let goal = bounds.best(goal, |p| ctx.g_of(p));
+    if let Some(p) = &goal {
+        stats.goal_g = ctx.depth(p);
+    }
+    goal
+}
+
+/// Computes the successors of the points of `layer`, given with their rank in the band,
+/// and the heuristic of these successors
+///
+/// # Returns
+/// The generations of the successors, sorted by the order in which the serial search
+/// generates them
+fn expand(ctx: &Context, layer: &[(usize, &Point)], threads: usize) -> Vec<Generation> {
+    let mut shards: Vec<Vec<(usize, &Point)>> = vec![vec![]; threads];
+    for &(rank, point) in layer {
+        shards[owner(point, threads)].push((rank, point));
+    }
+
+    let (senders, receivers): (Vec<Sender<Batch>>, Vec<Receiver<Batch>>) =
+        (0..threads).map(|_| mpsc::channel()).unzip();
+
+    let mut successors: Vec<Generation> = thread::scope(|scope| {
+        let workers: Vec<_> = shards
+            .into_iter()
+            .zip(receivers)
+            .map(|(shard, inbox)| {
+                let senders = senders.clone();
+                scope.spawn(move || work(ctx, &shard, senders, &inbox))
+            })
+            .collect();
+        // the inboxes are closed once every worker has sent its batches
+        drop(senders);
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("an MLCS worker panicked"))
+            .collect()
+    });
+
+    successors.sort_unstable_by_key(|(key, _, _, _)| *key);
+    successors
+}
+
+/// Expands the points of `shard`, routes their successors to their owners,
+/// then merges the successors received in `inbox`
+fn work(
+    ctx: &Context,
+    shard: &[(usize, &Point)],
+    senders: Vec<Sender<Batch>>,
+    inbox: &Receiver<Batch>,
+) -> Vec<Generation> {
+    let threads = senders.len();
+    let mut outboxes: Vec<Batch> = vec![vec![]; threads];
+    for &(rank, p) in shard {
+        for ch_idx in 0..ctx.alphabet.len() {
+            if let Some(q) = ctx.next_successor_for_letter(p, ch_idx) {
+                outboxes[owner(&q, threads)].push(((rank, ch_idx), q));
+            }
+        }
+    }
+    for (sender, batch) in senders.iter().zip(outboxes) {
+        if !batch.is_empty() {
+            sender.send(batch).expect("an MLCS worker stopped early");
+        }
+    }
+    drop(senders);
+
+    let mut keys: HashMap<Point, Vec<Key>> = HashMap::new();
+    for batch in inbox {
+        for (key, q) in batch {
+            keys.entry(q).or_default().push(key);
+        }
+    }
+
+    let mut generations = vec![];
+    for (q, mut keys) in keys {
+        keys.sort_unstable();
+        let h = ctx.heuristic(&q);
+        for (i, key) in keys.into_iter().enumerate() {
+            generations.push((key, q.clone(), h, i == 0));
+        }
+    }
+    generations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_parallel, multiple_longest_common_subsequence};
+    use super::*;
+
+    #[test]
+    fn same_output_as_serial_search() {
+        let synthetic = fixtures::synthetic(6, 40, 4, 11);
+        let synthetic: Vec<&str> = synthetic.iter().map(String::as_str).collect();
+        let inputs = [
+            fixtures::LONG.input,
+            fixtures::MEDIUM_PLUS.input,
+            fixtures::MIX.input,
+            &synthetic,
+            &["ABC", "AC", "BAC"],
+            &["abc", "def"],
+            &["", "abc"],
+        ];
+        for input in inputs {
+            let expected = multiple_longest_common_subsequence(&input.to_vec());
+            for threads in [1, 2, 3, 8] {
+                assert_eq!(mlcs_parallel(input, threads), expected, "{threads} threads");
+            }
+        }
+    }
+
+    #[test]
+    fn low_memory_reconstruction() {
+        let config = MlcsConfig {
+            low_memory_reconstruction: true,
+            ..MlcsConfig::default()
+        };
+        let input = fixtures::MEDIUM_PLUS.input;
+        assert_eq!(
+            Context::new(input).solve_parallel(&config, 4),
+            fixtures::MEDIUM_PLUS.expected
+        );
+    }
+
+    #[test]
+    fn stress_many_strings() {
+        // many strings, so that the heuristic dominates, and many threads
+        for seed in 0..4 {
+            let strings = fixtures::synthetic(20, 30, 3, seed);
+            let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let expected = Context::new(&input).solve(&MlcsConfig::default());
+            for threads in [2, 5, 16] {
+                assert_eq!(mlcs_parallel(&input, threads), expected, "seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn zero_threads_means_one() {
+        assert_eq!(mlcs_parallel(&["ABC", "AC", "BAC"], 0), "AC");
+    }
+}
@@ -0,0 +1,215 @@
+//! Longest common subsequence of exactly three strings, by the direct dynamic programming.
+//!
+//! `table[i][j][k]` is the LCS length of the first i, j and k chars of the strings: one
+//! more than `table[i - 1][j - 1][k - 1]` when the three last chars are equal, the largest
+//! of the three tables dropping one of them otherwise. The traceback walks back from the
+//! full strings.
+//!
+//! The table is a single `Vec` indexed by `(i * (m + 1) + j) * (p + 1) + k`, which fills
+//! (n + 1)(m + 1)(p + 1) cells of 4 bytes: 4 GB for three strings of 1000 chars. Its size is
+//! checked before it is allocated. The search of `mlcs_exact` usually needs far less, but
+//! gives no such bound.
+
+use super::MlcsError;
+
+/// The default byte limit of `lcs3`, 1 GiB
+pub const LCS3_DEFAULT_LIMIT: usize = 1 << 30;
+
+/// Finds a longest common subsequence of `a`, `b` and `c`, compared char by char
+///
+/// The table may take up to `LCS3_DEFAULT_LIMIT` bytes, see `lcs3_with_limit`.
+///
+/// # Errors
+///
+/// `MlcsError::InputTooLarge` if the table would take more than `LCS3_DEFAULT_LIMIT` bytes.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs3;
+///
+/// assert_eq!(lcs3("AGGT12", "12TXAYB", "12XBA"), Ok("12".to_string()));
+/// assert_eq!(lcs3("串用于测试", "用于串测试", "用测试于"), Ok("用测试".to_string()));
+/// ```
+pub fn lcs3(a: &str, b: &str, c: &str) -> Result<String, MlcsError> {
+    lcs3_with_limit(a, b, c, LCS3_DEFAULT_LIMIT)
+}
+
+/// Same as `lcs3`, the table taking at most `limit` bytes
+///
+/// # Errors
+///
+/// `MlcsError::InputTooLarge` if the table would take more than `limit` bytes, before
+/// anything is allocated.
+///
+/// # Complexity
+///
+/// O(n * m * p) time and space, for strings of n, m and p chars.
+pub fn lcs3_with_limit(a: &str, b: &str, c: &str, limit: usize) -> Result<String, MlcsError> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let c: Vec<char> = c.chars().collect();
+    let (n, m, p) = (a.len(), b.len(), c.len());
+
+    // (n + 1)(m + 1)(p + 1) cells of a u32, usize::MAX if that overflows
+    let cells = (n + 1)
+        .checked_mul(m + 1)
+        .and_then(|cells| cells.checked_mul(p + 1));
+    let estimated_bytes = cells
+        .and_then(|cells| cells.checked_mul(size_of::<u32>()))
+        .unwrap_or(usize::MAX);
+    if estimated_bytes > limit {
+        return Err(MlcsError::InputTooLarge {
+            estimated_bytes,
+            limit,
+        });
+    }
+    let index = |i: usize, j: usize, k: usize| (i * (m + 1) + j) * (p + 1) + k;
+
+    let mut table = vec![0u32; cells.unwrap_or(0)];
+    for i in 1..=n {
+        for j in 1..=m {
+            for k in 1..=p {
+                table[index(i, j, k)] = if a[i - 1] == b[j - 1] && b[j - 1] == c[k - 1] {
+                    table[index(i - 1, j - 1, k - 1)] + 1
+                } else {
+                    table[index(i - 1, j, k)]
+                        .max(table[index(i, j - 1, k)])
+                        .max(table[index(i, j, k - 1)])
+                };
+            }
+        }
+    }
+
+    let mut lcs = vec![];
+    let (mut i, mut j, mut k) = (n, m, p);
+    while i > 0 && j > 0 && k > 0 {
+        let here = table[index(i, j, k)];
+        if a[i - 1] == b[j - 1] && b[j - 1] == c[k - 1] {
+            lcs.push(a[i - 1]);
+            (i, j, k) = (i - 1, j - 1, k - 1);
+        } else if table[index(i - 1, j, k)] == here {
+            i -= 1;
+        } else if table[index(i, j - 1, k)] == here {
+            j -= 1;
+        } else {
+            k -= 1;
+        }
+    }
+    Ok(lcs.into_iter().rev().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_exact};
+    use super::*;
+    use crate::dynamic_programming::is_subsequence;
+
+    // the length of the longest subsequences of a found in b and c
+    fn brute_force_len(a: &str, b: &str, c: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        (0..1u32 << a.len())
+            .map(|mask| {
+                (0..a.len())
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .map(|i| a[i])
+                    .collect::<String>()
+            })
+            .filter(|sub| is_subsequence(sub, b) && is_subsequence(sub, c))
+            .map(|sub| sub.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn check(a: &str, b: &str, c: &str, expected_len: usize) {
+        let lcs = lcs3(a, b, c).unwrap();
+        assert_eq!(lcs.chars().count(), expected_len, "{a} {b} {c}");
+        fixtures::assert_valid_mlcs(&lcs, &[a, b, c], expected_len);
+    }
+
+    macro_rules! lcs3_tests {
+        ($($name:ident: ($a:expr, $b:expr, $c:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (a, b, c): (&str, &str, &str) = ($a, $b, $c);
+                    assert_eq!(lcs3(a, b, c), Ok($expected.to_string()));
+                    // the length does not depend on the order of the strings
+                    let len = $expected.chars().count();
+                    check(c, a, b, len);
+                    check(b, c, a, len);
+                }
+            )*
+        };
+    }
+
+    lcs3_tests! {
+        all_empty: ("", "", "", ""),
+        one_empty: ("abc", "", "abc", ""),
+        no_common_char: ("abc", "abd", "xyz", ""),
+        identical: ("abcdef", "abcdef", "abcdef", "abcdef"),
+        classic: ("AGGT12", "12TXAYB", "12XBA", "12"),
+        geeks: ("geeks", "geeksfor", "geeksforgeeks", "geeks"),
+        interleaved: ("abcd1e2", "bc12ea", "bd1ea", "b1e"),
+        unicode: ("串用于测试", "用于串测试", "用测试于", "用测试"),
+        emoji: ("🚀a🚀b", "a🚀b🚀", "🚀ab", "🚀b"),
+    }
+
+    #[test]
+    fn random_strings_match_the_brute_force() {
+        for seed in 1..=300 {
+            let mut strings = fixtures::synthetic(3, 12, 1 + (seed % 3) as u8, seed as u64);
+            // up to 8, 12 and 12 chars, the lengths shifting with the seed
+            for (s, len) in strings
+                .iter_mut()
+                .zip([seed % 9, (seed + 5) % 13, seed / 3 % 13])
+            {
+                s.truncate(len);
+            }
+            let [a, b, c] = &strings[..] else {
+                unreachable!("three strings are generated")
+            };
+            check(a, b, c, brute_force_len(a, b, c));
+        }
+    }
+
+    #[test]
+    fn same_length_as_the_exact_search() {
+        let mut inputs: Vec<Vec<String>> = fixtures::FIXTURES
+            .iter()
+            .filter(|fixture| fixture.input.len() == 3)
+            .map(|fixture| fixture.input.iter().map(|s| s.to_string()).collect())
+            .collect();
+        for seed in 1..20 {
+            inputs.push(fixtures::synthetic(3, 25, 4, seed));
+        }
+        for input in inputs {
+            let input: Vec<&str> = input.iter().map(String::as_str).collect();
+            let expected = mlcs_exact(&input).chars().count();
+            check(input[0], input[1], input[2], expected);
+        }
+    }
+
+    #[test]
+    fn table_over_the_limit() {
+        // 4 * 5 * 3 cells of 4 bytes
+        let (a, b, c) = ("abc", "abcd", "ab");
+        assert_eq!(lcs3_with_limit(a, b, c, 240), Ok("ab".to_string()));
+        assert_eq!(
+            lcs3_with_limit(a, b, c, 239),
+            Err(MlcsError::InputTooLarge {
+                estimated_bytes: 240,
+                limit: 239
+            })
+        );
+        // 1001^3 cells, over the default limit
+        let long = "a".repeat(1000);
+        assert_eq!(
+            lcs3(&long, &long, &long),
+            Err(MlcsError::InputTooLarge {
+                estimated_bytes: 4 * 1001 * 1001 * 1001,
+                limit: LCS3_DEFAULT_LIMIT
+            })
+        );
+    }
+}
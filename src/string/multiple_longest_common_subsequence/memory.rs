@@ -0,0 +1,141 @@
+//! Estimation of the memory used by an MLCS search, so that callers can check
+//! whether an input fits in RAM before solving it.
+//!
+//! Only heap allocations are counted, using the in-memory layout of `Context`:
+//! `Vec` headers, `Arc` pointers and reference counts, `Option<usize>` positions,
+//! `u64` scores and one `HashMap` entry per point in each of the three search maps.
+
+use std::mem::size_of;
+use std::sync::Arc;
+
+type Point = Vec<Option<usize>>;
+
+/// The number of bytes needed by each part of a `Context`
+///
+/// # Fields
+/// * `chains` - the strings, stored as `char`s
+/// * `score_matrices` - the suffix tables used by the heuristic, one for each pair of
+///   strings i < j
+/// * `lookup_table` - the next occurrence of each common letter in each string
+/// * `per_point` - the projected cost of one point in the search maps `f`, `g` and `parents`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryEstimate {
+    pub chains: usize,
+    pub score_matrices: usize,
+    pub lookup_table: usize,
+    pub per_point: usize,
+}
+
+impl MemoryEstimate {
+    /// The bytes used by the preprocessing tables, before any search
+    pub fn tables(&self) -> usize {
+        self.chains
+            .saturating_add(self.score_matrices)
+            .saturating_add(self.lookup_table)
+    }
+
+    /// The bytes used by the preprocessing tables and a search reaching `points` points
+    /// The number of points is not known beforehand, `SearchStats::nodes_expanded`
+    /// gives it for a similar input
+    pub fn with_points(&self, points: usize) -> usize {
+        self.tables()
+            .saturating_add(self.per_point.saturating_mul(points))
+    }
+}
+
+/// Estimates the memory used by a search among strings of the given lengths (in chars),
+/// sharing `alphabet_size` letters
+pub fn estimate_memory(lengths: &[usize], alphabet_size: usize) -> MemoryEstimate {
+    let d = lengths.len();
+    let total = lengths
+        .iter()
+        .fold(0usize, |total, &m| total.saturating_add(m));
+    let header = size_of::<Vec<u8>>();
+    // the strings and tables shared behind an `Arc`: the pointer and the two counts
+    // heading the allocation
+    let shared = size_of::<Arc<[u8]>>() + 2 * size_of::<usize>();
+    // the estimates of absurd lengths stop at usize::MAX bytes instead of overflowing
+    let bytes = |count: usize, size: usize| count.saturating_mul(size);
+
+    let chains = bytes(d, shared).saturating_add(bytes(total, size_of::<char>()));
+
+    // one (n_i + 1) x (n_j + 1) table for each pair i < j, each row of string i being
+    // followed by the n_j + 1 scores of every later string j
+    let mut score_matrices = bytes(d.saturating_mul(d.saturating_sub(1)) / 2, shared);
+    let (mut later, mut later_cells) = (0usize, 0usize);
+    for &m in lengths.iter().rev() {
+        let row = bytes(later, header).saturating_add(bytes(later_cells, size_of::<u64>()));
+        score_matrices = score_matrices.saturating_add(bytes(m.saturating_add(1), row));
+        later += 1;
+        later_cells = later_cells.saturating_add(m.saturating_add(1));
+    }
+
+    // for each letter, one column of positions per string
+    let lookup_table = bytes(
+        alphabet_size,
+        bytes(d, shared)
+            .saturating_add(header)
+            .saturating_add(bytes(total, size_of::<Option<usize>>())),
+    );
+
+    // each map owns a copy of the point as its key, and `parents` also owns the parent,
+    // the inline part of the entries being stored in tables kept at most 7/8 full
+    let point = bytes(d, size_of::<Option<usize>>());
+    let entries = 3 * size_of::<Point>() + 2 * size_of::<u64>() + size_of::<Option<Point>>();
+    let per_point = bytes(point, 4).saturating_add(entries * 8 / 7 + 3);
+
+    MemoryEstimate {
+        chains,
+        score_matrices,
+        lookup_table,
+        per_point,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_grow_quadratically() {
+        let small = estimate_memory(&[100, 100, 100], 4);
+        let large = estimate_memory(&[200, 200, 200], 4);
+        let ratio = large.score_matrices as f64 / small.score_matrices as f64;
+        assert!((3.9..4.1).contains(&ratio), "{ratio}");
+        assert_eq!(large.per_point, small.per_point);
+    }
+
+    #[test]
+    fn empty_input() {
+        let estimate = estimate_memory(&[], 0);
+        assert_eq!(estimate.tables(), 0);
+        assert_eq!(estimate.with_points(10), 10 * estimate.per_point);
+    }
+
+    #[test]
+    fn exact_table_sizes() {
+        // a single string of length 2 over 1 letter
+        let estimate = estimate_memory(&[2], 1);
+        assert_eq!(estimate.chains, 32 + 2 * 4);
+        assert_eq!(estimate.score_matrices, 0);
+        assert_eq!(estimate.lookup_table, 32 + 24 + 2 * 16);
+
+        // two strings of lengths 1 and 2: a single 2 x 3 table
+        let estimate = estimate_memory(&[1, 2], 1);
+        assert_eq!(estimate.score_matrices, 32 + 2 * (24 + 3 * 8));
+
+        // three strings of lengths 1, 2 and 3: a 2 x 3, a 2 x 4 and a 3 x 4 table
+        let estimate = estimate_memory(&[1, 2, 3], 1);
+        assert_eq!(
+            estimate.score_matrices,
+            3 * 32 + 2 * (24 + 3 * 8) + 2 * (24 + 4 * 8) + 3 * (24 + 4 * 8)
+        );
+    }
+
+    #[test]
+    fn absurd_lengths_saturate() {
+        let estimate = estimate_memory(&[usize::MAX, usize::MAX], usize::MAX);
+        assert_eq!(estimate.tables(), usize::MAX);
+        assert_eq!(estimate.with_points(usize::MAX), usize::MAX);
+    }
+}
@@ -0,0 +1,2865 @@
+use std::cmp::max;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use self::bounds::Bounds;
+use self::breadcrumbs::Breadcrumbs;
+use crate::string::SubstitutionMatrix;
+
+mod bounds;
+mod breadcrumbs;
+mod builder;
+mod constrained;
+mod corpus;
+mod error;
+#[cfg(test)]
+pub(super) mod fixtures;
+#[cfg(feature = "hda")]
+mod hda;
+mod lcs3;
+mod memory;
+#[cfg(test)]
+mod oracle;
+#[cfg(test)]
+mod panic_free;
+mod runs;
+mod scored;
+mod sequences;
+mod shared;
+mod similarity;
+#[cfg(test)]
+mod tiny_inputs;
+mod top_k;
+mod weighted;
+mod windowed;
+
+pub use self::builder::{MlcsBuilder, MlcsOutcome};
+pub use self::constrained::mlcs_must_include;
+pub use self::corpus::PreprocessedCorpus;
+pub use self::error::MlcsError;
+pub use self::lcs3::{lcs3, lcs3_with_limit, LCS3_DEFAULT_LIMIT};
+pub use self::memory::{estimate_memory, MemoryEstimate};
+pub use self::runs::mlcs_collapse_runs;
+pub use self::scored::mlcs_substitution;
+pub use self::sequences::mlcs_sequences;
+pub use self::shared::SharedContext;
+pub use self::similarity::{
+    dedup_similar, lcs_len_pair_with_scratch, lcs_prefix_matrix, mlcs_len_pair, mlcs_similarity,
+    pairwise_similarity_matrix,
+};
+pub use self::top_k::top_k_common_subsequences;
+pub use self::weighted::weighted_multiple_lcs;
+pub use self::windowed::windowed_mlcs;
+
+#[derive(Clone, Eq, PartialEq)]
+struct QueueNode {
+    point: Vec<Option<usize>>,
+    f: u64,
+    h: u64,
+}
+
+impl Ord for QueueNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.f.cmp(&other.f).then_with(|| self.h.cmp(&other.h))
+    }
+}
+
+impl PartialOrd for QueueNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Options of the MLCS-A* search
+///
+/// # Fields
+/// * `width` - the band of f values explored at each round: only the points whose
+///   f is at least the round maximum minus `width` are expanded; the others are dropped
+///   for good, and with them the longest subsequences when the heuristic overestimates
+///   the chars left to match by more than `width`. With `u64::MAX`, every point is
+///   expanded and the search is exact, see `mlcs_exact`
+/// * `low_memory_reconstruction` - stores each reached point once, with a small breadcrumb
+///   leading to its parent, instead of three maps of points; the result is the same
+/// * `max_nodes` - interrupts the search once more than `max_nodes` points were given a cost
+/// * `timeout` - interrupts the search once it ran for that long
+///
+/// An interrupted search returns the deepest point reached before its last band, which
+/// still gives a common subsequence, though maybe not a longest one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MlcsConfig {
+    pub width: u64,
+    pub low_memory_reconstruction: bool,
+    pub max_nodes: Option<usize>,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for MlcsConfig {
+    fn default() -> Self {
+        MlcsConfig {
+            width: 20,
+            low_memory_reconstruction: false,
+            max_nodes: None,
+            timeout: None,
+        }
+    }
+}
+
+/// Counters describing the work done by a search
+///
+/// # Fields
+/// * `nodes_expanded` - the number of points which were given a cost, the root included
+/// * `max_queue_len` - the largest number of points waiting in the queue at the start of a band
+/// * `bands` - the number of rounds of the main loop
+/// * `goal_g` - the number of matched chars of the returned point, 0 if none was found
+/// * `interrupted` - whether the search was stopped by `max_nodes`, `timeout` or a
+///   cancellation
+///
+/// The inputs answered without a search, see `shortcut`, only count their `goal_g`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    pub nodes_expanded: usize,
+    pub max_queue_len: usize,
+    pub bands: usize,
+    pub goal_g: u64,
+    pub interrupted: bool,
+}
+
+/// The order in which the letters of the common alphabet are tried
+///
+/// The successors of a point, and the starting points, are generated letter by letter in
+/// this order. Among the points of a band with the same f and h the search compares their
+/// positions in the strings (see `collect_layer`), but when several successors of a point
+/// reach the upper bound, the first one discovered is kept: among several longest
+/// subsequences, this order decides which one is returned.
+///
+/// Both orders only depend on the set of strings, so the result does not depend on the
+/// order in which the strings are passed, nor on the locale.
+///
+/// # Variants
+/// * `CodePoint` - by increasing code point, the `Ord` of `char`, the default
+/// * `FirstOccurrence` - by first occurrence in the smallest string, the strings being
+///   compared by their chars
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlphabetOrder {
+    #[default]
+    CodePoint,
+    FirstOccurrence,
+}
+
+// the suffix table of a pair of strings, see `matrices_score`
+type SuffixTable = Arc<[Vec<u64>]>;
+// the next positions of a letter in a string, see `next_positions`
+type NextPositions = Arc<[Option<usize>]>;
+
+/// The state of an MLCS search among a set of strings
+///
+/// The strings are sequences of chars by default, but the search only compares their
+/// elements, which may be of any type `T: Eq + Hash + Ord + Clone` (see `mlcs_sequences`).
+///
+/// The preprocessing tables (`alphabet`, `chains`, `ms` and `mt`) are computed once by
+/// `new` and never modified afterwards. The strings and the tables are held behind `Arc`s,
+/// so contexts built from the same tables share them instead of copying them, e.g. the
+/// queries of a `PreprocessedCorpus`. The search maps (`f`, `g` and `parents`, or
+/// `crumbs` in low memory mode) are filled by `solve`, and `reset` empties them so the
+/// same context can be solved again, e.g. with another `MlcsConfig`, without rebuilding
+/// the tables.
+///
+/// Among several longest subsequences, the one returned only depends on the set of strings,
+/// and not on their order: the ties of the search are broken by comparing the positions of
+/// the points in the strings sorted by increasing chars (see `collect_layer`).
+// alphabet : the common alphabet
+// chains : the strings among which the common subsequence is
+// crumbs : the search tree when it replaces f, g and parents
+// d : the number of strings
+// f : for each point, an heuristic function
+// g : for each point, the number of ancestors
+// max_gap : the largest step of a coordinate from a point to its successor, None meaning
+//           any step
+// ms : the suffix tables of the pairs of strings i < j
+// mt : the lookup table
+// order : the indices of the strings, sorted by increasing chars
+// parents : the ancestor tree
+// runs : whether the weights are the lengths of runs, or the importance of the positions
+// weights : for each string, the number of chars each position stands for, or its
+//           importance, None meaning 1
+pub struct Context<T = char> {
+    alphabet: Vec<T>,
+    chains: Vec<Arc<[T]>>,
+    crumbs: Option<Breadcrumbs<T>>,
+    d: usize,
+    f: HashMap<Vec<Option<usize>>, u64>,
+    g: HashMap<Vec<Option<usize>>, u64>,
+    max_gap: Option<usize>,
+    ms: Vec<SuffixTable>,
+    mt: Vec<Vec<NextPositions>>,
+    order: Vec<usize>,
+    parents: HashMap<Vec<Option<usize>>, Option<Vec<Option<usize>>>>,
+    runs: bool,
+    weights: Option<Vec<Vec<u64>>>,
+}
+
+impl SearchStats {
+    // the statistics of an input answered without a search
+    fn solved<T>(mlcs: &[T]) -> Self {
+        SearchStats {
+            goal_g: mlcs.len() as u64,
+            ..SearchStats::default()
+        }
+    }
+}
+
+impl Context {
+    pub fn new(strings: &[&str]) -> Self {
+        // cast to ease [index] accessibily
+        let chains: Vec<Vec<char>> = strings.iter().map(|s| s.chars().collect()).collect();
+        Self::from_chains(chains)
+    }
+
+    /// Same as `new`, trying the letters of the alphabet in `alphabet_order`
+    pub fn with_alphabet_order(strings: &[&str], alphabet_order: AlphabetOrder) -> Self {
+        let mut ctx = Self::new(strings);
+        ctx.sort_alphabet(alphabet_order);
+        ctx
+    }
+
+    /// Builds a context from preprocessing tables computed by the caller, e.g. shared by
+    /// several searches over subsets of the same strings
+    ///
+    /// With d strings, the tables must be:
+    /// * `alphabet` - the letters occurring in every string, each once; the order of the
+    ///   letters is the one of `AlphabetOrder`, by increasing code point in `new`
+    /// * `mt` - for each letter `alphabet[k]` and each string `chains[i]`, `mt[k][i][p]` is
+    ///   the first position at or after p of the letter in the string, None if there is
+    ///   none, for every position p of the string
+    /// * `ms` - the d * (d - 1) / 2 suffix tables of the pairs i < j, in the order
+    ///   (0, 1), (0, 2), ..., (0, d - 1), (1, 2), ..., the table of (i, j) holding in
+    ///   `[p][q]` the LCS length of `chains[i][p..]` and `chains[j][q..]`, for p and q up
+    ///   to the lengths of the strings included
+    ///
+    /// These are the tables `new` computes. Nothing is checked but the number of tables
+    /// in debug builds: wrong tables give successors matching different chars in the
+    /// strings, or a heuristic which misguides the search. The tables are taken behind
+    /// `Arc`s, so the contexts of a pool of strings share a single copy of each.
+    #[cfg(feature = "batch")]
+    pub fn from_parts(
+        chains: Vec<Vec<char>>,
+        alphabet: Vec<char>,
+        mt: Vec<Vec<Arc<[Option<usize>]>>>,
+        ms: Vec<Arc<[Vec<u64>]>>,
+    ) -> Self {
+        Self::from_tables(chains, alphabet, ms, mt)
+    }
+
+    /// Builds a context where the match of a char is worth its score against itself in
+    /// `substitution`, the negative scores counting as 0
+    /// The suffix tables are the ones of `score_matrix` with `substitution`, where any two
+    /// chars may be aligned for their score: they are never below the worth of the common
+    /// subsequences of two suffixes, so the heuristic still bounds the worth left to match
+    fn from_substitution(chains: Vec<Vec<char>>, substitution: &SubstitutionMatrix) -> Self {
+        let alphabet = get_alphabet(&chains);
+        let mt = mt_table(&chains, &alphabet);
+
+        let mut ms = vec![];
+        for (i, s1) in chains.iter().enumerate() {
+            for s2 in &chains[i + 1..] {
+                ms.push(score_matrix(s1, s2, Some(substitution)).into());
+            }
+        }
+        let weights = chains
+            .iter()
+            .map(|s| {
+                s.iter()
+                    .map(|&c| substitution.score(c, c).max(0).unsigned_abs())
+                    .collect()
+            })
+            .collect();
+
+        let mut ctx = Self::from_tables(chains, alphabet, ms, mt);
+        ctx.weights = Some(weights);
+        ctx
+    }
+
+    /// Estimates the bytes used by the tables of this context, and by each point
+    /// a search over it will reach
+    pub fn estimated_memory(&self) -> MemoryEstimate {
+        let lengths: Vec<usize> = self.chains.iter().map(|chain| chain.len()).collect();
+        estimate_memory(&lengths, self.alphabet.len())
+    }
+
+    /// Runs the MLCS-A* search over the preprocessed strings
+    /// The context must be fresh or `reset` since the last call
+    /// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+    pub fn solve(&mut self, config: &MlcsConfig) -> String {
+        self.solve_with_stats(config).0
+    }
+
+    /// Same as `solve`, also reporting how much work the search did
+    pub fn solve_with_stats(&mut self, config: &MlcsConfig) -> (String, SearchStats) {
+        let mut result = String::new();
+        let stats = self.solve_into(config, &mut result);
+        (result, stats)
+    }
+
+    /// Same as `solve`, the successors of each band being computed by `threads` threads
+    /// See the `hda` module for how the work is shared, the result is exactly the one of `solve`
+    #[cfg(feature = "hda")]
+    pub fn solve_parallel(&mut self, config: &MlcsConfig, threads: usize) -> String {
+        let goal = hda::search(self, config, threads, &mut SearchStats::default());
+        goal.map_or_else(String::new, |p| self.common_seq(&p))
+    }
+
+    // ascend back up the parent tree to form the common subsequence
+    fn common_seq(&self, p: &Vec<Option<usize>>) -> String {
+        let mut common_subsequence = String::new();
+        self.common_seq_into(p, &mut common_subsequence);
+        common_subsequence
+    }
+}
+
+impl<T: Eq + Hash + Ord + Clone> Context<T> {
+    /// Same as `new`, on strings already split into chars, or into elements of any type
+    fn from_chains(chains: Vec<Vec<T>>) -> Self {
+        let alphabet: Vec<T> = get_alphabet(&chains);
+
+        let ms = matrices_score(&chains);
+
+        let mt = mt_table(&chains, &alphabet);
+
+        Self::from_tables(chains, alphabet, ms, mt)
+    }
+
+    /// Builds a context from already computed preprocessing tables
+    /// `mt[k]` must be the lookup table of the letter `alphabet[k]`
+    /// and `ms` must hold the suffix tables of the pairs i < j, in `pair_index` order
+    /// The strings already behind an `Arc` are shared, the others are moved into one
+    fn from_tables(
+        chains: Vec<impl Into<Arc<[T]>>>,
+        alphabet: Vec<T>,
+        ms: Vec<SuffixTable>,
+        mt: Vec<Vec<NextPositions>>,
+    ) -> Self {
+        let chains: Vec<Arc<[T]>> = chains.into_iter().map(Into::into).collect();
+        debug_assert_eq!(mt.len(), alphabet.len(), "one lookup table per letter");
+        debug_assert!(
+            mt.iter().all(|tables| tables.len() == chains.len()),
+            "one lookup table per letter and string"
+        );
+        debug_assert_eq!(
+            ms.len(),
+            pair_count(chains.len()),
+            "one suffix table per pair of strings"
+        );
+        // equal strings keep their order, and always have the same position in a point
+        let mut order: Vec<usize> = (0..chains.len()).collect();
+        order.sort_by(|&i, &j| chains[i].cmp(&chains[j]));
+        let mut ctx = Context {
+            alphabet,
+            d: chains.len(),
+            chains,
+            crumbs: None,
+            f: HashMap::new(),
+            g: HashMap::new(),
+            max_gap: None,
+            ms,
+            mt,
+            order,
+            parents: HashMap::new(),
+            runs: false,
+            weights: None,
+        };
+        ctx.reset();
+        ctx
+    }
+
+    /// Builds a context where each position of the strings has a weight, the match of a
+    /// char in every string being worth the smallest of their weights
+    /// With `runs`, each position stands for a run of `weight` identical chars, repeated
+    /// that many times in the subsequence, otherwise the weights are only the importance
+    /// of the positions
+    fn from_weights(chains: Vec<Vec<T>>, weights: Vec<Vec<u64>>, runs: bool) -> Self {
+        let alphabet = get_alphabet(&chains);
+        let mt = mt_table(&chains, &alphabet);
+
+        let mut ms = vec![];
+        for (i, (s1, w1)) in chains.iter().zip(&weights).enumerate() {
+            for (s2, w2) in chains[i + 1..].iter().zip(&weights[i + 1..]) {
+                ms.push(score_matrix_by(s1, s2, |i, j| w1[i].min(w2[j])).into());
+            }
+        }
+
+        let mut ctx = Self::from_tables(chains, alphabet, ms, mt);
+        ctx.runs = runs;
+        ctx.weights = Some(weights);
+        ctx
+    }
+
+    /// Sorts `alphabet` in `alphabet_order`, keeping each lookup table of `mt` with its letter
+    fn sort_alphabet(&mut self, alphabet_order: AlphabetOrder) {
+        let mut letters: Vec<(T, Vec<NextPositions>)> =
+            self.alphabet.drain(..).zip(self.mt.drain(..)).collect();
+        match alphabet_order {
+            AlphabetOrder::CodePoint => letters.sort_by(|(a, _), (b, _)| a.cmp(b)),
+            // every common letter occurs in the smallest string, and without strings
+            // there is no letter to sort
+            AlphabetOrder::FirstOccurrence => {
+                if let Some(&first) = self.order.first() {
+                    let smallest = &self.chains[first];
+                    letters.sort_by_cached_key(|(ch, _)| smallest.iter().position(|c| c == ch));
+                }
+            }
+        }
+        (self.alphabet, self.mt) = letters.into_iter().unzip();
+    }
+
+    /// Forgets the state of a previous search
+    /// `f`, `g` and `parents` are emptied and only hold the root point afterwards,
+    /// while `alphabet`, `chains`, `ms` and `mt` are kept as they are
+    pub fn reset(&mut self) {
+        self.f.clear();
+        self.g.clear();
+        self.parents.clear();
+        self.crumbs = None;
+
+        // an impossible to reach point, father of all points
+        let p0 = vec![None; self.d];
+        self.parents.insert(p0.clone(), None);
+        self.g.insert(p0.clone(), 0);
+        self.f.insert(p0, 0);
+    }
+
+    // same as `solve_with_stats`, the subsequence being appended to buf
+    fn solve_into(&mut self, config: &MlcsConfig, buf: &mut impl Extend<T>) -> SearchStats {
+        let mut stats = SearchStats::default();
+        let goal = self.search(config, &mut stats, None, |_| false);
+        if let Some(p) = goal {
+            self.common_seq_into(&p, buf);
+        }
+
+        stats.nodes_expanded = self.crumbs.as_ref().map_or(self.g.len(), Breadcrumbs::len);
+        stats
+    }
+
+    /// The main loop of the search
+    /// Stops as soon as a point reaches the upper bound of the length, or as soon as a point
+    /// whose g satisfies `stop` is reached, and returns that point, or the incumbent if it is
+    /// deeper (see `Bounds`)
+    /// When the queue is empty, or when the limits of `config` are exceeded, returns the
+    /// incumbent
+    /// `cancel` is read before each band, and the search returns None once it is set
+    fn search(
+        &mut self,
+        config: &MlcsConfig,
+        stats: &mut SearchStats,
+        cancel: Option<&AtomicBool>,
+        stop: impl Fn(u64) -> bool,
+    ) -> Option<Vec<Option<usize>>> {
+        // empty strings have no common char, the root is the only point
+        if self.chains.iter().all(|chain| chain.is_empty()) {
+            return None;
+        }
+        let deadline = config.timeout.map(|timeout| Instant::now() + timeout);
+        let mut queue: BinaryHeap<QueueNode> = self.init_queue(config);
+        let mut bounds = Bounds::new(&queue);
+
+        let goal = 'search: loop {
+            if queue.is_empty() {
+                break None;
+            }
+            if cancel.is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed)) {
+                stats.interrupted = true;
+                return None;
+            }
+            stats.bands += 1;
+            stats.max_queue_len = max(stats.max_queue_len, queue.len());
+
+            let y = band_threshold(&queue, config.width);
+            let current_layer = collect_layer(&mut queue, y, &self.order);
+            let mut next_points: Vec<Vec<Option<usize>>> = Vec::new();
+
+            for node in current_layer {
+                // a node with h = 0 has no successor and is only kept by the bounds,
+                // since the successors already generated may lead further
+                if stop(self.g_of(&node.point)) {
+                    break 'search Some(node.point);
+                }
+                if bounds.prunes(&node) {
+                    continue;
+                }
+                if self.exceeds_limits(config, deadline) {
+                    stats.interrupted = true;
+                    break 'search None;
+                }
+                let p = node.point;
+                for q in self.get_successors(&p) {
+                    // a point reached again in the band keeps the parent giving it
+                    // the longest prefix
+                    let known = next_points.contains(&q);
+                    if known && self.g_through(&p, &q) <= self.g_of(&q) {
+                        continue;
+                    }
+                    self.update_suc(p.clone(), q.clone());
+                    let g = self.g_of(&q);
+                    if stop(g) || bounds.reached(g) {
+                        break 'search Some(q);
+                    }
+                    if !known {
+                        next_points.push(q);
+                    }
+                }
+            }
+            bounds.update(next_points.iter().map(|q| (self.g_of(q), q)));
+            queue = next_points
+                .into_iter()
+                .map(|point| self.node_from_point(point))
+                .collect();
+        };
+
+        let goal = bounds.best(goal, |p| self.g_of(p));
+        if let Some(p) = &goal {
+            stats.goal_g = self.depth(p);
+        }
+        goal
+    }
+
+    // whether the search went past the max_nodes or the deadline of config
+    fn exceeds_limits(&self, config: &MlcsConfig, deadline: Option<Instant>) -> bool {
+        let nodes = self.crumbs.as_ref().map_or(self.g.len(), Breadcrumbs::len);
+        config.max_nodes.is_some_and(|max_nodes| nodes > max_nodes)
+            || deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    // given a point p and his successor q, computes necessary informations
+    // point p is marked PARENT of q
+    fn update_suc(&mut self, p: Vec<Option<usize>>, q: Vec<Option<usize>>) {
+        let h = self.heuristic(&q);
+        self.record_successor(p, q, h);
+    }
+
+    // same as update_suc, h being the already computed heuristic of q
+    fn record_successor(&mut self, p: Vec<Option<usize>>, q: Vec<Option<usize>>, h: u64) {
+        // g(q) = g(p) + the number of chars matched at q
+        let nb = self.g_through(&p, &q);
+        // the cost function for point q : h(q) + g(q)
+        let f = add_costs(h, nb);
+        // the breadcrumbs keep the char, the maps find it back from the point
+        let ch = self.crumbs.as_ref().and_then(|_| self.matched_char(&q));
+        let copies = self.copies(&q);
+        if let Some(crumbs) = &mut self.crumbs {
+            crumbs.insert(q, &p, ch, copies, nb, f);
+            return;
+        }
+        self.g.insert(q.clone(), nb);
+        self.f.insert(q.clone(), f);
+        // saves the fact that p is the parent of q
+        self.parents.insert(q, Some(p));
+    }
+
+    // the g of q when reached from p
+    fn g_through(&self, p: &Vec<Option<usize>>, q: &[Option<usize>]) -> u64 {
+        add_costs(self.g_of(p), self.gain(q))
+    }
+
+    // every point the search reads was recorded by `record_successor`, or is the root
+    // recorded by `reset`, so the maps always hold it
+    fn g_of(&self, p: &Vec<Option<usize>>) -> u64 {
+        self.crumbs
+            .as_ref()
+            .map_or_else(|| self.g[p], |crumbs| crumbs.g(p))
+    }
+
+    fn f_of(&self, p: &Vec<Option<usize>>) -> u64 {
+        self.crumbs
+            .as_ref()
+            .map_or_else(|| self.f[p], |crumbs| crumbs.f(p))
+    }
+
+    /// Finds all succcesors of the point p
+    /// A successor of p = (p_1, p_2, etc, p_n) is a point q = (q_1, q_2, etc, q_n)
+    /// such that q_1 > p_1, q_2 > p_2, etc, q_n > p_n
+    /// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+    ///
+    /// # Arguments
+    /// # 'Context' A struct containing informations
+    /// # 'p' The point under examination
+    ///
+    /// # Returns
+    /// An array of the successors, empty if a coordinate of p is None: the coordinates
+    /// of a successor always line up with the strings they belong to
+    fn get_successors(&self, p: &[Option<usize>]) -> Vec<Vec<Option<usize>>> {
+        let mut successors = Vec::with_capacity(self.alphabet.len());
+        // most letters have no successor, they only fill and clear this buffer
+        let mut succ = Vec::with_capacity(p.len());
+        for ch_idx in 0..self.alphabet.len() {
+            if self.successor_into(p, ch_idx, &mut succ) {
+                successors.push(succ.clone());
+            }
+        }
+        successors
+    }
+
+    /// Finds the successor of the point p matching the letter alphabet[ch_idx],
+    /// made of the next position of that letter after p in each string
+    ///
+    /// # Returns
+    /// None if the letter does not occur after p in some string, p being possibly at the
+    /// last char of that string, if its next position there is more than `max_gap` after
+    /// p, or if a coordinate of p is None (only the root has such coordinates, see
+    /// `get_starting_p`)
+    #[cfg(any(feature = "hda", test))]
+    fn next_successor_for_letter(
+        &self,
+        p: &[Option<usize>],
+        ch_idx: usize,
+    ) -> Option<Vec<Option<usize>>> {
+        let mut succ = Vec::with_capacity(p.len());
+        self.successor_into(p, ch_idx, &mut succ).then_some(succ)
+    }
+
+    // writes the successor of p matching alphabet[ch_idx] into succ, returning false
+    // where `next_successor_for_letter` returns None, succ then holding a part of it
+    fn successor_into(
+        &self,
+        p: &[Option<usize>],
+        ch_idx: usize,
+        succ: &mut Vec<Option<usize>>,
+    ) -> bool {
+        succ.clear();
+        for (i, p_ith_elt) in p.iter().enumerate() {
+            let Some(idx) = *p_ith_elt else {
+                return false;
+            };
+            // mt has one entry per char, there is nothing after the last one
+            let Some(next) = self.mt[ch_idx][i].get(idx + 1).copied().flatten() else {
+                return false;
+            };
+            if self.max_gap.is_some_and(|max_gap| next - idx > max_gap) {
+                return false;
+            }
+            succ.push(Some(next));
+        }
+        true
+    }
+
+    // same as common_seq, appending the subsequence to buf
+    fn common_seq_into(&self, p: &Vec<Option<usize>>, buf: &mut impl Extend<T>) {
+        if let Some(crumbs) = &self.crumbs {
+            crumbs.sequence_into(p, buf);
+            return;
+        }
+        let mut common_subsequence: Vec<T> = vec![];
+        // Gaining mutability
+        let mut p = p;
+
+        while let Some(parent) = &self.parents[p] {
+            if let Some(ch) = self.matched_char(p) {
+                for _ in 0..self.copies(p) {
+                    common_subsequence.push(ch.clone());
+                }
+            }
+
+            // getting the parent of current point
+            p = parent;
+        }
+
+        buf.extend(common_subsequence.into_iter().rev());
+    }
+
+    // ascend back up the parent tree, keeping each matched char and its positions
+    // only available when the search kept the points of the parent tree
+    fn columns(&self, p: &Vec<Option<usize>>) -> Vec<(T, Vec<usize>)> {
+        let mut columns = vec![];
+        let mut p = p;
+        while let Some(parent) = &self.parents[p] {
+            if let Some(ch) = self.matched_char(p) {
+                columns.push((ch, p.iter().flatten().copied().collect()));
+            }
+            p = parent;
+        }
+        columns.reverse();
+        columns
+    }
+
+    // the char matched at p, read in the first string and checked against the others
+    // None if a coordinate is None, or if the strings disagree, which is a bug of the search
+    fn matched_char(&self, p: &[Option<usize>]) -> Option<T> {
+        let mut chars = p
+            .iter()
+            .zip(&self.chains)
+            .map(|(idx, chain)| idx.map(|idx| &chain[idx]));
+        let ch = chars.next()??;
+        let agree = chars.all(|other| other == Some(ch));
+        debug_assert!(
+            agree,
+            "the point {p:?} does not match the same char in every string"
+        );
+        agree.then(|| ch.clone())
+    }
+
+    // the number of chars of the common subsequence ending at p, following the parent tree
+    // g(p) is only a lower bound of it: when a point gets a new parent, the g of its
+    // descendants is not updated
+    fn depth(&self, p: &Vec<Option<usize>>) -> u64 {
+        if let Some(crumbs) = &self.crumbs {
+            return crumbs.depth(p);
+        }
+        let mut depth = 0;
+        let mut p = p;
+        while let Some(parent) = &self.parents[p] {
+            depth += self.copies(p);
+            p = parent;
+        }
+        depth
+    }
+
+    // the number of times the char matched at q is repeated in the common subsequence
+    fn copies(&self, q: &[Option<usize>]) -> u64 {
+        if self.runs {
+            self.gain(q)
+        } else {
+            1
+        }
+    }
+
+    // the value a match at q adds to the common subsequence, its number of chars
+    // unless the positions have an importance
+    fn gain(&self, q: &[Option<usize>]) -> u64 {
+        match &self.weights {
+            None => 1,
+            Some(weights) => q
+                .iter()
+                .zip(weights)
+                .filter_map(|(qi, w)| qi.map(|idx| w[idx]))
+                .min()
+                .unwrap_or(0),
+        }
+    }
+
+    /// CF Initqueue
+    fn get_starting_p(&self) -> Vec<Vec<Option<usize>>> {
+        let mut successors: Vec<Vec<Option<usize>>> = vec![];
+
+        // for each alphabet letter, finds the next match
+        // meaning the a point where all strings share a character
+        // example: In ["AB", "BC", "CB", "BF"],
+        // A match for the letter B would be p = (1, 0, 1, 0)
+        for (ch_idx, _) in self.alphabet.iter().enumerate() {
+            // for each string, finds the next position of that letter
+            let mut succ: Vec<Option<usize>> = vec![];
+            for i in 0..(self.chains.len()) {
+                // gets the next position of the current letter, a letter of the
+                // alphabet occurring in every string, none of which is then empty
+                let next_ch_idx = self.mt[ch_idx][i][0];
+                succ.push(next_ch_idx);
+            }
+
+            // once the vector is complete, we add it to the successors,
+            // unless the letter is missing from a string
+            if succ.iter().all(Option::is_some) {
+                successors.push(succ);
+            }
+        }
+
+        successors
+    }
+
+    /// Computes the heuristic function given a point
+    /// min ( { M_ij[ p[i] + 1 ][ p[j] + 1 ] | (i,j) in [0 ; d], i < j } )
+    /// i.e. a bound on the number of chars that can still be matched after p
+    /// M_ji[ p[j] + 1 ][ p[i] + 1 ] is the same LCS length as M_ij[ p[i] + 1 ][ p[j] + 1 ],
+    /// so only the tables of the pairs i < j are kept
+    /// With a single string, there is no pair and the rest of the string is matched
+    /// Only defined for points matching a char in every string, the root excluded
+    /// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+    fn heuristic(&self, p: &[Option<usize>]) -> u64 {
+        debug_assert!(
+            p.iter().all(Option::is_some),
+            "the heuristic of {p:?} is not defined"
+        );
+        if self.d == 1 {
+            return p[0].map_or(0, |p0| {
+                self.weights.as_ref().map_or_else(
+                    || (self.chains[0].len() - p0 - 1) as u64,
+                    |weights| weights[0][p0 + 1..].iter().sum(),
+                )
+            });
+        }
+        let mut similarity: Vec<u64> = vec![];
+        for i in 0..self.d {
+            for j in (i + 1)..self.d {
+                if let (Some(pi), Some(pj)) = (p[i], p[j]) {
+                    similarity.push(self.ms[pair_index(i, j, self.d)][pi + 1][pj + 1]);
+                }
+            }
+        }
+
+        similarity.iter().min().copied().unwrap_or(0)
+    }
+
+    /// Add the first matches to the queue
+    /// For each starting point found, sets an impossible point as parent
+    /// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+    ///
+    /// # Arguments
+    ///
+    /// * `self' - A structure containing informations
+    /// * 'config' - The options of the search, deciding how the search tree is stored
+    fn init_queue(&mut self, config: &MlcsConfig) -> BinaryHeap<QueueNode> {
+        if config.low_memory_reconstruction {
+            self.crumbs = Some(Breadcrumbs::new(self.d));
+        }
+        let mut queue = BinaryHeap::new();
+        for q in self.get_starting_p() {
+            self.update_suc(vec![None; self.d], q.clone());
+            queue.push(self.node_from_point(q));
+        }
+        queue
+    }
+
+    fn node_from_point(&self, point: Vec<Option<usize>>) -> QueueNode {
+        QueueNode {
+            f: self.f_of(&point),
+            h: self.heuristic(&point),
+            point,
+        }
+    }
+}
+
+/// Preprocesses the strings as `Context::new`, refusing inputs with fewer than two strings
+///
+/// # Errors
+///
+/// * `MlcsError::EmptyInput` if there is no string
+/// * `MlcsError::SingleString` if there is only one string
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{Context, MlcsConfig, MlcsError};
+///
+/// fn common_part(inputs: &[&str]) -> Result<String, MlcsError> {
+///     let mut ctx = Context::try_from(inputs)?;
+///     Ok(ctx.solve(&MlcsConfig::default()))
+/// }
+///
+/// assert_eq!(common_part(&["gxtxayb", "abgtab"]), Ok("gtab".to_string()));
+/// assert_eq!(common_part(&["abc"]), Err(MlcsError::SingleString));
+/// ```
+impl<'a> TryFrom<&'a [&'a str]> for Context {
+    type Error = MlcsError;
+
+    fn try_from(strings: &'a [&'a str]) -> Result<Self, Self::Error> {
+        match strings.len() {
+            0 => Err(MlcsError::EmptyInput),
+            1 => Err(MlcsError::SingleString),
+            _ => Ok(Self::new(strings)),
+        }
+    }
+}
+
+/// Finds the common alphabet of the strings, the letters occurring in every one of them
+/// They are the letters of the shortest string found in all the others
+///
+/// # Arguments
+/// # 'chains' The strings among wich the mlcs is
+///
+/// # Returns
+/// The common letters by increasing code point, empty when there are no strings
+fn get_alphabet<T: Eq + Hash + Ord + Clone>(chains: &[Vec<T>]) -> Vec<T> {
+    let Some(shortest) = chains.iter().min_by_key(|s| s.len()) else {
+        return vec![];
+    };
+    let letters: Vec<HashSet<&T>> = chains.iter().map(|s| s.iter().collect()).collect();
+    let mut alphabet: Vec<T> = shortest.clone();
+    alphabet.sort();
+    alphabet.dedup();
+    alphabet.retain(|c| letters.iter().all(|letters| letters.contains(c)));
+
+    alphabet
+}
+
+/// Computes the suffix tables between each pair of string
+/// used by the MLCS-Astar heuristic function
+/// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+///
+/// The table of j and i is the transpose of the one of i and j, and the heuristic never
+/// compares a string with itself, so only the pairs i < j are computed, in `pair_index`
+/// order.
+///
+/// # Arguments
+///
+/// * `chains` - A slice of collected strings
+///            - from which the suffix tables are computed.
+fn matrices_score<T: Eq>(chains: &[Vec<T>]) -> Vec<SuffixTable> {
+    let mut scores: Vec<SuffixTable> = vec![];
+    for (i, s1) in chains.iter().enumerate() {
+        for s2 in &chains[i + 1..] {
+            scores.push(score_matrix_by(s1, s2, |_, _| 1).into());
+        }
+    }
+
+    scores
+}
+
+/// Builds the lookup table used for accessing the index of the next char
+///
+/// # Arguments
+/// # 'chains' the strings as a matrix of char
+/// # 'alphabet' the letters common to all strings, see `get_alphabet`
+///
+/// # Returns
+/// An array of matrices, `mt[k]` being the one of the letter `alphabet[k]`.
+/// Each matrix is tied to a string and can indicate, given a letter,
+/// the next position of that letter in the string.
+fn mt_table<T: Eq>(chains: &[Vec<T>], alphabet: &[T]) -> Vec<Vec<NextPositions>> {
+    alphabet
+        .iter()
+        .map(|ch| {
+            chains
+                .iter()
+                .map(|s| next_positions(s, ch).into())
+                .collect()
+        })
+        .collect()
+}
+
+/// Computes, for each index i of s, the position of the first
+/// occurrence of ch at or after i (None if there is no such occurrence)
+fn next_positions<T: Eq>(s: &[T], ch: &T) -> Vec<Option<usize>> {
+    let mut v: Vec<Option<usize>> = vec![None; s.len()];
+    let mut lpos = None;
+
+    // iterating backwards on the string
+    for i in (0..(s.len())).rev() {
+        if s[i] == *ch {
+            lpos = Some(i);
+        }
+        // pushing the index of the last encounter with the current letter
+        v[i] = lpos;
+    }
+
+    v
+}
+
+/// Finds one of the longest_common_subsequence among multiple strings
+/// using a similar approach to the A* algorithm in graph theory
+/// [Documentation](https://github.com/epita-rs/MLCS/blob/main/doc/paper.pdf)
+/// # Arguments
+///
+/// * `S` - Array of strings.
+///
+/// # Returns
+///
+/// * `String` if a Longest Common Subsequence exists
+/// * `String' if no LCS was found
+///
+/// The strings are compared char by char, i.e. by Unicode code point, as in `mlcs_chars`.
+/// Use `mlcs_bytes` to compare their bytes instead.
+///
+/// The search is the one of `mlcs_approx`, whose band may miss the longest subsequences:
+/// `mlcs_exact` always finds one.
+///
+/// Neither this function nor the other ones of the module panic, whatever the strings:
+/// no string, empty strings, strings without a common char or any Unicode. The only
+/// limit is the memory of the suffix tables, see `estimate_memory`.
+pub fn multiple_longest_common_subsequence(chains: &Vec<&str>) -> String {
+    solve_chains(to_chains(chains), &MlcsConfig::default())
+}
+
+/// Same as `multiple_longest_common_subsequence`, the subsequence replacing the content
+/// of `buf`, whose allocation is reused
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_into_string_reuse;
+///
+/// let mut buf = String::with_capacity(16);
+/// for (input, expected) in [(["ABC", "AC"], "AC"), (["abcd", "bd"], "bd")] {
+///     mlcs_into_string_reuse(&input, &mut buf);
+///     assert_eq!(buf, expected);
+/// }
+/// assert!(buf.capacity() >= 16);
+/// ```
+pub fn mlcs_into_string_reuse(chains: &[&str], buf: &mut String) {
+    buf.clear();
+    solve_chains_into(to_chains(chains), &MlcsConfig::default(), buf);
+}
+
+/// Same as `multiple_longest_common_subsequence`, stating that the strings are compared
+/// char by char, i.e. by Unicode code point
+///
+/// Two chars sharing some bytes of their UTF-8 encoding, e.g. 'é' and 'è', do not match.
+/// The result is always a valid string.
+/// This is `mlcs_sequences` over the chars of the strings.
+pub fn mlcs_chars(chains: &[&str]) -> String {
+    solve_chains(to_chains(chains), &MlcsConfig::default())
+}
+
+/// Finds a longest common subsequence of byte strings, compared byte by byte
+///
+/// Each byte goes through the same search as a char of `mlcs_chars`, as the char of the
+/// same value. On UTF-8 strings, the bytes of different chars may match, e.g. the first
+/// byte of 'é' and 'è', and the result is not always valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{mlcs_bytes, mlcs_chars};
+///
+/// let (a, b) = ("é", "è");
+/// assert_eq!(mlcs_chars(&[a, b]), "");
+/// assert_eq!(mlcs_bytes(&[a.as_bytes(), b.as_bytes()]), [0xc3]);
+/// ```
+pub fn mlcs_bytes(chains: &[&[u8]]) -> Vec<u8> {
+    let chains: Vec<Vec<char>> = chains
+        .iter()
+        .map(|s| s.iter().copied().map(char::from).collect())
+        .collect();
+    let result = solve_chains(chains, &MlcsConfig::default());
+    // every char comes from a byte
+    result.chars().map(|c| c as u8).collect()
+}
+
+/// Finds a common subsequence of the strings, as long as possible within the band of the
+/// default configuration
+///
+/// The search only expands, at each round, the points whose f is at most
+/// `MlcsConfig::default().width` below the best one. The result is a longest common
+/// subsequence unless the heuristic, the smallest LCS length of two of the strings, goes
+/// over the chars that can still be matched by more than that on the way, which needs many
+/// strings or long ones. Otherwise, it may be a few chars shorter.
+/// This is `multiple_longest_common_subsequence`, named after what it guarantees.
+pub fn mlcs_approx(chains: &[&str]) -> String {
+    solve_chains(to_chains(chains), &MlcsConfig::default())
+}
+
+/// Finds a longest common subsequence of the strings
+///
+/// Every point reached is expanded, as with a `width` of `u64::MAX`: the result is always
+/// a longest common subsequence, but the search keeps every point of each round and can
+/// take much more time and memory than `mlcs_approx` on long strings.
+pub fn mlcs_exact(chains: &[&str]) -> String {
+    let config = MlcsConfig {
+        width: u64::MAX,
+        ..MlcsConfig::default()
+    };
+    solve_chains(to_chains(chains), &config)
+}
+
+/// Same as `multiple_longest_common_subsequence`, refusing strings which share more than
+/// `max_alphabet` letters
+///
+/// The lookup table holds a position per char of every string for each letter occurring in
+/// every string, so thousands of shared letters, e.g. CJK ideographs, take much more memory
+/// than the strings. The letters are counted before any table is built.
+///
+/// # Errors
+///
+/// `MlcsError::AlphabetTooLarge` if more than `max_alphabet` letters occur in every string.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{mlcs_with_max_alphabet, MlcsError};
+///
+/// assert_eq!(mlcs_with_max_alphabet(&["abcd", "bdx"], 2), Ok("bd".to_string()));
+/// assert_eq!(
+///     mlcs_with_max_alphabet(&["abcd", "bdcx"], 2),
+///     Err(MlcsError::AlphabetTooLarge {
+///         letters: 3,
+///         limit: 2
+///     })
+/// );
+/// ```
+pub fn mlcs_with_max_alphabet(chains: &[&str], max_alphabet: usize) -> Result<String, MlcsError> {
+    let chains = to_chains(chains);
+    let letters = get_alphabet(&chains).len();
+    if letters > max_alphabet {
+        return Err(MlcsError::AlphabetTooLarge {
+            letters,
+            limit: max_alphabet,
+        });
+    }
+    Ok(solve_chains(chains, &MlcsConfig::default()))
+}
+
+pub(super) fn to_chains(strings: &[&str]) -> Vec<Vec<char>> {
+    strings.iter().map(|s| s.chars().collect()).collect()
+}
+
+/// The strings of an entry point, ready for the search, see `prepare_chains`
+pub(super) enum Prepared<T> {
+    /// The MLCS found without a search
+    Solved(Vec<T>),
+    /// The context of the distinct strings, which still have to be searched
+    Search(Box<Context<T>>),
+}
+
+/// Does what every entry point does before searching: `shortcut` answers the inputs which
+/// need no search, in linear time, and the others are preprocessed once per distinct
+/// string, see `dedup_chains`
+pub(super) fn prepare_chains<T: Eq + Hash + Ord + Clone>(chains: Vec<Vec<T>>) -> Prepared<T> {
+    // strings without any char have nothing in common, whatever the tables hold
+    if chains.iter().all(Vec::is_empty) {
+        return Prepared::Solved(vec![]);
+    }
+    match shortcut(&chains) {
+        Some(mlcs) => Prepared::Solved(mlcs),
+        None => Prepared::Search(Box::new(Context::from_chains(dedup_chains(chains).0))),
+    }
+}
+
+/// Solves the strings with `config`, unless `shortcut` finds their MLCS without a search
+/// The repeated strings are searched once, see `dedup_chains`
+fn solve_chains(chains: Vec<Vec<char>>, config: &MlcsConfig) -> String {
+    let mut mlcs = String::new();
+    solve_chains_into(chains, config, &mut mlcs);
+    mlcs
+}
+
+/// Same as `solve_chains`, on strings of any elements, the MLCS being appended to `buf`
+/// `mlcs_sequences` calls it on its rows, the string entry points on their chars
+fn solve_chains_into<T: Eq + Hash + Ord + Clone>(
+    chains: Vec<Vec<T>>,
+    config: &MlcsConfig,
+    buf: &mut impl Extend<T>,
+) {
+    match prepare_chains(chains) {
+        Prepared::Solved(mlcs) => buf.extend(mlcs),
+        Prepared::Search(mut ctx) => {
+            ctx.solve_into(config, buf);
+        }
+    }
+}
+
+/// Keeps the first occurrence of each string
+///
+/// A repeated string changes neither the MLCS nor the search, it only multiplies the suffix
+/// tables and the coordinates of the points: the copies of a string always have the same
+/// coordinate, the suffix table of two copies holds the length of the rest of the string,
+/// which is never below the table of another pair, and in the order of `collect_layer` the
+/// positions of the copies only repeat each other.
+///
+/// # Returns
+///
+/// The distinct strings, and for each string, the index of its copy among them.
+fn dedup_chains<T: Eq + Hash + Clone>(chains: Vec<Vec<T>>) -> (Vec<Vec<T>>, Vec<usize>) {
+    let mut distinct: Vec<Vec<T>> = vec![];
+    let mut index: HashMap<Vec<T>, usize> = HashMap::new();
+    let copies = chains
+        .into_iter()
+        .map(|chain| {
+            *index.entry(chain).or_insert_with_key(|chain| {
+                distinct.push(chain.clone());
+                distinct.len() - 1
+            })
+        })
+        .collect();
+    (distinct, copies)
+}
+
+/// Finds the MLCS of the inputs which need no search, in linear time
+///
+/// Building the suffix tables takes quadratic time and memory, which is far too much for
+/// long repetitive strings whose answer is obvious:
+/// * when the shortest string is a subsequence of all the others, e.g. when all the strings
+///   are equal, it is the MLCS
+/// * when no letter occurs in every string, the MLCS is empty
+/// * when a single letter occurs in every string, the MLCS is that letter, as many times as
+///   it occurs in the string holding the fewest of them
+///
+/// Returns None for the other inputs, and without strings.
+fn shortcut<T: Eq + Hash + Ord + Clone>(chains: &[impl AsRef<[T]>]) -> Option<Vec<T>> {
+    let shortest = chains.iter().map(AsRef::as_ref).min_by_key(|s| s.len())?;
+    if chains
+        .iter()
+        .all(|s| is_subsequence_of(shortest, s.as_ref()))
+    {
+        return Some(shortest.to_vec());
+    }
+
+    let letters: Vec<HashSet<&T>> = chains.iter().map(|s| s.as_ref().iter().collect()).collect();
+    let mut common: Vec<T> = shortest.to_vec();
+    common.sort_unstable();
+    common.dedup();
+    common.retain(|c| letters.iter().all(|letters| letters.contains(c)));
+    match &common[..] {
+        [] => Some(vec![]),
+        [c] => {
+            let count = chains
+                .iter()
+                .map(|s| s.as_ref().iter().filter(|&x| x == c).count())
+                .min()?;
+            Some(vec![c.clone(); count])
+        }
+        _ => None,
+    }
+}
+
+fn is_subsequence_of<T: Eq>(sub: &[T], s: &[T]) -> bool {
+    let mut chars = s.iter();
+    sub.iter().all(|c| chars.any(|x| x == c))
+}
+
+/// Same as `multiple_longest_common_subsequence`, taking owned strings,
+/// e.g. the lines of a file
+///
+/// # Example
+///
+/// ```
+/// use std::io::{BufRead, Cursor};
+/// use the_algorithms_rust::string::mlcs_owned;
+///
+/// let file = Cursor::new("ABC\nAC\nBAC\n");
+/// let lines: Vec<String> = file.lines().collect::<Result<_, _>>().unwrap();
+/// assert_eq!(mlcs_owned(lines), "AC");
+/// ```
+pub fn mlcs_owned(chains: Vec<String>) -> String {
+    let chains: Vec<&str> = chains.iter().map(String::as_str).collect();
+    multiple_longest_common_subsequence(&chains)
+}
+
+/// Strings collected from an iterator, whose MLCS is found by `solve`
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::MlcsInput;
+///
+/// let input: MlcsInput = ["abc", "abd"].into_iter().collect();
+/// assert_eq!(input.solve(), "ab");
+///
+/// let lcs = "ABC AC BAC".split(' ').collect::<MlcsInput>().solve();
+/// assert_eq!(lcs, "AC");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MlcsInput(Vec<String>);
+
+impl<S: AsRef<str>> FromIterator<S> for MlcsInput {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        MlcsInput(iter.into_iter().map(|s| s.as_ref().to_owned()).collect())
+    }
+}
+
+impl MlcsInput {
+    /// Same as `mlcs_owned` on the collected strings
+    pub fn solve(self) -> String {
+        mlcs_owned(self.0)
+    }
+}
+
+/// Same as `multiple_longest_common_subsequence`, each run of whitespace being
+/// collapsed to a single space beforehand, e.g. to compare code indented differently
+///
+/// A run of whitespace is then matched as a whole: the result holds at most one space
+/// between two other chars, and can be shorter than the LCS of the original strings,
+/// whose runs of whitespace may be matched across several runs of another string.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_collapse_whitespace;
+///
+/// let a = "if x {\n    y\n}";
+/// let b = "if x {\n\ty\n}";
+/// assert_eq!(mlcs_collapse_whitespace(&[a, b]), "if x { y }");
+/// ```
+pub fn mlcs_collapse_whitespace(chains: &[&str]) -> String {
+    if chains.is_empty() {
+        return String::new();
+    }
+    let collapsed: Vec<String> = chains.iter().map(|s| collapse_whitespace(s)).collect();
+    let collapsed: Vec<&str> = collapsed.iter().map(String::as_str).collect();
+    multiple_longest_common_subsequence(&collapsed)
+}
+
+/// Replaces each run of whitespace of `s` by a single space
+fn collapse_whitespace(s: &str) -> String {
+    let mut collapsed = String::with_capacity(s.len());
+    let mut in_whitespace = false;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
+
+/// Same as `multiple_longest_common_subsequence`, the chars of `ignore` being removed from
+/// the strings beforehand, e.g. to compare texts regardless of their punctuation
+///
+/// The ignored chars are never matched, and never appear in the result, even when they
+/// are at the same place in every string.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use the_algorithms_rust::string::mlcs_ignoring;
+///
+/// let ignore = HashSet::from([',', ' ']);
+/// assert_eq!(mlcs_ignoring(&["a, b", "a b,"], &ignore), "ab");
+/// ```
+pub fn mlcs_ignoring(chains: &[&str], ignore: &HashSet<char>) -> String {
+    let chains: Vec<Vec<char>> = chains
+        .iter()
+        .map(|s| s.chars().filter(|c| !ignore.contains(c)).collect())
+        .collect();
+    solve_chains(chains, &MlcsConfig::default())
+}
+
+/// Same as `multiple_longest_common_subsequence`, also reporting
+/// the statistics of the search, e.g. to tune the band width
+pub fn mlcs_with_stats(chains: &[&str]) -> (String, SearchStats) {
+    match prepare_chains(to_chains(chains)) {
+        Prepared::Solved(mlcs) => (mlcs.iter().collect(), SearchStats::solved(&mlcs)),
+        Prepared::Search(mut ctx) => ctx.solve_with_stats(&MlcsConfig::default()),
+    }
+}
+
+/// Same as `multiple_longest_common_subsequence`, giving each char of the subsequence
+/// with its position in every string
+///
+/// # Returns
+///
+/// The columns of the alignment, in the order of the subsequence: a matched char, and the
+/// position (in chars) where it is matched in each string, in the order of `chains`.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_columns;
+///
+/// let columns = mlcs_columns(&["ABC", "AC", "BAC"]);
+/// assert_eq!(columns, [('A', vec![0, 0, 1]), ('C', vec![2, 1, 2])]);
+/// ```
+pub fn mlcs_columns(chains: &[&str]) -> Vec<(char, Vec<usize>)> {
+    let chains = to_chains(chains);
+    let mut ctx = match prepare_chains(chains.clone()) {
+        Prepared::Solved(mlcs) => return greedy_columns(&mlcs, &chains),
+        Prepared::Search(ctx) => ctx,
+    };
+    let goal = ctx.search(
+        &MlcsConfig::default(),
+        &mut SearchStats::default(),
+        None,
+        |_| false,
+    );
+    let columns = goal.map_or_else(Vec::new, |p| ctx.columns(&p));
+    // each string is matched where its copy among the distinct strings is
+    let copies: HashMap<&[char], usize> = ctx
+        .chains
+        .iter()
+        .enumerate()
+        .map(|(k, chain)| (&**chain, k))
+        .collect();
+    let copies: Vec<usize> = chains
+        .iter()
+        .map(|chain| copies[chain.as_slice()])
+        .collect();
+    columns
+        .into_iter()
+        .map(|(ch, positions)| (ch, copies.iter().map(|&k| positions[k]).collect()))
+        .collect()
+}
+
+/// The columns of `mlcs`, a common subsequence of `chains` found without a search, each
+/// char being matched at its first position after the previous column in every string
+fn greedy_columns<T: Eq + Clone>(mlcs: &[T], chains: &[Vec<T>]) -> Vec<(T, Vec<usize>)> {
+    let mut next = vec![0; chains.len()];
+    mlcs.iter()
+        .map(|ch| {
+            let positions = chains
+                .iter()
+                .zip(&mut next)
+                .map(|(chain, next)| {
+                    let pos = *next
+                        + chain[*next..]
+                            .iter()
+                            .position(|x| x == ch)
+                            .expect("the MLCS is a subsequence of every string");
+                    *next = pos + 1;
+                    pos
+                })
+                .collect();
+            (ch.clone(), positions)
+        })
+        .collect()
+}
+
+/// The positions of each string left out of the common subsequence of `mlcs_columns`,
+/// e.g. the deletions of a diff
+///
+/// # Returns
+///
+/// For each string, in the order of `chains`, the positions (in chars) matched by no
+/// column, in increasing order.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_complement;
+///
+/// // "AC" is matched at [0, 2], [0, 1] and [1, 2]
+/// assert_eq!(
+///     mlcs_complement(&["ABC", "AC", "BAC"]),
+///     [vec![1], vec![], vec![0]]
+/// );
+/// ```
+pub fn mlcs_complement(chains: &[&str]) -> Vec<Vec<usize>> {
+    let columns = mlcs_columns(chains);
+    chains
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            // the columns match increasing positions in every string
+            let mut matched = columns.iter().map(|(_, positions)| positions[i]).peekable();
+            (0..s.chars().count())
+                .filter(|&pos| matched.next_if_eq(&pos).is_none())
+                .collect()
+        })
+        .collect()
+}
+
+/// Same as `multiple_longest_common_subsequence`, also giving the number of chars to delete
+/// from the strings to leave only the subsequence, a distance between the strings
+///
+/// The length of the subsequence is read from the depth of the final point, so the
+/// distance costs no more than the subsequence.
+///
+/// # Returns
+///
+/// The subsequence, and the sum over the strings of their number of chars minus the number
+/// of chars of the subsequence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_distance;
+///
+/// assert_eq!(mlcs_distance(&["abc", "abc"]), ("abc".to_string(), 0));
+/// assert_eq!(mlcs_distance(&["abc", "axc"]), ("ac".to_string(), 2));
+/// ```
+pub fn mlcs_distance(chains: &[&str]) -> (String, usize) {
+    let (subsequence, len) = match prepare_chains(to_chains(chains)) {
+        Prepared::Solved(mlcs) => (mlcs.iter().collect(), mlcs.len()),
+        Prepared::Search(mut ctx) => {
+            let goal = ctx.search(
+                &MlcsConfig::default(),
+                &mut SearchStats::default(),
+                None,
+                |_| false,
+            );
+            goal.map_or_else(
+                || (String::new(), 0),
+                |p| (ctx.common_seq(&p), ctx.depth(&p) as usize),
+            )
+        }
+    };
+    let deletions = chains.iter().map(|s| s.chars().count() - len).sum();
+    (subsequence, deletions)
+}
+
+/// Same as `multiple_longest_common_subsequence`, giving up as soon as `cancel` is set,
+/// e.g. by another thread
+///
+/// `cancel` is read before each round of the search, so the search stops within a round
+/// of it being set. The preprocessing of the strings is not interrupted.
+///
+/// # Returns
+///
+/// None if the search was cancelled, the subsequence otherwise.
+pub fn mlcs_cancellable(chains: &[&str], cancel: &AtomicBool) -> Option<String> {
+    let mut ctx = match prepare_chains(to_chains(chains)) {
+        Prepared::Solved(mlcs) => return Some(mlcs.into_iter().collect()),
+        Prepared::Search(ctx) => ctx,
+    };
+    let mut stats = SearchStats::default();
+    let goal = ctx.search(&MlcsConfig::default(), &mut stats, Some(cancel), |_| false);
+    // the default config has no other limit
+    if stats.interrupted {
+        return None;
+    }
+    Some(goal.map_or_else(String::new, |p| ctx.common_seq(&p)))
+}
+
+/// Checks whether the strings have a common subsequence of at least `k` chars
+///
+/// The search stops as soon as a point matching `k` chars is reached, without rebuilding
+/// the subsequence. It returns `false` when the search ends without reaching such a point.
+pub fn mlcs_at_least(chains: &[&str], k: usize) -> bool {
+    if k == 0 {
+        return true;
+    }
+    if chains.is_empty() {
+        return false;
+    }
+    let mut ctx = match prepare_chains(to_chains(chains)) {
+        Prepared::Solved(mlcs) => return mlcs.len() >= k,
+        Prepared::Search(ctx) => ctx,
+    };
+    let k = k as u64;
+    let goal = ctx.search(
+        &MlcsConfig::default(),
+        &mut SearchStats::default(),
+        None,
+        |g| g >= k,
+    );
+    // the search stopped either on a point with g >= k, or on the final point
+    goal.is_some_and(|p| ctx.g_of(&p) >= k || ctx.depth(&p) >= k)
+}
+
+/// Same as `multiple_longest_common_subsequence`, the search being shared among `threads` threads
+#[cfg(feature = "hda")]
+pub fn mlcs_parallel(chains: &[&str], threads: usize) -> String {
+    match prepare_chains(to_chains(chains)) {
+        Prepared::Solved(mlcs) => mlcs.into_iter().collect(),
+        Prepared::Search(mut ctx) => ctx.solve_parallel(&MlcsConfig::default(), threads),
+    }
+}
+
+/// Empties the queue into the band of the nodes whose f is at least `threshold`
+///
+/// The band is sorted best first, by decreasing f, then h: the search stops on the first
+/// node of the band reaching the end of the strings, which must not be preferred to nodes
+/// promising more chars. The nodes having the same f and h are sorted by increasing
+/// positions, read in the strings given by `order`, so that the order of the band, and the
+/// result of the search, only depend on the nodes and not on their order in the queue.
+/// With the strings sorted by `order`, it does not depend on the order of the strings
+/// either: the search generates the successors of each node by increasing letter, and
+/// keeps the first of the points that are equally good.
+fn collect_layer(
+    queue: &mut BinaryHeap<QueueNode>,
+    threshold: u64,
+    order: &[usize],
+) -> Vec<QueueNode> {
+    let mut nodes = Vec::new();
+    while let Some(node) = queue.pop() {
+        nodes.push(node);
+    }
+    let positions =
+        |node: &QueueNode| -> Vec<Option<usize>> { order.iter().map(|&i| node.point[i]).collect() };
+    nodes.sort_by_cached_key(|node| (std::cmp::Reverse((node.f, node.h)), positions(node)));
+    nodes
+        .into_iter()
+        .filter(|node| node.f >= threshold)
+        .collect()
+}
+
+/// Computes the suffix table: matrix[i][j] is the length of the LCS of s1[i..] and s2[j..]
+///
+/// With a `substitution` matrix, any two chars may be aligned, for their score in the
+/// matrix, and matrix[i][j] is the best total score of the chars aligned in order between
+/// s1[i..] and s2[j..], the pairs of negative score being left out.
+pub(super) fn score_matrix(
+    s1: &[char],
+    s2: &[char],
+    substitution: Option<&SubstitutionMatrix>,
+) -> Vec<Vec<u64>> {
+    let Some(substitution) = substitution else {
+        return score_matrix_by(s1, s2, |_, _| 1);
+    };
+    let mut matrix: Vec<Vec<u64>> = vec![vec![0; s2.len() + 1]; s1.len() + 1];
+    for i in (0..s1.len()).rev() {
+        for j in (0..s2.len()).rev() {
+            let gain = substitution.score(s1[i], s2[j]).max(0).unsigned_abs();
+            matrix[i][j] = max(
+                add_costs(matrix[i + 1][j + 1], gain),
+                max(matrix[i][j + 1], matrix[i + 1][j]),
+            );
+        }
+    }
+    matrix
+}
+
+/// Computes the suffix table, a match between s1[i] and s2[j] being worth gain(i, j)
+fn score_matrix_by<T: Eq>(s1: &[T], s2: &[T], gain: impl Fn(usize, usize) -> u64) -> Vec<Vec<u64>> {
+    let m = s1.len();
+    let n = s2.len();
+    let mut matrix: Vec<Vec<u64>> = vec![vec![0; n + 1]; m + 1];
+
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            matrix[i][j] = if s1[i] == s2[j] {
+                matrix[i + 1][j + 1] + gain(i, j)
+            } else {
+                max(matrix[i][j + 1], matrix[i + 1][j])
+            };
+        }
+    }
+
+    matrix
+}
+
+/// The lowest f of the points expanded in the next band, `width` below the best f of the
+/// queue, or 0 when the band is wider than that f
+fn band_threshold(queue: &BinaryHeap<QueueNode>, width: u64) -> u64 {
+    queue.peek().map_or(0, |node| node.f).saturating_sub(width)
+}
+
+/// Adds two costs of the search, g and a gain, or h and g
+/// The costs are bounded by the total weight of a string, so the sum only overflows if a
+/// caller let through weights adding up past `u64::MAX` (see `weighted_multiple_lcs`):
+/// debug builds stop there, release builds saturate instead of wrapping around, which would
+/// make the worst points look the best
+fn add_costs(a: u64, b: u64) -> u64 {
+    let sum = a.checked_add(b);
+    debug_assert!(sum.is_some(), "the costs {a} + {b} overflow");
+    sum.unwrap_or(u64::MAX)
+}
+
+/// The index of the suffix table of the strings i < j among the tables of the
+/// d * (d - 1) / 2 pairs, sorted by i, then j
+fn pair_index(i: usize, j: usize, d: usize) -> usize {
+    debug_assert!(i < j && j < d, "({i}, {j}) is not a pair of {d} strings");
+    i * (2 * d - i - 1) / 2 + (j - i - 1)
+}
+
+/// The number of pairs i < j among d strings
+fn pair_count(d: usize) -> usize {
+    d * d.saturating_sub(1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the expected result of an input: either one exact subsequence, which is also checked
+    // to be valid and as long as itself first, or only a minimal length, any valid
+    // subsequence of that length or more passing
+    enum Expected {
+        Exact(&'static str),
+        AtLeast(usize),
+    }
+
+    impl From<&'static str> for Expected {
+        fn from(expected: &'static str) -> Self {
+            Expected::Exact(expected)
+        }
+    }
+
+    impl From<usize> for Expected {
+        fn from(min_len: usize) -> Self {
+            Expected::AtLeast(min_len)
+        }
+    }
+
+    macro_rules! astar_tests {
+        ($($name:ident: ($input:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let input: Vec<&str> = $input.iter().map(|s| *s).collect();
+                    let result = multiple_longest_common_subsequence(&input);
+                    match Expected::from($expected) {
+                        Expected::Exact(expected) => {
+                            let len = expected.chars().count();
+                            fixtures::assert_valid_mlcs(&result, &input, len);
+                            assert_eq!(result, expected);
+                        }
+                        Expected::AtLeast(min_len) => {
+                            fixtures::assert_valid_mlcs(&result, &input, min_len);
+                        }
+                    }
+                }
+             )*
+        };
+    }
+
+    astar_tests! {
+             all_empty_strings: (["", ""], ""),
+             three_empty_strings: (["", "", ""], ""),
+             all_same: (["abcdef", "abcdef", "abcdef", "abcdef"], "abcdef"),
+             empty_strings: (["", "ABC"], ""),
+             long: (fixtures::LONG.input, fixtures::LONG.expected),
+             medium_case: (
+                    [
+                        "gxt#xayb",
+                        "abgt#ab",
+                        "gyayt#ahjb",
+                        "gyayjjjt#ab",
+                        "gyayt#ahhhhb",
+                        "ygaytp#pppahjb",
+                        "ylllgaytm#8765majb",
+                    ],
+                    "gt#ab"
+             ),
+             medium_plus: (fixtures::MEDIUM_PLUS.input, fixtures::MEDIUM_PLUS.expected),
+             medium_plus_length: (fixtures::MEDIUM_PLUS.input, fixtures::MEDIUM_PLUS.expected_len),
+             mix: (fixtures::MIX.input, fixtures::MIX.expected),
+             mix_length: (fixtures::MIX.input, fixtures::MIX.expected_len),
+             no_match: (["ABC", "DEF"], ""),
+             simple_case: (["ABC", "AC", "BAC"], "AC"),
+             two_strings_last_char: (["ab", "b"], "b"),
+             two_strings_of_bytes: (["\u{0}\u{ff}\u{0}", "\u{ff}\u{0}"], "\u{ff}\u{0}"),
+             two_strings_single_char: (["a", "a"], "a"),
+             unicode: (
+                    [
+                        "串用于测试展示测中测中测测🚀测测串文",
+                        "串串用于测试测中中展示测测中🚀文串",
+                        "串用于测试展中中中中中示中测🚀测测文",
+                        "串用于测串试展示中测测文",
+                        "串用于测中中中试串展中示🚀测测测中文",
+                        "串用于测试中中中展串🚀中示中文",
+                        "串中中中用于测🚀试展示测测测中中串文",
+                        "串用串中🚀中于测试中展中示中文串",
+                        "串🚀用于测中中试中展示中文测测测测测串",
+                    ],
+                    "串用于测试展示中文"
+             ),
+
+    }
+
+    #[test]
+    fn fixtures_lengths() {
+        for fixture in &fixtures::FIXTURES {
+            assert_eq!(
+                fixture.expected.chars().count(),
+                fixture.expected_len,
+                "{}",
+                fixture.name
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a subsequence")]
+    fn invalid_subsequences_are_caught() {
+        fixtures::assert_valid_mlcs("ba", &["ab", "ba"], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "has at least 3")]
+    fn shorter_subsequences_are_caught() {
+        fixtures::assert_valid_mlcs("ab", &["abc", "abc"], 3);
+    }
+
+    #[test]
+    fn fixtures_give_expected_outputs() {
+        for fixture in &fixtures::FIXTURES {
+            fixtures::assert_fixture(fixture, |input| {
+                multiple_longest_common_subsequence(&input.to_vec())
+            });
+        }
+    }
+
+    #[test]
+    fn synthetic_inputs() {
+        let input = fixtures::synthetic(4, 30, 4, 7);
+        assert_eq!(input, fixtures::synthetic(4, 30, 4, 7));
+        assert!(input.iter().all(|s| s.len() == 30));
+
+        let input: Vec<&str> = input.iter().map(String::as_str).collect();
+        let result = multiple_longest_common_subsequence(&input);
+        for s in input {
+            let mut chars = s.chars();
+            assert!(result.chars().all(|c| chars.any(|x| x == c)));
+        }
+    }
+
+    #[test]
+    fn owned_strings() {
+        let input = ["gxt#xayb", "abgt#ab", "gyayt#ahjb"];
+        assert_eq!(
+            mlcs_owned(input.iter().map(|s| s.to_string()).collect()),
+            multiple_longest_common_subsequence(&input.to_vec())
+        );
+    }
+
+    #[test]
+    fn search_stats() {
+        let input = ["gxt#xayb", "abgt#ab", "gyayt#ahjb", "gyayjjjt#ab"];
+        let (result, stats) = mlcs_with_stats(&input);
+
+        assert_eq!(result, multiple_longest_common_subsequence(&input.to_vec()));
+        assert_eq!(stats.goal_g, result.chars().count() as u64);
+        assert!(stats.nodes_expanded as u64 >= stats.goal_g);
+        assert!(stats.bands > 0);
+        assert!(stats.max_queue_len > 0);
+
+        // points reached again through a longer path make g stale for their descendants
+        let (result, stats) = mlcs_with_stats(fixtures::LONG.input);
+        assert_eq!(stats.goal_g, result.chars().count() as u64);
+    }
+
+    #[test]
+    fn identical_strings_stop_at_the_upper_bound() {
+        let input = ["abcdefghij"; 5];
+        let (result, stats) = Context::new(&input).solve_with_stats(&MlcsConfig::default());
+        assert_eq!(result, "abcdefghij");
+        // every starting point is in the first band and is reached again, deeper, from
+        // the previous one, until the last one reaches the bound
+        assert_eq!(stats.bands, 1);
+        assert_eq!(stats.goal_g, 10);
+    }
+
+    #[test]
+    fn string_equal_to_the_pairwise_lcs_stops_at_the_upper_bound() {
+        // "bcba" is a longest common subsequence of the two other strings
+        let input = ["abcbdab", "bdcaba", "bcba"];
+        let (result, stats) = Context::new(&input).solve_with_stats(&MlcsConfig::default());
+        assert_eq!(result, "bcba");
+        assert_eq!(stats.bands, 2);
+        assert!(stats.nodes_expanded <= 20, "{stats:?}");
+    }
+
+    #[test]
+    fn every_entry_point_takes_the_shortcut() {
+        // the suffix tables of 5 strings of 3000 chars would take more than a gigabyte
+        let long = "a".repeat(3000);
+        let input = [long.as_str(); 5];
+        let (result, stats) = mlcs_with_stats(&input);
+        assert_eq!(result, long);
+        assert_eq!(
+            stats,
+            SearchStats {
+                goal_g: 3000,
+                ..SearchStats::default()
+            }
+        );
+        assert_eq!(mlcs_distance(&input), (long.clone(), 0));
+        assert_eq!(
+            mlcs_cancellable(&input, &AtomicBool::new(false)),
+            Some(long.clone())
+        );
+        assert!(mlcs_at_least(&input, 3000));
+        assert!(!mlcs_at_least(&input, 3001));
+        let outcome = MlcsBuilder::new(&input).case_insensitive(true).run();
+        assert_eq!(outcome.subsequence, long);
+        assert!(outcome.is_complete());
+    }
+
+    #[test]
+    fn search_stats_without_match() {
+        let (result, stats) = mlcs_with_stats(&["abc", "def"]);
+        assert_eq!(result, "");
+        assert_eq!(stats.goal_g, 0);
+        assert_eq!(stats.bands, 0);
+    }
+
+    #[test]
+    fn at_least_around_the_true_length() {
+        let inputs: [&[&str]; 4] = [
+            &["gxt#xayb", "abgt#ab", "gyayt#ahjb", "gyayjjjt#ab"],
+            &["ABC", "AC", "BAC"],
+            &["串用于测试展示", "串用测试于展示", "用于串测试展示"],
+            fixtures::LONG.input,
+        ];
+        for input in inputs {
+            let len = multiple_longest_common_subsequence(&input.to_vec())
+                .chars()
+                .count();
+            assert!(mlcs_at_least(input, 0), "{input:?}");
+            assert!(mlcs_at_least(input, len - 1), "{input:?}");
+            assert!(mlcs_at_least(input, len), "{input:?}");
+            assert!(!mlcs_at_least(input, len + 1), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn at_least_without_match() {
+        assert!(mlcs_at_least(&["abc", "def"], 0));
+        assert!(!mlcs_at_least(&["abc", "def"], 1));
+        assert!(!mlcs_at_least(&["", "abc"], 1));
+        assert!(!mlcs_at_least(&[], 1));
+    }
+
+    #[test]
+    fn low_memory_reconstruction_gives_the_same_output() {
+        let low_memory = MlcsConfig {
+            low_memory_reconstruction: true,
+            ..MlcsConfig::default()
+        };
+        let synthetic = fixtures::synthetic(5, 40, 4, 3);
+        let synthetic: Vec<&str> = synthetic.iter().map(String::as_str).collect();
+        let inputs = [
+            fixtures::LONG.input,
+            fixtures::MEDIUM_PLUS.input,
+            fixtures::MIX.input,
+            &synthetic,
+            &["abc", "def"],
+        ];
+        for input in inputs {
+            let (expected, expected_stats) =
+                Context::new(input).solve_with_stats(&MlcsConfig::default());
+            let mut ctx = Context::new(input);
+            let (result, stats) = ctx.solve_with_stats(&low_memory);
+            assert_eq!(result, expected);
+            assert_eq!(stats, expected_stats);
+            // only the root is left in the maps
+            assert_eq!(ctx.g.len(), 1);
+            assert_eq!(ctx.parents.len(), 1);
+        }
+    }
+
+    #[test]
+    fn successor_for_letter() {
+        let ctx = Context::new(&["abcab", "bacba", "cabab"]);
+        let letter = |ch| ctx.alphabet.iter().position(|&x| x == ch).unwrap();
+
+        // from the first 'a' of each string
+        let p = vec![Some(0), Some(1), Some(1)];
+        assert_eq!(
+            ctx.next_successor_for_letter(&p, letter('b')),
+            Some(vec![Some(1), Some(3), Some(2)])
+        );
+        assert_eq!(
+            ctx.next_successor_for_letter(&p, letter('a')),
+            Some(vec![Some(3), Some(4), Some(3)])
+        );
+        // there is no 'c' after position 1 of the third string
+        assert_eq!(ctx.next_successor_for_letter(&p, letter('c')), None);
+    }
+
+    #[test]
+    fn successor_for_letter_needs_every_coordinate() {
+        let ctx = Context::new(&["abab", "abab"]);
+        let a = ctx.alphabet.iter().position(|&x| x == 'a').unwrap();
+        let b = ctx.alphabet.iter().position(|&x| x == 'b').unwrap();
+
+        assert_eq!(
+            ctx.next_successor_for_letter(&[Some(0), Some(0)], b),
+            Some(vec![Some(1), Some(1)])
+        );
+        assert_eq!(ctx.next_successor_for_letter(&[Some(0), None], b), None);
+        assert_eq!(ctx.next_successor_for_letter(&[None, None], b), None);
+        // no 'a' after the last 'a' of the strings
+        assert_eq!(ctx.next_successor_for_letter(&[Some(2), Some(2)], a), None);
+    }
+
+    #[test]
+    fn no_successors_with_a_none_coordinate() {
+        let ctx = Context::new(&["abcab", "bacba", "cabab"]);
+        assert!(ctx.get_successors(&[Some(0), None, Some(1)]).is_empty());
+        assert!(ctx.get_successors(&[None, None, None]).is_empty());
+    }
+
+    macro_rules! missing_letters_tests {
+        ($($name:ident: ($input:expr, $expected_len:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let input: Vec<&str> = $input.to_vec();
+                    let result = multiple_longest_common_subsequence(&input);
+                    fixtures::assert_valid_mlcs(&result, &input, $expected_len);
+                    assert_eq!(result.chars().count(), $expected_len);
+                }
+            )*
+        };
+    }
+
+    // the letters of the shortest string missing from another string are dropped from
+    // the alphabet, so that the starting points have no None coordinate
+    missing_letters_tests! {
+        missing_first_letter: (["xab", "ab", "yab"], 2),
+        missing_middle_letter: (["axb", "ab", "ayb"], 2),
+        missing_last_letter: (["abx", "abz", "aby"], 2),
+        missing_in_one_string_only: (["abcabc", "acac", "abcc"], 3),
+        every_letter_missing_somewhere: (["ab", "bc", "ca"], 0),
+        interleaved: (["a1b2c3", "3a2b1c", "abc", "xaybzc"], 3),
+    }
+
+    #[test]
+    fn no_successor_after_the_last_char() {
+        let ctx = Context::new(&["ab", "b"]);
+        let b = ctx.alphabet.iter().position(|&x| x == 'b').unwrap();
+
+        // the last char of one string
+        assert_eq!(ctx.next_successor_for_letter(&[Some(0), Some(0)], b), None);
+        // the last char of every string
+        assert_eq!(ctx.next_successor_for_letter(&[Some(1), Some(0)], b), None);
+        assert!(ctx.get_successors(&[Some(1), Some(0)]).is_empty());
+    }
+
+    #[test]
+    fn short_strings_sweep() {
+        // pseudo-random inputs of 2 to 4 strings of at most 6 chars over 3 letters
+        for seed in 1..=500 {
+            let d = 2 + seed % 3;
+            let mut strings = fixtures::synthetic(d, 6, 3, seed as u64);
+            // the lengths shifting with the seed
+            for (j, s) in strings.iter_mut().enumerate() {
+                s.truncate((seed / 3 + 4 * j) % 7);
+            }
+            let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let result = multiple_longest_common_subsequence(&input);
+            fixtures::assert_valid_mlcs(&result, &input, 0);
+            if d == 2 {
+                assert_eq!(result.len(), mlcs_len_pair(input[0], input[1]), "{input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn successors_are_the_reachable_letters() {
+        let ctx = Context::new(&["abcab", "bacba", "cabab"]);
+        let successors = ctx.get_successors(&[Some(0), Some(1), Some(1)]);
+        assert_eq!(
+            successors,
+            vec![
+                vec![Some(3), Some(4), Some(3)],
+                vec![Some(1), Some(3), Some(2)]
+            ]
+        );
+    }
+
+    #[test]
+    fn successors_match_the_letters_one_by_one() {
+        // every point of small strings, including those where the buffer is left half
+        // filled by a letter missing further on
+        let ctx = Context::new(&["abcabba", "cbabac", "bacbcab"]);
+        let lens: Vec<usize> = ctx.chains.iter().map(|chain| chain.len()).collect();
+        for i in 0..lens[0] {
+            for j in 0..lens[1] {
+                for k in 0..lens[2] {
+                    let p = [Some(i), Some(j), Some(k)];
+                    // the next position of each letter after p, read off the strings
+                    let expected: Vec<Vec<Option<usize>>> = ctx
+                        .alphabet
+                        .iter()
+                        .filter_map(|&c| {
+                            ctx.chains
+                                .iter()
+                                .zip(p)
+                                .map(|(chain, idx)| {
+                                    let idx = idx?;
+                                    let next = chain[idx + 1..].iter().position(|&x| x == c)?;
+                                    Some(Some(idx + 1 + next))
+                                })
+                                .collect()
+                        })
+                        .collect();
+                    assert_eq!(ctx.get_successors(&p), expected, "{p:?}");
+                }
+            }
+        }
+    }
+
+    // tables built without dropping the letters missing from some string, as
+    // `Context::from_tables` does not check them
+    fn context_with_unpruned_alphabet(input: &[&str]) -> Context {
+        let chains: Vec<Vec<char>> = input.iter().map(|s| s.chars().collect()).collect();
+        let mut alphabet = chains.iter().min_by_key(|s| s.len()).unwrap().clone();
+        alphabet.sort();
+        alphabet.dedup();
+        let mt = mt_table(&chains, &alphabet);
+        let ms = matrices_score(&chains);
+        Context::from_tables(chains, alphabet, ms, mt)
+    }
+
+    #[cfg(feature = "batch")]
+    #[test]
+    fn context_from_parts() {
+        // the tables of a pool of strings, shared by the searches over its pairs
+        let pool = to_chains(&["ABCBDAB", "BDCABA", "串BA用C", "ABC"]);
+        let d = pool.len();
+        let ms = matrices_score(&pool);
+        for i in 0..d {
+            for j in (i + 1)..d {
+                let chains = vec![pool[i].clone(), pool[j].clone()];
+                let alphabet = get_alphabet(&chains);
+                let mt = mt_table(&chains, &alphabet);
+                let pair_ms = vec![ms[pair_index(i, j, d)].clone()];
+                let strings: Vec<String> = chains.iter().map(|s| s.iter().collect()).collect();
+                let strings: Vec<&str> = strings.iter().map(String::as_str).collect();
+                let expected = Context::new(&strings).solve_with_stats(&MlcsConfig::default());
+                let mut ctx = Context::from_parts(chains, alphabet, mt, pair_ms);
+                assert_eq!(ctx.solve_with_stats(&MlcsConfig::default()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn common_alphabet() {
+        let chains = to_chains(&["cabx", "xbcay", "abcxz"]);
+        assert_eq!(get_alphabet(&chains), ['a', 'b', 'c', 'x']);
+        assert_eq!(get_alphabet(&to_chains(&["ab", "cd"])), []);
+        assert_eq!(get_alphabet(&to_chains(&["baab"])), ['a', 'b']);
+        assert_eq!(get_alphabet(&to_chains(&["", "abc"])), []);
+        assert_eq!(get_alphabet::<char>(&[]), []);
+    }
+
+    #[test]
+    fn lookup_tables_match_a_naive_scan() {
+        for input in [
+            ["ABCBDAB", "BDCABA", "BCDAAB"].as_slice(),
+            &["串用于测试", "用于串测试", "测试串用于"],
+            &["aaa", "a", "ba"],
+            &["", "abc"],
+            &["xyz"],
+        ] {
+            let chains = to_chains(input);
+            let alphabet = get_alphabet(&chains);
+            let mt = mt_table(&chains, &alphabet);
+            assert_eq!(mt.len(), alphabet.len());
+            for (tables, &ch) in mt.iter().zip(&alphabet) {
+                assert_eq!(tables.len(), chains.len());
+                for (table, s) in tables.iter().zip(&chains) {
+                    assert_eq!(table.len(), s.len());
+                    for (i, &next) in table.iter().enumerate() {
+                        let naive = (i..s.len()).find(|&j| s[j] == ch);
+                        assert_eq!(next, naive, "{ch:?} after {i} in {s:?}");
+                    }
+                }
+            }
+            let ctx = Context::new(input);
+            assert_eq!(ctx.alphabet, alphabet);
+            assert_eq!(ctx.mt, mt);
+        }
+    }
+
+    #[test]
+    fn starting_points_skip_missing_letters() {
+        // 'x' does not occur in the second string
+        let ctx = context_with_unpruned_alphabet(&["axb", "bab"]);
+        assert_eq!(ctx.alphabet, ['a', 'b', 'x']);
+        assert_eq!(
+            ctx.get_starting_p(),
+            vec![vec![Some(0), Some(1)], vec![Some(2), Some(0)]]
+        );
+    }
+
+    #[test]
+    fn missing_letters_are_not_matched() {
+        for input in [["x", "a"], ["xab", "ab"], ["axb", "bab"], ["abx", "ba"]] {
+            let expected = multiple_longest_common_subsequence(&input.to_vec());
+            let result = context_with_unpruned_alphabet(&input).solve(&MlcsConfig::default());
+            assert!(!result.contains('x'), "{input:?} gave {result:?}");
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn matched_char() {
+        let ctx = Context::new(&["abc", "cab"]);
+        assert_eq!(ctx.matched_char(&[Some(0), Some(1)]), Some('a'));
+        assert_eq!(ctx.matched_char(&[Some(2), Some(0)]), Some('c'));
+        assert_eq!(ctx.matched_char(&[None, None]), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "does not match the same char in every string")]
+    fn common_seq_checks_every_coordinate() {
+        let mut ctx = Context::new(&["abc", "cab"]);
+        // 'a' in the first string, 'c' in the second one
+        let p = vec![Some(0), Some(0)];
+        ctx.record_successor(vec![None, None], p.clone(), 0);
+        ctx.common_seq(&p);
+    }
+
+    #[test]
+    fn layer_order_is_total() {
+        // many nodes sharing f and h, pushed in several orders
+        let mut nodes: Vec<QueueNode> = vec![];
+        for (i, j) in (0..6).flat_map(|i| (0..6).map(move |j| (i, j))) {
+            nodes.push(QueueNode {
+                point: vec![Some(i), Some(j)],
+                f: 7 + (i % 2) as u64,
+                h: 3,
+            });
+        }
+        let mut reference = nodes.clone();
+        reference.sort_by_key(|node| (std::cmp::Reverse((node.f, node.h)), node.point.clone()));
+
+        for seed in 0..5 {
+            let mut shuffled = nodes.clone();
+            shuffled.rotate_left(seed * 7);
+            if seed % 2 == 1 {
+                shuffled.reverse();
+            }
+            let mut queue: BinaryHeap<QueueNode> = shuffled.into_iter().collect();
+            let layer = collect_layer(&mut queue, 0, &[0, 1]);
+            let points: Vec<_> = layer.iter().map(|node| &node.point).collect();
+            let expected: Vec<_> = reference.iter().map(|node| &node.point).collect();
+            assert_eq!(points, expected);
+        }
+    }
+
+    #[test]
+    fn ties_follow_the_order_of_the_strings() {
+        let node = |i: usize, j: usize| QueueNode {
+            point: vec![Some(i), Some(j)],
+            f: 5,
+            h: 2,
+        };
+        let nodes = [node(0, 2), node(1, 1), node(2, 0)];
+        for (order, expected) in [([0, 1], [0, 1, 2]), ([1, 0], [2, 1, 0])] {
+            let mut queue: BinaryHeap<QueueNode> = nodes.iter().cloned().collect();
+            let layer = collect_layer(&mut queue, 0, &order);
+            let firsts: Vec<Option<usize>> = layer.iter().map(|node| node.point[0]).collect();
+            assert_eq!(firsts, expected.map(Some));
+        }
+    }
+
+    #[test]
+    fn same_output_for_every_order_of_the_strings() {
+        // many subsequences of 2 chars: "AB", "BA", "AA" and "BB"
+        let strings = ["ABAB", "BABA", "AABB", "BBAA"];
+        let expected = multiple_longest_common_subsequence(&strings.to_vec());
+        for _ in 0..100 {
+            assert_eq!(
+                multiple_longest_common_subsequence(&strings.to_vec()),
+                expected
+            );
+        }
+        let mut permutation = strings;
+        // Heap's algorithm, over the 24 orders
+        let mut counters = [0; 4];
+        let mut i = 1;
+        while i < 4 {
+            if counters[i] < i {
+                permutation.swap(if i % 2 == 0 { 0 } else { counters[i] }, i);
+                let result = multiple_longest_common_subsequence(&permutation.to_vec());
+                assert_eq!(result, expected, "{permutation:?}");
+                counters[i] += 1;
+                i = 1;
+            } else {
+                counters[i] = 0;
+                i += 1;
+            }
+        }
+
+        for input in [
+            ["ABAB", "BABA"].as_slice(),
+            &["abcab", "bacba", "cabab"],
+            &["串用于测试", "测试串用于", "于测试串用"],
+            fixtures::LONG.input,
+        ] {
+            let expected = multiple_longest_common_subsequence(&input.to_vec());
+            let mut reversed = input.to_vec();
+            reversed.reverse();
+            assert_eq!(multiple_longest_common_subsequence(&reversed), expected);
+            let mut rotated = input.to_vec();
+            rotated.rotate_left(1);
+            assert_eq!(multiple_longest_common_subsequence(&rotated), expected);
+        }
+    }
+
+    #[test]
+    fn the_threshold_is_below_the_best_node() {
+        let node = |f: u64, h: u64, i: usize| QueueNode {
+            point: vec![Some(i), Some(i)],
+            f,
+            h,
+        };
+        let mut queue: BinaryHeap<QueueNode> = [
+            node(3, 1, 0),
+            node(9, 2, 1),
+            node(5, 5, 2),
+            node(9, 4, 3),
+            node(7, 0, 4),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(queue.peek().map(|node| (node.f, node.h)), Some((9, 4)));
+
+        let layer = collect_layer(&mut queue, 5, &[0, 1]);
+        assert!(queue.is_empty());
+        let costs: Vec<(u64, u64)> = layer.iter().map(|node| (node.f, node.h)).collect();
+        assert_eq!(costs, [(9, 4), (9, 2), (7, 0), (5, 5)]);
+    }
+
+    // the f of the nodes kept in the band of width `width`, out of nodes of f 0 to max_f
+    fn band_of(max_f: u64, width: u64) -> Vec<u64> {
+        let mut queue: BinaryHeap<QueueNode> = (0..=max_f)
+            .map(|f| QueueNode {
+                point: vec![Some(f as usize)],
+                f,
+                h: 0,
+            })
+            .collect();
+        let y = band_threshold(&queue, width);
+        collect_layer(&mut queue, y, &[0])
+            .iter()
+            .map(|node| node.f)
+            .collect()
+    }
+
+    #[test]
+    fn band_as_wide_as_the_best_f() {
+        assert_eq!(band_of(4, 4), [4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn band_wider_than_the_best_f() {
+        assert_eq!(band_of(4, 5), [4, 3, 2, 1, 0]);
+        assert_eq!(band_of(4, u64::MAX), [4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn band_narrower_than_the_best_f() {
+        assert_eq!(band_of(4, 3), [4, 3, 2, 1]);
+        assert_eq!(band_of(100, 2), [100, 99, 98]);
+        assert_eq!(band_of(100, 0), [100]);
+    }
+
+    #[test]
+    fn band_of_an_empty_queue() {
+        assert_eq!(band_threshold(&BinaryHeap::new(), 3), 0);
+    }
+
+    #[test]
+    fn costs_add_up() {
+        assert_eq!(add_costs(2, 3), 5);
+        assert_eq!(add_costs(u64::MAX - 1, 1), u64::MAX);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn overflowing_costs_stop_debug_builds() {
+        add_costs(u64::MAX, 1);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn overflowing_costs_saturate() {
+        assert_eq!(add_costs(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn best_nodes_of_the_band_first() {
+        // with a narrow band, expanding the worst nodes of the band first reached the end
+        // of the strings through them, e.g. "b" instead of "ac"
+        let config = MlcsConfig {
+            width: 1,
+            ..MlcsConfig::default()
+        };
+        for (input, expected) in [
+            (["acb", "bbcccacaca"], "ac"),
+            (["caabaca", "baab"], "baa"),
+            (["acbabccb", "abacccab"], "abaccb"),
+        ] {
+            assert_eq!(Context::new(&input).solve(&config), expected);
+            assert_eq!(expected.len(), mlcs_len_pair(input[0], input[1]));
+        }
+    }
+
+    #[test]
+    fn columns_index_their_char() {
+        let inputs: [&[&str]; 5] = [
+            fixtures::LONG.input,
+            fixtures::MEDIUM_PLUS.input,
+            &["串用于测试展示", "串用测试于展示", "用于串测试展示"],
+            &["abc", "def"],
+            &["", "abc"],
+        ];
+        for input in inputs {
+            let columns = mlcs_columns(input);
+            let expected = multiple_longest_common_subsequence(&input.to_vec());
+            assert_eq!(columns.len(), expected.chars().count());
+            assert_eq!(
+                columns.iter().map(|(ch, _)| ch).collect::<String>(),
+                expected
+            );
+            let chains: Vec<Vec<char>> = input.iter().map(|s| s.chars().collect()).collect();
+            for (ch, positions) in &columns {
+                assert_eq!(positions.len(), input.len());
+                for (chain, &pos) in chains.iter().zip(positions) {
+                    assert_eq!(chain[pos], *ch);
+                }
+            }
+            // the positions increase from one column to the next in every string
+            for pair in columns.windows(2) {
+                assert!(pair[0].1.iter().zip(&pair[1].1).all(|(a, b)| a < b));
+            }
+        }
+        assert!(mlcs_columns(&[]).is_empty());
+    }
+
+    #[test]
+    fn complement_partitions_each_string_with_the_columns() {
+        let inputs: [&[&str]; 5] = [
+            fixtures::MEDIUM_PLUS.input,
+            &["串用于测试展示", "串用测试于展示", "用于串测试展示"],
+            &["abc", "def"],
+            &["", "abc"],
+            &["abc", "abc", "abc"],
+        ];
+        for input in inputs {
+            let columns = mlcs_columns(input);
+            let complement = mlcs_complement(input);
+            assert_eq!(complement.len(), input.len());
+            for (i, s) in input.iter().enumerate() {
+                let mut positions: Vec<usize> = columns.iter().map(|(_, p)| p[i]).collect();
+                assert!(complement[i].windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(complement[i].iter().all(|pos| !positions.contains(pos)));
+                positions.extend(&complement[i]);
+                positions.sort_unstable();
+                assert_eq!(positions, (0..s.chars().count()).collect::<Vec<_>>());
+            }
+        }
+        assert!(mlcs_complement(&[]).is_empty());
+        assert_eq!(mlcs_complement(&["abc", "def"]), vec![vec![0, 1, 2]; 2]);
+    }
+
+    #[test]
+    fn alphabet_over_the_limit() {
+        // 3000 CJK ideographs, shared by both strings in opposite orders
+        let ideographs: String = ('\u{4e00}'..).take(3000).collect();
+        let reversed: String = ideographs.chars().rev().collect();
+        assert_eq!(
+            mlcs_with_max_alphabet(&[&ideographs, &reversed], 1000),
+            Err(MlcsError::AlphabetTooLarge {
+                letters: 3000,
+                limit: 1000
+            })
+        );
+        // only the letters of every string count
+        let latin: String = ('a'..='z').collect();
+        let input = [&ideographs[..30], "串", &latin];
+        assert_eq!(mlcs_with_max_alphabet(&input, 0), Ok(String::new()));
+        let input = ["串用于测试", "用于串测试"];
+        assert_eq!(
+            mlcs_with_max_alphabet(&input, 4),
+            Err(MlcsError::AlphabetTooLarge {
+                letters: 5,
+                limit: 4
+            })
+        );
+        assert_eq!(
+            mlcs_with_max_alphabet(&input, 5),
+            Ok("用于测试".to_string())
+        );
+        assert_eq!(mlcs_with_max_alphabet(&[], 0), Ok(String::new()));
+    }
+
+    #[test]
+    fn distance_counts_the_deletions() {
+        assert_eq!(mlcs_distance(&["abc", "abc"]), ("abc".to_string(), 0));
+        assert_eq!(mlcs_distance(&["abc", "axc"]), ("ac".to_string(), 2));
+        assert_eq!(mlcs_distance(&["abc", "def"]), (String::new(), 6));
+        assert_eq!(mlcs_distance(&["", "abc"]), (String::new(), 3));
+        assert_eq!(mlcs_distance(&[]), (String::new(), 0));
+        for fixture in fixtures::FIXTURES {
+            let (result, distance) = mlcs_distance(fixture.input);
+            assert_eq!(
+                result,
+                multiple_longest_common_subsequence(&fixture.input.to_vec())
+            );
+            let complement = mlcs_complement(fixture.input);
+            assert_eq!(distance, complement.iter().map(Vec::len).sum::<usize>());
+        }
+    }
+
+    #[test]
+    fn cancellable_without_cancellation() {
+        let cancel = AtomicBool::new(false);
+        for fixture in fixtures::FIXTURES {
+            let result = mlcs_cancellable(fixture.input, &cancel);
+            assert_eq!(
+                result.as_deref(),
+                Some(fixture.expected),
+                "{}",
+                fixture.name
+            );
+        }
+        assert_eq!(
+            mlcs_cancellable(&["abc", "def"], &cancel).as_deref(),
+            Some("")
+        );
+        cancel.store(true, AtomicOrdering::Relaxed);
+        assert_eq!(mlcs_cancellable(fixtures::LONG.input, &cancel), None);
+    }
+
+    #[test]
+    fn cancelled_from_another_thread() {
+        // 5 strings of 300 chars over 4 letters, whose search takes minutes
+        let strings = fixtures::synthetic(5, 300, 4, 1);
+        let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+        let cancel = AtomicBool::new(false);
+        std::thread::scope(|scope| {
+            let search = scope.spawn(|| mlcs_cancellable(&input, &cancel));
+            std::thread::sleep(Duration::from_millis(200));
+            let cancelled_at = Instant::now();
+            cancel.store(true, AtomicOrdering::Relaxed);
+            assert_eq!(search.join().unwrap(), None);
+            assert!(cancelled_at.elapsed() < Duration::from_secs(10));
+        });
+    }
+
+    #[test]
+    fn bytes_and_chars() {
+        // 'é' and 'è' are C3 A9 and C3 A8 in UTF-8
+        let (a, b) = ("café crème", "cafè crême");
+        assert_eq!(mlcs_chars(&[a, b]), "caf crme");
+        let bytes = mlcs_bytes(&[a.as_bytes(), b.as_bytes()]);
+        assert_eq!(bytes, b"caf\xc3 cr\xc3me");
+        assert!(String::from_utf8(bytes).is_err());
+
+        let (a, b) = ("串用于", "用于串");
+        assert_eq!(mlcs_chars(&[a, b]).chars().count(), 2);
+        assert_eq!(mlcs_bytes(&[a.as_bytes(), b.as_bytes()]), "用于".as_bytes());
+        assert_eq!(mlcs_bytes(&[&[0, 255, 1], &[255, 1, 0]]), [255, 1]);
+        assert!(mlcs_bytes(&[]).is_empty());
+    }
+
+    #[test]
+    fn collected_input() {
+        let input: MlcsInput = fixtures::LONG.input.iter().collect();
+        assert_eq!(input.solve(), fixtures::LONG.expected);
+        let owned = vec!["串用于测试".to_string(), "用于串测试".to_string()];
+        assert_eq!(owned.into_iter().collect::<MlcsInput>().solve(), "用于测试");
+        assert_eq!(
+            std::iter::empty::<&str>().collect::<MlcsInput>().solve(),
+            ""
+        );
+    }
+
+    // runs f on another thread, failing if it does not return within the limit
+    fn within<T: Send + 'static>(limit: Duration, f: impl FnOnce() -> T + Send + 'static) -> T {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || sender.send(f()));
+        receiver
+            .recv_timeout(limit)
+            .unwrap_or_else(|_| panic!("did not finish within {limit:?}"))
+    }
+
+    #[test]
+    fn long_runs_of_one_letter() {
+        let limit = Duration::from_secs(10);
+        let a = "a".repeat(3000);
+        let result = within(limit, move || {
+            multiple_longest_common_subsequence(&vec![a.as_str(); 5])
+        });
+        assert_eq!(result, "a".repeat(3000));
+
+        // other letters, not shared by every string
+        let result = within(limit, || {
+            let strings = [
+                "a".repeat(3000) + "xyz",
+                "x".to_string() + &"a".repeat(2500) + "y",
+                "a".repeat(1000) + "z" + &"a".repeat(2000),
+                "za".repeat(2800),
+            ];
+            mlcs_chars(&strings.iter().map(String::as_str).collect::<Vec<_>>())
+        });
+        assert_eq!(result, "a".repeat(2500));
+
+        let ab = "ab".repeat(1500);
+        let result = within(limit, move || {
+            let longer = "c".to_string() + &ab + "c";
+            mlcs_exact(&[&ab, &longer, &ab, &ab, &longer])
+        });
+        assert_eq!(result, "ab".repeat(1500));
+    }
+
+    #[test]
+    fn repeated_strings() {
+        let inputs: [&[&str]; 5] = [
+            &["abc", "abc", "xbc", "abc"],
+            &["ABCBDAB", "BDCABA", "ABCBDAB", "BDCABA", "BDCABA"],
+            &["abcab", "bacba", "cabab", "bacba", "abcab"],
+            &["串用于测试", "用于串测试", "串用于测试"],
+            &["abc", "def", "abc"],
+        ];
+        for input in inputs {
+            let (distinct, copies) = dedup_chains(to_chains(input));
+            assert!(distinct.len() < input.len());
+            for (chain, &k) in to_chains(input).iter().zip(&copies) {
+                assert_eq!(*chain, distinct[k]);
+            }
+            let distinct: Vec<String> = distinct.iter().map(|s| s.iter().collect()).collect();
+            let distinct: Vec<&str> = distinct.iter().map(String::as_str).collect();
+            let expected = Context::new(&distinct).solve(&MlcsConfig::default());
+            // the search over every copy gives the same subsequence
+            assert_eq!(
+                Context::new(input).solve(&MlcsConfig::default()),
+                expected,
+                "{input:?}"
+            );
+            assert_eq!(
+                multiple_longest_common_subsequence(&input.to_vec()),
+                expected
+            );
+        }
+        assert_eq!(
+            dedup_chains(to_chains(&["b", "a", "b", "a", "c"])),
+            (to_chains(&["b", "a", "c"]), vec![0, 1, 0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn columns_without_search() {
+        let long = fixtures::synthetic(1, 3000, 4, 7).remove(0);
+        let columns = within(Duration::from_secs(10), move || {
+            mlcs_columns(&[long.as_str(), long.as_str()])
+        });
+        assert_eq!(columns.len(), 3000);
+        assert!(columns
+            .iter()
+            .enumerate()
+            .all(|(i, (_, positions))| positions == &[i, i]));
+        // a single common letter, matched at its first free position
+        assert_eq!(
+            mlcs_columns(&["xaya", "aza"]),
+            [('a', vec![1, 0]), ('a', vec![3, 2])]
+        );
+        assert_eq!(mlcs_complement(&["abc", "abc", "abc"]), vec![vec![]; 3]);
+    }
+
+    #[test]
+    fn columns_of_repeated_strings() {
+        let columns = mlcs_columns(&["ABC", "AC", "ABC", "BAC"]);
+        assert_eq!(columns, [('A', vec![0, 0, 0, 1]), ('C', vec![2, 1, 2, 2])]);
+    }
+
+    #[test]
+    fn many_copies_of_a_few_strings() {
+        let strings = fixtures::synthetic(3, 60, 4, 5);
+        let expected = mlcs_chars(&strings.iter().map(String::as_str).collect::<Vec<_>>());
+        let result = within(Duration::from_secs(10), move || {
+            let copies: Vec<&str> = strings
+                .iter()
+                .cycle()
+                .take(60)
+                .map(String::as_str)
+                .collect();
+            mlcs_chars(&copies)
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ignoring() {
+        let ignore = HashSet::from([',', ' ']);
+        assert_eq!(mlcs_ignoring(&["a, b", "a b,"], &ignore), "ab");
+        assert_eq!(
+            multiple_longest_common_subsequence(&vec!["a, b", "a b,"]),
+            "a b"
+        );
+        // ignored chars common to every string are not kept either
+        assert_eq!(mlcs_ignoring(&["x,y,z", "x,z,y"], &ignore), "xy");
+        assert_eq!(mlcs_ignoring(&["串,用 于", "用于 串"], &ignore), "用于");
+        assert_eq!(mlcs_ignoring(&[", ,", " "], &ignore), "");
+        assert_eq!(mlcs_ignoring(&["ABC", "AC", "BAC"], &HashSet::new()), "AC");
+        assert_eq!(mlcs_ignoring(&[], &ignore), "");
+    }
+
+    #[test]
+    fn one_buffer_for_many_inputs() {
+        let inputs: [&[&str]; 7] = [
+            fixtures::MIX.input,
+            &["ABC", "AC", "BAC"],
+            &["abc", "def"],
+            &["串用于测试", "用于串测试"],
+            &["", ""],
+            fixtures::LONG.input,
+            &["aXaYa", "baab"],
+        ];
+        let mut buf = String::from("left over from a previous call");
+        for input in inputs {
+            mlcs_into_string_reuse(input, &mut buf);
+            assert_eq!(buf, multiple_longest_common_subsequence(&input.to_vec()));
+        }
+        // the longest result fits in the buffer, which is never reallocated again
+        let capacity = buf.capacity();
+        let pointer = buf.as_ptr();
+        for input in inputs {
+            mlcs_into_string_reuse(input, &mut buf);
+        }
+        assert_eq!(buf.capacity(), capacity);
+        assert_eq!(buf.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn context_of_empty_strings() {
+        for input in [["", ""].as_slice(), &["", "", ""], &[""], &[]] {
+            let (result, stats) = Context::new(input).solve_with_stats(&MlcsConfig::default());
+            assert_eq!(result, "");
+            assert_eq!(stats.bands, 0, "{input:?}");
+            assert_eq!(stats.goal_g, 0);
+            assert!(!stats.interrupted);
+            assert!(mlcs_columns(input).is_empty());
+            assert!(!mlcs_at_least(input, 1));
+        }
+    }
+
+    #[test]
+    fn shortcuts() {
+        let chains = |input: &[&str]| to_chains(input);
+        let collect =
+            |mlcs: Option<Vec<char>>| mlcs.map(|mlcs| mlcs.into_iter().collect::<String>());
+        assert_eq!(
+            collect(shortcut(&chains(&["ace", "abcde", "xaxcxe"]))),
+            Some("ace".into())
+        );
+        assert_eq!(
+            collect(shortcut(&chains(&["abc", "def"]))),
+            Some(String::new())
+        );
+        assert_eq!(
+            collect(shortcut(&chains(&["aXaYa", "aaZaa", "a"]))),
+            Some("a".into())
+        );
+        assert_eq!(
+            collect(shortcut(&chains(&["baab", "aXaYa"]))),
+            Some("aa".into())
+        );
+        assert_eq!(collect(shortcut(&chains(&["ab", "ba"]))), None);
+        assert_eq!(collect(shortcut(&chains(&[]))), None);
+        assert_eq!(
+            collect(shortcut(&chains(&["", "abc"]))),
+            Some(String::new())
+        );
+    }
+
+    #[test]
+    fn whitespace_runs() {
+        assert_eq!(collapse_whitespace(""), "");
+        assert_eq!(collapse_whitespace(" \t\n "), " ");
+        assert_eq!(collapse_whitespace("a  b\t\tc\n"), "a b c ");
+    }
+
+    #[test]
+    fn indentation_does_not_change_the_structure() {
+        let spaces = "fn main() {\n    let x = 1;\n    if x > 0 {\n        print(x);\n    }\n}";
+        let tabs = "fn main() {\n\tlet x = 1;\n\tif x > 0 {\n\t\tprint(x);\n\t}\n}";
+        let flat = "fn main() { let x = 1; if x > 0 { print(x); } }";
+        let expected = flat;
+        assert_eq!(mlcs_collapse_whitespace(&[spaces, tabs]), expected);
+        assert_eq!(mlcs_collapse_whitespace(&[spaces, tabs, flat]), expected);
+        // without collapsing, only the newlines are matched between the lines
+        assert_eq!(
+            multiple_longest_common_subsequence(&vec![spaces, tabs]),
+            "fn main() {\nlet x = 1;\nif x > 0 {\nprint(x);\n}\n}"
+        );
+        assert_eq!(mlcs_collapse_whitespace(&[]), "");
+    }
+
+    #[test]
+    fn single_string_is_its_own_subsequence() {
+        // the heuristic had no pair to read and was always 0,
+        // so the first starting point was returned
+        for s in ["abcab", "a", "串用于测试", "aaa"] {
+            assert_eq!(multiple_longest_common_subsequence(&vec![s]), s);
+        }
+        assert_eq!(mlcs_collapse_runs(&["aabbbc"]), "aabbbc");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is not defined")]
+    fn heuristic_of_a_point_with_a_none_coordinate() {
+        let ctx = Context::new(&["abc", "cab"]);
+        ctx.heuristic(&[Some(0), None]);
+    }
+
+    #[test]
+    fn context_try_from() {
+        let input = ["gxt#xayb", "abgt#ab", "gyayt#ahjb", "gyayjjjt#ab"];
+        let mut ctx = Context::try_from(&input[..]).unwrap();
+        let expected = Context::new(&input).solve(&MlcsConfig::default());
+        assert_eq!(ctx.solve(&MlcsConfig::default()), expected);
+        // two strings are enough, even empty or without a common char
+        assert!(Context::try_from(&["", ""][..]).is_ok());
+        assert!(Context::try_from(&["abc", "xyz"][..]).is_ok());
+        assert!(matches!(
+            Context::try_from(&[][..]),
+            Err(MlcsError::EmptyInput)
+        ));
+        assert!(matches!(
+            Context::try_from(&["abc"][..]),
+            Err(MlcsError::SingleString)
+        ));
+    }
+
+    #[test]
+    fn reset_keeps_preprocessing() {
+        let input = ["gxt#xayb", "abgt#ab", "gyayt#ahjb", "gyayjjjt#ab"];
+        let narrow = MlcsConfig {
+            width: 0,
+            ..MlcsConfig::default()
+        };
+        let wide = MlcsConfig::default();
+
+        let mut ctx = Context::new(&input);
+        let first = ctx.solve(&narrow);
+        ctx.reset();
+        assert_eq!(ctx.g.len(), 1);
+        assert_eq!(ctx.f.len(), 1);
+        assert_eq!(ctx.parents.len(), 1);
+        let second = ctx.solve(&wide);
+
+        assert_eq!(first, Context::new(&input).solve(&narrow));
+        assert_eq!(second, Context::new(&input).solve(&wide));
+    }
+
+    macro_rules! score_matrix_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $lcs_len:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let s1: Vec<char> = $s1.chars().collect();
+                    let s2: Vec<char> = $s2.chars().collect();
+                    let matrix = score_matrix(&s1, &s2, None);
+                    assert_eq!(matrix.len(), s1.len() + 1);
+                    assert_eq!(matrix[0].len(), s2.len() + 1);
+                    assert_eq!(matrix[0][0], $lcs_len);
+                    // every cell holds the LCS length of the suffixes
+                    for i in 0..=s1.len() {
+                        for j in 0..=s2.len() {
+                            let expected = mlcs_len_pair(
+                                &s1[i..].iter().collect::<String>(),
+                                &s2[j..].iter().collect::<String>(),
+                            );
+                            assert_eq!(matrix[i][j], expected as u64, "cell ({i}, {j})");
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    score_matrix_tests! {
+        score_matrix_empty: ("", "", 0),
+        score_matrix_one_empty: ("abc", "", 0),
+        score_matrix_single_char_match: ("a", "a", 1),
+        score_matrix_single_char_mismatch: ("a", "b", 0),
+        score_matrix_two_chars: ("ab", "ab", 2),
+        score_matrix_two_chars_swapped: ("ab", "ba", 1),
+        score_matrix_match_on_first_chars: ("ax", "ay", 1),
+        score_matrix_match_on_last_chars: ("xa", "ya", 1),
+        score_matrix_classic: ("ABCBDAB", "BDCABA", 4),
+    }
+
+    #[test]
+    fn suffix_tables_of_the_pairs_i_below_j() {
+        // the table of j and i would be the transpose of the one of i and j
+        let ctx = Context::new(&["gxt#xayb", "abgt#ab", "gyayt#ahjb", "tb"]);
+        assert_eq!(ctx.ms.len(), 6);
+        for i in 0..ctx.d {
+            for j in (i + 1)..ctx.d {
+                let mij = &ctx.ms[pair_index(i, j, ctx.d)];
+                assert_eq!(**mij, score_matrix(&ctx.chains[i], &ctx.chains[j], None));
+                let mji = score_matrix(&ctx.chains[j], &ctx.chains[i], None);
+                for (a, row) in mij.iter().enumerate() {
+                    for (b, &cell) in row.iter().enumerate() {
+                        assert_eq!(cell, mji[b][a]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pair_indices() {
+        for d in 0..6 {
+            let pairs: Vec<usize> = (0..d)
+                .flat_map(|i| ((i + 1)..d).map(move |j| pair_index(i, j, d)))
+                .collect();
+            assert_eq!(pairs, (0..pair_count(d)).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! A brute-force MLCS, used as a reference by the tests.
+//!
+//! The subsequences of the shortest string are enumerated from the longest to the
+//! shortest, and the first one found in every other string is a longest common
+//! subsequence. This takes O(2^n * total length) time for a shortest string of n chars,
+//! so it is limited to `MAX_SHORTEST` chars.
+//!
+//! Longer inputs of 2 or 3 strings are checked against the classic dynamic programming
+//! instead, in O(n^d) time and space.
+
+use super::{fixtures, mlcs_approx, mlcs_exact, multiple_longest_common_subsequence};
+use super::{Context, MlcsConfig};
+use crate::dynamic_programming::is_subsequence;
+use quickcheck::{Arbitrary, Gen};
+
+const MAX_SHORTEST: usize = 15;
+
+/// The length of a longest common subsequence of `chains`
+///
+/// # Panics
+///
+/// Panics if the shortest string has more than `MAX_SHORTEST` chars.
+pub(super) fn brute_force_mlcs_len(chains: &[&str]) -> usize {
+    let Some(shortest) = chains.iter().min_by_key(|s| s.chars().count()) else {
+        return 0;
+    };
+    let shortest: Vec<char> = shortest.chars().collect();
+    let n = shortest.len();
+    assert!(
+        n <= MAX_SHORTEST,
+        "{n} chars are too many for the brute force"
+    );
+
+    let mut masks: Vec<u32> = (0..1 << n).collect();
+    masks.sort_unstable_by_key(|mask| std::cmp::Reverse(mask.count_ones()));
+    masks
+        .into_iter()
+        .find(|&mask| {
+            let sub: String = (0..n)
+                .filter(|&i| mask & (1 << i) != 0)
+                .map(|i| shortest[i])
+                .collect();
+            chains.iter().all(|s| is_subsequence(&sub, s))
+        })
+        .map_or(0, |mask| mask.count_ones() as usize)
+}
+
+/// The length of a longest common subsequence of 2 or 3 strings, by dynamic programming
+///
+/// Two strings are compared by `longest_common_subsequence`, whose traceback walks its
+/// table back to an actual common subsequence.
+///
+/// # Panics
+///
+/// Panics if there are not 2 or 3 strings.
+pub(super) fn dp_mlcs_len(chains: &[&str]) -> usize {
+    match chains {
+        [a, b] => crate::dynamic_programming::longest_common_subsequence(a, b)
+            .chars()
+            .count(),
+        [a, b, c] => {
+            let (a, b, c): (Vec<char>, Vec<char>, Vec<char>) = (
+                a.chars().collect(),
+                b.chars().collect(),
+                c.chars().collect(),
+            );
+            // table[i][j][k] is the MLCS length of a[i..], b[j..] and c[k..]
+            let mut table = vec![vec![vec![0; c.len() + 1]; b.len() + 1]; a.len() + 1];
+            for i in (0..a.len()).rev() {
+                for j in (0..b.len()).rev() {
+                    for k in (0..c.len()).rev() {
+                        table[i][j][k] = if a[i] == b[j] && b[j] == c[k] {
+                            table[i + 1][j + 1][k + 1] + 1
+                        } else {
+                            table[i + 1][j][k]
+                                .max(table[i][j + 1][k])
+                                .max(table[i][j][k + 1])
+                        };
+                    }
+                }
+            }
+            table[0][0][0]
+        }
+        _ => panic!(
+            "{} strings are not handled by the dynamic programming",
+            chains.len()
+        ),
+    }
+}
+
+/// 2 to 5 strings of at most 12 chars over 2 to 4 letters
+#[derive(Clone, Debug)]
+struct SmallInput(Vec<String>);
+
+impl Arbitrary for SmallInput {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let d = 2 + usize::arbitrary(g) % 4;
+        let letters = 2 + u8::arbitrary(g) % 3;
+        SmallInput(
+            (0..d)
+                .map(|_| {
+                    let len = usize::arbitrary(g) % 13;
+                    (0..len)
+                        .map(|_| char::from(b'a' + u8::arbitrary(g) % letters))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    // fewer strings, then shorter strings
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let strings = self.0.clone();
+        let mut smaller: Vec<SmallInput> = vec![];
+        if strings.len() > 2 {
+            for i in 0..strings.len() {
+                let mut fewer = strings.clone();
+                fewer.remove(i);
+                smaller.push(SmallInput(fewer));
+            }
+        }
+        for (i, s) in strings.iter().enumerate() {
+            for (k, _) in s.char_indices() {
+                let mut shorter = strings.clone();
+                shorter[i].remove(k);
+                smaller.push(SmallInput(shorter));
+            }
+        }
+        Box::new(smaller.into_iter())
+    }
+}
+
+#[quickcheck_macros::quickcheck]
+fn same_length_as_the_brute_force(input: SmallInput) -> bool {
+    let chains: Vec<&str> = input.0.iter().map(String::as_str).collect();
+    let result = multiple_longest_common_subsequence(&chains);
+    chains.iter().all(|s| is_subsequence(&result, s))
+        && result.chars().count() == brute_force_mlcs_len(&chains)
+}
+
+/// Two strings of at most 40 bytes, drawn from 1 to 6 random byte values
+///
+/// Two strings are the inputs where the pruning, the off-by-one errors of the suffix tables
+/// and the indexing of the lookup tables show most, and they are longer than the brute force
+/// allows, so they are checked against the dynamic programming.
+#[derive(Clone, Debug)]
+struct BytePair(Vec<u8>, Vec<u8>);
+
+impl Arbitrary for BytePair {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let letters: Vec<u8> = (0..=usize::arbitrary(g) % 6)
+            .map(|_| u8::arbitrary(g))
+            .collect();
+        let mut string = || -> Vec<u8> {
+            (0..usize::arbitrary(g) % 41)
+                .map(|_| *g.choose(&letters).unwrap())
+                .collect()
+        };
+        BytePair(string(), string())
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let first = self.1.clone();
+        let second = self.0.clone();
+        Box::new(
+            self.0
+                .shrink()
+                .map(move |s| BytePair(s, first.clone()))
+                .chain(self.1.shrink().map(move |s| BytePair(second.clone(), s))),
+        )
+    }
+}
+
+#[quickcheck_macros::quickcheck]
+fn two_strings_of_bytes_as_long_as_the_dynamic_programming(input: BytePair) -> bool {
+    let (s1, s2): (String, String) = (
+        input.0.iter().copied().map(char::from).collect(),
+        input.1.iter().copied().map(char::from).collect(),
+    );
+    let chains = vec![s1.as_str(), &s2];
+    let result = multiple_longest_common_subsequence(&chains);
+    chains.iter().all(|s| is_subsequence(&result, s))
+        && result.chars().count() == dp_mlcs_len(&chains)
+}
+
+// the counterexamples found by `same_length_as_the_brute_force`
+#[test]
+fn regressions() {
+    for input in [
+        // the band only kept the best starting point when its f was below the width
+        ["bccccaa", "bccbaba", "ccbccb"].as_slice(),
+        // the search stopped on the first point of the band matching the end of the strings
+        &["bba", "bba"],
+    ] {
+        let result = multiple_longest_common_subsequence(&input.to_vec());
+        assert_eq!(
+            result.chars().count(),
+            brute_force_mlcs_len(input),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn brute_force() {
+    assert_eq!(brute_force_mlcs_len(&[]), 0);
+    assert_eq!(brute_force_mlcs_len(&["", "abc"]), 0);
+    assert_eq!(brute_force_mlcs_len(&["ABCBDAB", "BDCABA"]), 4);
+    assert_eq!(brute_force_mlcs_len(&["abcab", "bacba", "cabab"]), 3);
+    assert_eq!(brute_force_mlcs_len(&["串用于", "用串于"]), 2);
+}
+
+#[test]
+#[should_panic(expected = "too many")]
+fn brute_force_is_limited() {
+    brute_force_mlcs_len(&["abcdefghijklmnop", "abcdefghijklmnop"]);
+}
+
+// pseudo-random inputs of 2 or 3 strings of 10 to 49 chars over 2 to 4 letters
+fn medium_inputs(count: usize) -> Vec<Vec<String>> {
+    (1..=count)
+        .map(|seed| {
+            let letters = 2 + (seed / 2 % 3) as u8;
+            let mut strings = fixtures::synthetic(2 + seed % 2, 49, letters, seed as u64);
+            // the lengths shifting with the seed
+            for (j, s) in strings.iter_mut().enumerate() {
+                s.truncate(10 + (7 * seed + 13 * j) % 40);
+            }
+            strings
+        })
+        .collect()
+}
+
+// the number of inputs whose result with this width is shorter than the optimum
+fn suboptimal_results(inputs: &[Vec<String>], width: u64) -> usize {
+    let config = MlcsConfig {
+        width,
+        ..MlcsConfig::default()
+    };
+    inputs
+        .iter()
+        .filter(|strings| {
+            let chains: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let result = Context::new(&chains).solve(&config);
+            assert!(
+                chains.iter().all(|s| is_subsequence(&result, s)),
+                "{chains:?}"
+            );
+            result.chars().count() < dp_mlcs_len(&chains)
+        })
+        .count()
+}
+
+#[test]
+fn exactness() {
+    let inputs = medium_inputs(150);
+    for strings in &inputs {
+        let chains: Vec<&str> = strings.iter().map(String::as_str).collect();
+        let expected = dp_mlcs_len(&chains);
+        assert_eq!(mlcs_exact(&chains).chars().count(), expected, "{chains:?}");
+        assert_eq!(mlcs_approx(&chains).chars().count(), expected, "{chains:?}");
+    }
+    // the pruning does lose chars, with a band too narrow for the heuristic
+    assert!(suboptimal_results(&inputs, 0) > 0);
+    assert_eq!(suboptimal_results(&inputs, MlcsConfig::default().width), 0);
+}
+
+#[test]
+fn dynamic_programming() {
+    assert_eq!(dp_mlcs_len(&["ABCBDAB", "BDCABA"]), 4);
+    assert_eq!(dp_mlcs_len(&["abcab", "bacba", "cabab"]), 3);
+    assert_eq!(dp_mlcs_len(&["", "abc", "abc"]), 0);
+    assert_eq!(dp_mlcs_len(&["串用于", "用串于", "串于"]), 2);
+    for input in [
+        ["bccccaa", "bccbaba", "ccbccb"].as_slice(),
+        &["abcbdab", "bdcaba"],
+    ] {
+        assert_eq!(dp_mlcs_len(input), brute_force_mlcs_len(input));
+    }
+}
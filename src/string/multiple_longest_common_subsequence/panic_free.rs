@@ -0,0 +1,161 @@
+//! Every public function of the module taking strings, run on inputs at the edges of the
+//! preprocessing and of the search, none of which may panic.
+//!
+//! The invariants of the search are upheld by construction: the successors are only built
+//! from the lookup tables, a letter of the alphabet occurs in every string, and every point
+//! read from the maps was recorded there before. The remaining inputs the module cannot
+//! handle are reported as errors: invalid weights by `MlcsError`, damaged corpus files by
+//! `io::Error`. Only memory may run out, with very long strings.
+
+use super::{
+    dedup_similar, lcs3, lcs3_with_limit, lcs_len_pair_with_scratch, lcs_prefix_matrix,
+    mlcs_approx, mlcs_at_least, mlcs_bytes, mlcs_cancellable, mlcs_chars, mlcs_collapse_runs,
+    mlcs_collapse_whitespace, mlcs_columns, mlcs_complement, mlcs_distance, mlcs_exact,
+    mlcs_ignoring, mlcs_into_string_reuse, mlcs_len_pair, mlcs_must_include, mlcs_owned,
+    mlcs_sequences, mlcs_similarity, mlcs_substitution, mlcs_with_max_alphabet, mlcs_with_stats,
+    multiple_longest_common_subsequence, pairwise_similarity_matrix, top_k_common_subsequences,
+    weighted_multiple_lcs, windowed_mlcs, AlphabetOrder, Context, MlcsBuilder, MlcsConfig,
+    MlcsError, MlcsInput, PreprocessedCorpus,
+};
+use crate::string::SubstitutionMatrix;
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+/// The inputs, from no string at all to strings of many thousand chars
+fn nasty_inputs() -> Vec<Vec<String>> {
+    let strings = |strings: &[&str]| strings.iter().map(|s| s.to_string()).collect();
+    vec![
+        vec![],
+        strings(&[""]),
+        strings(&["", ""]),
+        strings(&["", "", ""]),
+        strings(&["abc", "", "abc"]),
+        strings(&["a"]),
+        strings(&["a", "a"]),
+        strings(&["a", "b"]),
+        vec!["abcabc".to_string(); 5],
+        strings(&["abc", "def", "ghi"]),
+        vec!["a".to_string(); 100],
+        (0..100).map(|i| ["a", "b"][i % 2].to_string()).collect(),
+        strings(&["e\u{301}😀\u{0}", "😀e\u{301}", "\u{10FFFF}😀\u{0}"]),
+        strings(&["串用于测试", "用于串测试", "\u{feff}串"]),
+        strings(&["  \t\n", "\n\n", " "]),
+        vec!["x".repeat(20_000)],
+        vec!["ab".repeat(50); 2],
+    ]
+}
+
+/// The public functions of the module, run on `chains`
+fn run_every_entry_point(chains: &[&str]) {
+    multiple_longest_common_subsequence(&chains.to_vec());
+    mlcs_chars(chains);
+    let bytes: Vec<&[u8]> = chains.iter().map(|s| s.as_bytes()).collect();
+    mlcs_bytes(&bytes);
+    mlcs_approx(chains);
+    mlcs_exact(chains);
+    mlcs_into_string_reuse(chains, &mut String::from("reused"));
+    mlcs_owned(chains.iter().map(|s| s.to_string()).collect());
+    mlcs_sequences(
+        &chains
+            .iter()
+            .map(|s| s.chars().collect())
+            .collect::<Vec<Vec<char>>>(),
+    );
+    chains.iter().collect::<MlcsInput>().solve();
+    mlcs_substitution(chains, &SubstitutionMatrix::blosum62());
+    mlcs_collapse_whitespace(chains);
+    mlcs_collapse_runs(chains);
+    mlcs_ignoring(chains, &HashSet::from(['a', '😀']));
+    mlcs_with_stats(chains);
+    assert!(matches!(
+        mlcs_with_max_alphabet(chains, 1),
+        Ok(_) | Err(MlcsError::AlphabetTooLarge { .. })
+    ));
+    assert!(mlcs_with_max_alphabet(chains, usize::MAX).is_ok());
+    mlcs_columns(chains);
+    mlcs_complement(chains);
+    mlcs_distance(chains);
+    mlcs_cancellable(chains, &AtomicBool::new(false));
+    mlcs_cancellable(chains, &AtomicBool::new(true));
+    for k in [0, 1, usize::MAX] {
+        mlcs_at_least(chains, k);
+    }
+    for required in ["", "a", "😀", "aaaa"] {
+        mlcs_must_include(chains, required);
+    }
+    for max_gap in [0, 1, usize::MAX] {
+        windowed_mlcs(chains, max_gap);
+    }
+    for k in [0, 3] {
+        top_k_common_subsequences(chains, k);
+    }
+    #[cfg(feature = "hda")]
+    for threads in [0, 3] {
+        super::mlcs_parallel(chains, threads);
+    }
+
+    let weights: Vec<Vec<f64>> = chains
+        .iter()
+        .map(|s| vec![1.5; s.chars().count()])
+        .collect();
+    assert!(weighted_multiple_lcs(chains, &weights).is_ok());
+    assert_eq!(
+        weighted_multiple_lcs(chains, &[]).is_ok(),
+        chains.is_empty()
+    );
+
+    let (a, b) = (
+        chains.first().copied().unwrap_or(""),
+        chains.get(1).copied().unwrap_or(""),
+    );
+    mlcs_len_pair(a, b);
+    lcs_len_pair_with_scratch(a, b, &mut vec![]);
+    lcs_prefix_matrix(a, b);
+    let c = chains.get(2).copied().unwrap_or("");
+    assert!(lcs3(a, b, c).is_ok());
+    assert!(lcs3_with_limit(a, b, c, 0).is_err());
+    mlcs_similarity(a, b);
+    pairwise_similarity_matrix(chains);
+    for threshold in [-1.0, 0.5, f64::NAN] {
+        dedup_similar(chains, threshold);
+    }
+
+    let corpus = PreprocessedCorpus::build(chains);
+    corpus.mlcs_with("");
+    // a query as long as the longest string would need a table of its square
+    corpus.mlcs_with(&a.chars().take(10).collect::<String>());
+
+    for builder in [
+        MlcsBuilder::new(chains).width(0),
+        MlcsBuilder::new(chains).max_nodes(0),
+        MlcsBuilder::new(chains).timeout(Duration::ZERO),
+        MlcsBuilder::new(chains)
+            .case_insensitive(true)
+            .low_memory_reconstruction(true)
+            .alphabet_order(AlphabetOrder::FirstOccurrence),
+    ] {
+        builder.run();
+    }
+
+    assert_eq!(Context::try_from(chains).is_ok(), chains.len() >= 2);
+    let mut ctx = Context::new(chains);
+    ctx.estimated_memory();
+    ctx.solve(&MlcsConfig::default());
+    ctx.reset();
+    ctx.solve(&MlcsConfig {
+        low_memory_reconstruction: true,
+        ..MlcsConfig::default()
+    });
+}
+
+#[test]
+fn no_entry_point_panics() {
+    for input in nasty_inputs() {
+        let chains: Vec<&str> = input.iter().map(String::as_str).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| run_every_entry_point(&chains)));
+        let lengths: Vec<usize> = chains.iter().map(|s| s.chars().count()).collect();
+        assert!(result.is_ok(), "panicked on strings of lengths {lengths:?}");
+    }
+}
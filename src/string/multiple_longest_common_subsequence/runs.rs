@@ -0,0 +1,122 @@
+//! Run-collapsed MLCS, a fast path for highly repetitive inputs.
+//!
+//! On strings like `"aaaaabbbbb"`, every occurrence of a letter yields a point which is
+//! nearly identical to its neighbours, and the frontier of the search explodes.
+//! Here each string is first turned into its runs, e.g. `"aaabcc"` into
+//! `[('a', 3), ('b', 1), ('c', 2)]`, and the search runs over the run sequences:
+//! matching a run of every string adds `min(count)` copies of its letter to the result.
+//! The runs are expanded back when the subsequence is rebuilt.
+
+use super::{Context, MlcsConfig};
+
+/// Splits a string into its runs of identical chars
+///
+/// # Returns
+/// The letter of each run, and the length of each run
+fn collapse_runs(s: &str) -> (Vec<char>, Vec<u64>) {
+    let mut letters: Vec<char> = vec![];
+    let mut counts: Vec<u64> = vec![];
+    for c in s.chars() {
+        match counts.last_mut() {
+            Some(count) if letters.last() == Some(&c) => *count += 1,
+            _ => {
+                letters.push(c);
+                counts.push(1);
+            }
+        }
+    }
+    (letters, counts)
+}
+
+/// Finds a long common subsequence among multiple strings, treating each run of
+/// repeated chars as a single token
+///
+/// Collapsing the runs shrinks highly repetitive inputs by orders of magnitude, which makes
+/// them tractable. The result is always a common subsequence of all strings, but since a
+/// run is matched as a whole, it can be shorter than `multiple_longest_common_subsequence`
+/// when a run of one string would have to be matched across several runs of another one
+/// (e.g. `"aaaa"` against `"aabaa"`). When the strings share the same run structure, both
+/// functions agree.
+///
+/// # Arguments
+///
+/// * `chains` - The strings among which the common subsequence is searched.
+///
+/// # Returns
+///
+/// A common subsequence, expanded back from the matched runs.
+pub fn mlcs_collapse_runs(chains: &[&str]) -> String {
+    if chains.is_empty() {
+        return String::new();
+    }
+    let (letters, counts): (Vec<Vec<char>>, Vec<Vec<u64>>) =
+        chains.iter().map(|s| collapse_runs(s)).unzip();
+
+    // the match of a run in every string is worth the smallest of the run lengths
+    Context::from_weights(letters, counts, true).solve(&MlcsConfig::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, multiple_longest_common_subsequence};
+    use super::*;
+
+    #[test]
+    fn runs_of_a_string() {
+        assert_eq!(collapse_runs(""), (vec![], vec![]));
+        assert_eq!(
+            collapse_runs("aaab串串a"),
+            (vec!['a', 'b', '串', 'a'], vec![3, 1, 2, 1])
+        );
+    }
+
+    macro_rules! runs_tests {
+        ($($name:ident: ($input:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let input: Vec<&str> = $input.to_vec();
+                    let result = mlcs_collapse_runs(&input);
+                    assert_eq!(result, $expected);
+                    fixtures::assert_valid_mlcs(&result, &input, 0);
+                }
+             )*
+        };
+    }
+
+    runs_tests! {
+        pure_runs: (["aaaaabbbbb", "aaabbbbbbb", "aaaaaaabbb"], "aaabbb"),
+        single_letter: (["aaaa", "aaaaaaa", "aaaaa"], "aaaa"),
+        mixed_runs: (["aaxbbbbyc", "aaaxbbyccc", "axbbbbbbycc"], "axbbyc"),
+        unicode_runs: (["串串串用用", "串用用用用", "串串用"], "串用"),
+        no_common: (["aaa", "bbb"], ""),
+        one_empty: (["", "aaa"], ""),
+        no_input: ([] as [&str; 0], ""),
+    }
+
+    #[test]
+    fn matches_plain_algorithm_on_small_inputs() {
+        let inputs: [&[&str]; 4] = [
+            &["aaabbbccc", "aabbbbcc", "aaaabcccc"],
+            &["xxyyyzz", "xxxyyz", "xyyyzzz"],
+            &["abc", "abc"],
+            &["aabbaacc", "aaabbaac", "abbbaaacc"],
+        ];
+        for input in inputs {
+            assert_eq!(
+                mlcs_collapse_runs(input),
+                multiple_longest_common_subsequence(&input.to_vec()),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn huge_repetitive_inputs() {
+        let a = "a".repeat(100_000) + &"b".repeat(100_000);
+        let b = "a".repeat(50_000) + &"c".repeat(10) + &"b".repeat(150_000);
+        let c = "a".repeat(120_000) + &"b".repeat(80_000);
+        let expected = "a".repeat(50_000) + &"b".repeat(80_000);
+        assert_eq!(mlcs_collapse_runs(&[&a, &b, &c]), expected);
+    }
+}
@@ -0,0 +1,159 @@
+//! MLCS scored by a substitution matrix, e.g. of protein sequences.
+//!
+//! The chars of the subsequence are still matched with equal chars in every string, but
+//! each is worth its score against itself in the matrix, e.g. 11 for a tryptophan (W) and 4
+//! for an alanine (A) in BLOSUM62, and the search maximizes the total score instead of the
+//! length. The heuristic reads the suffix tables of `score_matrix` with the matrix, in which
+//! two strings may align different chars, a glutamate (E) with an aspartate (D) for 2: the
+//! best alignment of two suffixes is worth at least any of their common subsequences, so it
+//! still bounds the score left to match.
+
+use super::{dedup_chains, shortcut, to_chains, Context, MlcsConfig};
+use crate::string::SubstitutionMatrix;
+
+/// Finds a common subsequence of the strings maximizing the total score of its chars, each
+/// char scoring its substitution with itself in `substitution`
+///
+/// The negative scores count as 0. With the score 1 for every char, this is
+/// `multiple_longest_common_subsequence`, and as there, the band of the default
+/// configuration may miss the best subsequences on many or long strings.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::{mlcs_substitution, SubstitutionMatrix};
+///
+/// // "AA" is longer, but a tryptophan scores 11 and an alanine 4
+/// let blosum62 = SubstitutionMatrix::blosum62();
+/// assert_eq!(mlcs_substitution(&["WAA", "AAW"], &blosum62), "W");
+/// ```
+pub fn mlcs_substitution(chains: &[&str], substitution: &SubstitutionMatrix) -> String {
+    let chains = to_chains(chains);
+    // the chars of the answers of `shortcut` all score at least 0, and no other common
+    // subsequence holds more of them
+    if let Some(mlcs) = shortcut(&chains) {
+        return mlcs.into_iter().collect();
+    }
+    Context::from_substitution(dedup_chains(chains).0, substitution).solve(&MlcsConfig::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_approx, score_matrix};
+    use super::*;
+    use crate::dynamic_programming::is_subsequence;
+
+    // D and E, and I and M, align for a positive score
+    const RESIDUES: [char; 6] = ['A', 'D', 'E', 'I', 'M', 'S'];
+
+    fn total_score(s: &str, substitution: &SubstitutionMatrix) -> i64 {
+        s.chars().map(|c| substitution.score(c, c).max(0)).sum()
+    }
+
+    // the best total score of a common subsequence, over every subsequence of the first string
+    fn brute_force_score(chains: &[&str], substitution: &SubstitutionMatrix) -> i64 {
+        let first: Vec<char> = chains[0].chars().collect();
+        (0..1u32 << first.len())
+            .map(|mask| {
+                (0..first.len())
+                    .filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| first[i])
+                    .collect::<String>()
+            })
+            .filter(|sub| chains.iter().all(|s| is_subsequence(sub, s)))
+            .map(|sub| total_score(&sub, substitution))
+            .max()
+            .unwrap_or(0)
+    }
+
+    // a matrix scoring 1 for equal chars and 0 otherwise, like the LCS
+    fn identity() -> SubstitutionMatrix {
+        let path = std::env::temp_dir().join(format!("mlcs_identity_{}.txt", std::process::id()));
+        let mut text = String::from("  A B C D\n");
+        for a in ['A', 'B', 'C', 'D'] {
+            text.push(a);
+            for b in ['A', 'B', 'C', 'D'] {
+                text.push_str(if a == b { " 1" } else { " 0" });
+            }
+            text.push('\n');
+        }
+        std::fs::write(&path, text).unwrap();
+        let matrix = SubstitutionMatrix::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        matrix.unwrap()
+    }
+
+    #[test]
+    fn heavy_residues_win_over_length() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        assert_eq!(mlcs_substitution(&["WAA", "AAW"], &blosum62), "W");
+        assert_eq!(mlcs_substitution(&["WAAA", "AAAW"], &blosum62), "AAA");
+        // C scores 9, A and S 4
+        assert_eq!(mlcs_substitution(&["CCASAS", "ASASCC"], &blosum62), "CC");
+    }
+
+    #[test]
+    fn best_score_on_small_proteins() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut residue = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            RESIDUES[(state % 6) as usize]
+        };
+        for d in 2..=4 {
+            for _ in 0..20 {
+                let strings: Vec<String> = (0..d)
+                    .map(|_| (0..10).map(|_| residue()).collect())
+                    .collect();
+                let chains: Vec<&str> = strings.iter().map(String::as_str).collect();
+                let result = mlcs_substitution(&chains, &blosum62);
+                assert!(
+                    chains.iter().all(|s| is_subsequence(&result, s)),
+                    "{chains:?}"
+                );
+                assert_eq!(
+                    total_score(&result, &blosum62),
+                    brute_force_score(&chains, &blosum62),
+                    "{chains:?} {result}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn identity_matrix_gives_the_mlcs() {
+        let identity = identity();
+        for input in [
+            ["ABCBDAB", "BDCABA"].as_slice(),
+            &["ABCD", "ACBD", "BADC"],
+            &["AAB", "ABA", "BAA"],
+            &["", "ABC"],
+        ] {
+            let result = mlcs_substitution(input, &identity);
+            assert_eq!(result.len(), mlcs_approx(input).len(), "{input:?}");
+            assert!(input.iter().all(|s| is_subsequence(&result, s)));
+        }
+        let a: Vec<char> = "ABCBDAB".chars().collect();
+        let b: Vec<char> = "BDCABA".chars().collect();
+        assert_eq!(
+            score_matrix(&a, &b, Some(&identity)),
+            score_matrix(&a, &b, None)
+        );
+    }
+
+    #[test]
+    fn inputs_without_search() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        assert_eq!(mlcs_substitution(&[], &blosum62), "");
+        assert_eq!(mlcs_substitution(&["HEAGW"], &blosum62), "HEAGW");
+        assert_eq!(mlcs_substitution(&["HEAGW", "GW"], &blosum62), "GW");
+        assert_eq!(mlcs_substitution(&["HEA", "GW"], &blosum62), "");
+        // every fixture shares chars outside of the matrix, all scored as '*'
+        for fixture in &fixtures::FIXTURES {
+            let result = mlcs_substitution(fixture.input, &blosum62);
+            assert!(fixture.input.iter().all(|s| is_subsequence(&result, s)));
+        }
+    }
+}
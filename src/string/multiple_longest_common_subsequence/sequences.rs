@@ -0,0 +1,109 @@
+//! MLCS of sequences of any elements, e.g. the rows of a table or lists of tokens.
+//!
+//! The search only compares the elements of the strings and sorts the letters of their
+//! alphabet, so it runs on the rows as they are. The string entry points are this search
+//! over rows of chars.
+
+use super::{solve_chains_into, MlcsConfig};
+use std::hash::Hash;
+
+/// Finds a longest common subsequence of `rows`, compared element by element
+///
+/// On rows of chars, this is `mlcs_chars`: the letters of the search are sorted as the
+/// elements, which picks the same subsequence among the longest ones.
+///
+/// # Returns
+///
+/// The common subsequence, empty when there are no rows.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::mlcs_sequences;
+///
+/// let rows: Vec<Vec<u32>> = vec![vec![7, 1, 2, 3, 4], vec![1, 9, 3, 4, 7], vec![1, 3, 7, 4]];
+/// assert_eq!(mlcs_sequences(&rows), [1, 3, 4]);
+/// ```
+pub fn mlcs_sequences<T: Eq + Hash + Ord + Clone>(rows: &[Vec<T>]) -> Vec<T> {
+    let mut mlcs = vec![];
+    solve_chains_into(rows.to_vec(), &MlcsConfig::default(), &mut mlcs);
+    mlcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_chars};
+    use super::*;
+    use crate::dynamic_programming::is_subsequence_slice;
+
+    macro_rules! sequences_tests {
+        ($($name:ident: ($rows:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let rows: Vec<Vec<u32>> = $rows;
+                    let result = mlcs_sequences(&rows);
+                    assert_eq!(result, $expected);
+                    assert!(rows.iter().all(|row| is_subsequence_slice(&result, row)));
+                }
+            )*
+        };
+    }
+
+    sequences_tests! {
+        token_rows: (
+            vec![vec![7, 1, 2, 3, 4], vec![1, 9, 3, 4, 7], vec![1, 3, 7, 4]],
+            [1, 3, 4]
+        ),
+        equal_rows: (vec![vec![4, 2, 4, 2]; 3], [4, 2, 4, 2]),
+        large_tokens: (
+            vec![vec![u32::MAX, 0xd800, 5], vec![0xd800, u32::MAX, 5]],
+            [0xd800, 5]
+        ),
+        no_common_token: (vec![vec![1, 2], vec![3, 4]], []),
+        one_empty_row: (vec![vec![], vec![1, 2]], []),
+        single_row: (vec![vec![3, 1, 2]], [3, 1, 2]),
+        no_row: (vec![], []),
+    }
+
+    #[test]
+    fn rows_of_a_table() {
+        // the columns of each row, the common pattern being the shared cells in order
+        let rows = [
+            ["id", "name", "email", "age", "city"],
+            ["id", "email", "name", "city", "zip"],
+            ["uid", "id", "name", "age", "city"],
+        ];
+        let rows: Vec<Vec<&str>> = rows.iter().map(|row| row.to_vec()).collect();
+        assert_eq!(mlcs_sequences(&rows), ["id", "name", "city"]);
+    }
+
+    #[test]
+    fn more_common_elements_than_chars() {
+        // 1 114 112 distinct elements, more than the 1 112 064 chars
+        let n: u64 = 0x11_0000;
+        let row: Vec<u64> = (0..n).collect();
+        let longer: Vec<u64> = (0..n).flat_map(|x| [x, n]).collect();
+        assert_eq!(mlcs_sequences(&[row.clone(), longer]), row);
+        // and elements past the last char are searched as any other
+        let rows = vec![vec![n + 3, n, n + 1, n + 2], vec![n, n + 1, n + 3, n + 2]];
+        assert_eq!(mlcs_sequences(&rows), [n, n + 1, n + 2]);
+    }
+
+    #[test]
+    fn same_subsequence_as_the_chars() {
+        for fixture in fixtures::FIXTURES {
+            let rows: Vec<Vec<char>> = fixture.input.iter().map(|s| s.chars().collect()).collect();
+            let result: String = mlcs_sequences(&rows).into_iter().collect();
+            assert_eq!(result, mlcs_chars(fixture.input));
+            // the elements keep the order of their chars
+            let rows: Vec<Vec<u32>> = fixture
+                .input
+                .iter()
+                .map(|s| s.chars().map(|c| 1000 * u32::from(c)).collect())
+                .collect();
+            let tokens: Vec<u32> = result.chars().map(|c| 1000 * u32::from(c)).collect();
+            assert_eq!(mlcs_sequences(&rows), tokens);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! A context shared by several threads, queried and updated concurrently.
+//!
+//! `Context` is `Send` and `Sync`: as long as nothing updates it, scoped threads can share a
+//! `&Context`, which is how the `hda` module evaluates the heuristic in parallel. Once some
+//! threads record successors while others query the heuristic, the context has to be
+//! locked. `SharedContext` keeps it behind a `RwLock`: the queries only take the read lock
+//! and run together, and an update waits for the queries in progress to end.
+//!
+//! The preprocessing tables are never written after `Context::new`, so a query does not
+//! depend on the updates, and the lock only keeps the search maps consistent.
+
+use super::Context;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A `Context` shared by several threads
+///
+/// Cloning a `SharedContext` gives another handle on the same context.
+#[derive(Clone)]
+pub struct SharedContext(Arc<RwLock<Context>>);
+
+impl SharedContext {
+    pub fn new(ctx: Context) -> Self {
+        SharedContext(Arc::new(RwLock::new(ctx)))
+    }
+
+    /// The heuristic of the point p, see `Context::heuristic`
+    /// Only defined for points matching a char in every string
+    pub fn read_heuristic(&self, p: &[Option<usize>]) -> u64 {
+        // the tables read by the heuristic are never written, a poisoned lock holds them whole
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .heuristic(p)
+    }
+
+    /// Records the point q as a successor of the point p, computing its g and f
+    ///
+    /// # Panics
+    ///
+    /// Panics if another thread panicked while updating the context.
+    pub fn write_update(&self, p: Vec<Option<usize>>, q: Vec<Option<usize>>) {
+        self.0
+            .write()
+            .expect("a thread panicked while updating the context")
+            .update_suc(p, q);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn context_is_send_and_sync() {
+        assert_send_sync::<Context>();
+        assert_send_sync::<SharedContext>();
+    }
+
+    #[test]
+    fn concurrent_queries_and_updates() {
+        let input = ["ABCBDAB", "BDCABA", "BCDAAB"];
+        let serial = &Context::new(&input);
+        let starting_points = serial.get_starting_p();
+        let shared = SharedContext::new(Context::new(&input));
+
+        thread::scope(|scope| {
+            for p in &starting_points {
+                let shared = shared.clone();
+                scope.spawn(move || {
+                    shared.write_update(vec![None; 3], p.clone());
+                    assert_eq!(shared.read_heuristic(p), serial.heuristic(p));
+                });
+            }
+        });
+
+        let ctx = Arc::into_inner(shared.0).unwrap().into_inner().unwrap();
+        for p in &starting_points {
+            assert_eq!(ctx.g_of(p), 1);
+            assert_eq!(ctx.f_of(p), 1 + serial.heuristic(p));
+        }
+    }
+}
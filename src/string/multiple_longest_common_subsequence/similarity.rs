@@ -0,0 +1,325 @@
+//! Two-string LCS lengths, the matrix of these lengths over a set of strings, and the
+//! deduplication of a set of strings by their normalized LCS similarity.
+//!
+//! With only two strings, the length of the LCS is given by the classic dynamic
+//! programming, which is much cheaper than the MLCS-A* search, or by `lcs_bitmask` when
+//! the shorter string fits in a machine word.
+//! With the `rayon` feature, the pairs of `pairwise_similarity_matrix` are computed in parallel.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::string::lcs_bitmask;
+
+/// Computes the length, in chars, of the longest common subsequence of `a` and `b`
+///
+/// A shorter string of at most 128 chars is compared by `lcs_bitmask`, a word of bits at
+/// a time.
+///
+/// # Complexity
+///
+/// O(max(n, m)) time when min(n, m) <= 128, O(n * m) time and O(min(n, m)) space otherwise.
+pub fn mlcs_len_pair(a: &str, b: &str) -> usize {
+    lcs_bitmask(a, b)
+}
+
+/// Same as `mlcs_len_pair`, the row of the dynamic programming being kept in `scratch`
+///
+/// The strings are read char by char without being collected, so once `scratch` is as
+/// long as the shortest strings it is given, computing the length of many pairs does not
+/// allocate anymore. Its content is overwritten.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::lcs_len_pair_with_scratch;
+///
+/// let mut scratch = vec![];
+/// assert_eq!(lcs_len_pair_with_scratch("ABCBDAB", "BDCABA", &mut scratch), 4);
+/// assert_eq!(lcs_len_pair_with_scratch("kitten", "sitting", &mut scratch), 4);
+/// ```
+///
+/// # Complexity
+///
+/// O(n * m) time and O(min(n, m)) space.
+pub fn lcs_len_pair_with_scratch(a: &str, b: &str, scratch: &mut Vec<u64>) -> usize {
+    let (a_len, b_len) = (a.chars().count(), b.chars().count());
+    let (long, short, short_len) = if a_len >= b_len {
+        (a, b, b_len)
+    } else {
+        (b, a, a_len)
+    };
+
+    // row[j] is the LCS length of the prefix of `long` read so far and the first j chars
+    // of `short`
+    scratch.clear();
+    scratch.resize(short_len + 1, 0);
+    let row = scratch;
+    for c in long.chars() {
+        let mut diagonal = 0;
+        for (j, s) in short.chars().enumerate() {
+            let up = row[j + 1];
+            row[j + 1] = if c == s { diagonal + 1 } else { up.max(row[j]) };
+            diagonal = up;
+        }
+    }
+
+    row[short_len] as usize
+}
+
+/// Computes the classic forward LCS table of `a` and `b`: `matrix[i][j]` is the length
+/// of the LCS of the first i chars of `a` and the first j chars of `b`
+///
+/// It is the mirror of the suffix tables used by the MLCS heuristic, and holds the
+/// LCS length of `a` and `b` in its last cell.
+///
+/// # Complexity
+///
+/// O(n * m) time and space.
+pub fn lcs_prefix_matrix(a: &str, b: &str) -> Vec<Vec<u64>> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        for (j, &cb) in b.iter().enumerate() {
+            matrix[i + 1][j + 1] = if ca == cb {
+                matrix[i][j] + 1
+            } else {
+                matrix[i][j + 1].max(matrix[i + 1][j])
+            };
+        }
+    }
+    matrix
+}
+
+/// Computes the LCS similarity of `a` and `b`, i.e. the length of their LCS divided
+/// by the length of the longest of them, from 0 (no common char) to 1 (same strings)
+///
+/// Two empty strings are considered the same.
+pub fn mlcs_similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    mlcs_len_pair(a, b) as f64 / longest as f64
+}
+
+/// Selects representatives among `strings`, so that no two representatives have
+/// an `mlcs_similarity` above `threshold`
+///
+/// The selection is greedy, in input order: a string becomes a representative if it is
+/// not more similar than `threshold` to any of the representatives selected before it.
+/// The first string is thus always selected, and a string is replaced by none of
+/// its near-duplicates coming after it.
+///
+/// # Returns
+/// The indices of the representatives, in increasing order
+pub fn dedup_similar(strings: &[&str], threshold: f64) -> Vec<usize> {
+    let mut representatives: Vec<usize> = vec![];
+    for (i, s) in strings.iter().enumerate() {
+        if representatives
+            .iter()
+            .all(|&r| mlcs_similarity(strings[r], s) <= threshold)
+        {
+            representatives.push(i);
+        }
+    }
+    representatives
+}
+
+/// Computes the LCS length of every pair of strings
+///
+/// The matrix is symmetric and its diagonal holds the length of each string, so only
+/// the pairs above the diagonal are computed.
+pub fn pairwise_similarity_matrix(strings: &[&str]) -> Vec<Vec<usize>> {
+    let n = strings.len();
+    let pairs = (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j)));
+    let lcs = |(i, j): (usize, usize)| mlcs_len_pair(strings[i], strings[j]);
+
+    #[cfg(feature = "rayon")]
+    let lengths: Vec<usize> = pairs.collect::<Vec<_>>().into_par_iter().map(lcs).collect();
+    #[cfg(not(feature = "rayon"))]
+    let lengths: Vec<usize> = pairs.map(lcs).collect();
+
+    let mut matrix = vec![vec![0; n]; n];
+    let mut k = 0;
+    for i in 0..n {
+        matrix[i][i] = strings[i].chars().count();
+        for j in i + 1..n {
+            matrix[i][j] = lengths[k];
+            matrix[j][i] = lengths[k];
+            k += 1;
+        }
+    }
+
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::multiple_longest_common_subsequence;
+    use super::*;
+
+    macro_rules! len_pair_tests {
+        ($($name:ident: ($a:expr, $b:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(mlcs_len_pair($a, $b), $expected);
+                    assert_eq!(mlcs_len_pair($b, $a), $expected);
+                }
+            )*
+        };
+    }
+
+    len_pair_tests! {
+        both_empty: ("", "", 0),
+        one_empty: ("", "abc", 0),
+        same: ("abcdef", "abcdef", 6),
+        classic: ("ABCBDAB", "BDCABA", 4),
+        no_common: ("abc", "def", 0),
+        unicode: ("串用于测试", "串于测🚀试", 4),
+    }
+
+    #[test]
+    fn scratch_matches_the_full_table() {
+        let mut scratch = vec![7; 3];
+        for (a, b) in [
+            ("ABCBDAB", "BDCABA"),
+            ("", "abc"),
+            ("串用于测试", "用于串测试"),
+            ("kitten", "sitting"),
+            ("a", "aaaa"),
+            ("", ""),
+        ] {
+            let (m, n) = (a.chars().count(), b.chars().count());
+            let full = lcs_prefix_matrix(a, b)[m][n] as usize;
+            assert_eq!(lcs_len_pair_with_scratch(a, b, &mut scratch), full);
+            assert_eq!(lcs_len_pair_with_scratch(b, a, &mut scratch), full);
+        }
+    }
+
+    #[test]
+    fn scratch_is_not_reallocated() {
+        let mut scratch = Vec::with_capacity(64);
+        let buffer = scratch.as_ptr();
+        for (a, b) in [
+            ("ABCBDAB", "BDCABA"),
+            ("gxt#xayb", "abgt#ab"),
+            ("abc", "cba"),
+        ] {
+            lcs_len_pair_with_scratch(a, b, &mut scratch);
+            assert_eq!(scratch.as_ptr(), buffer);
+            assert_eq!(scratch.capacity(), 64);
+        }
+    }
+
+    #[test]
+    fn len_pair_matches_search() {
+        for (a, b) in [
+            ("gxt#xayb", "abgt#ab"),
+            ("qwertyuiop", "qwrtyiop"),
+            ("AGGTAB", "GXTXAYB"),
+        ] {
+            let mlcs = multiple_longest_common_subsequence(&vec![a, b]);
+            assert_eq!(mlcs_len_pair(a, b), mlcs.chars().count());
+        }
+    }
+
+    #[test]
+    fn similarity_matrix() {
+        let strings = ["ABCBDAB", "BDCABA", "", "串用ABC", "ABC"];
+        let matrix = pairwise_similarity_matrix(&strings);
+
+        assert_eq!(matrix.len(), strings.len());
+        for (i, s) in strings.iter().enumerate() {
+            assert_eq!(matrix[i][i], s.chars().count());
+            for (j, t) in strings.iter().enumerate() {
+                assert_eq!(matrix[i][j], matrix[j][i]);
+                if i != j {
+                    assert_eq!(matrix[i][j], mlcs_len_pair(s, t));
+                }
+            }
+        }
+        assert_eq!(matrix[0][1], 4);
+        assert_eq!(matrix[3][4], 3);
+    }
+
+    #[test]
+    fn similarity_matrix_of_nothing() {
+        assert_eq!(pairwise_similarity_matrix(&[]), Vec::<Vec<usize>>::new());
+        assert_eq!(pairwise_similarity_matrix(&["ab"]), vec![vec![2]]);
+    }
+
+    #[test]
+    fn similarity() {
+        assert_eq!(mlcs_similarity("", ""), 1.0);
+        assert_eq!(mlcs_similarity("", "abc"), 0.0);
+        assert_eq!(mlcs_similarity("abcd", "abcd"), 1.0);
+        assert_eq!(mlcs_similarity("abcd", "abxd"), 0.75);
+        assert_eq!(mlcs_similarity("ab", "abcd"), 0.5);
+        assert_eq!(mlcs_similarity("abc", "def"), 0.0);
+    }
+
+    #[test]
+    fn near_duplicates_collapse() {
+        let strings = [
+            "the quick brown fox",
+            "the quick brown fax",
+            "lorem ipsum dolor",
+            "the quick brwn fox",
+            "lorem ipsum dolor!",
+            "something else",
+        ];
+        assert_eq!(dedup_similar(&strings, 0.8), [0, 2, 5]);
+        // nothing is similar enough with a threshold of 1
+        assert_eq!(dedup_similar(&strings, 1.0), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn dedup_edge_cases() {
+        assert!(dedup_similar(&[], 0.5).is_empty());
+        assert_eq!(dedup_similar(&["abc", "abc", "abc"], 0.99), [0]);
+        assert_eq!(dedup_similar(&["", ""], 0.5), [0]);
+        // a negative threshold keeps only strings sharing no char with the others
+        assert_eq!(dedup_similar(&["ab", "bc", "de"], -1.0), [0]);
+        assert_eq!(dedup_similar(&["ab", "bc", "de"], 0.0), [0, 2]);
+    }
+
+    #[test]
+    fn prefix_matrix() {
+        let (a, b) = ("ABCBDAB", "BDCABA");
+        let matrix = lcs_prefix_matrix(a, b);
+        assert_eq!(matrix.len(), 8);
+        assert_eq!(matrix[0].len(), 7);
+        assert_eq!(matrix[7][6], 4);
+        assert_eq!(matrix[4][3], mlcs_len_pair("ABCB", "BDC") as u64);
+
+        for i in 0..matrix.len() {
+            for j in 0..matrix[i].len() {
+                if i == 0 || j == 0 {
+                    assert_eq!(matrix[i][j], 0);
+                    continue;
+                }
+                // one more char adds at most one to the LCS
+                assert!(matrix[i - 1][j] <= matrix[i][j]);
+                assert!(matrix[i][j - 1] <= matrix[i][j]);
+                assert!(matrix[i][j] <= matrix[i - 1][j - 1] + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_matrix_corner() {
+        for (a, b) in [
+            ("", ""),
+            ("", "abc"),
+            ("串用于测试", "串于测🚀试"),
+            ("qwerty", "wqetry"),
+        ] {
+            let matrix = lcs_prefix_matrix(a, b);
+            let corner = matrix[a.chars().count()][b.chars().count()];
+            assert_eq!(corner, mlcs_len_pair(a, b) as u64);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Every input of 1 to 3 strings of at most 3 chars over 2 letters, checked against the
+//! brute force of the `oracle` module.
+//!
+//! These inputs reach the boundaries of the preprocessing: strings of 0 or 1 char give
+//! suffix tables of a single row or column, letters missing from a string are removed from
+//! the alphabet, and the points matching the last char of a string have no successor.
+//! The suffix tables themselves are checked for every pair of strings of at most 4 chars.
+
+use super::fixtures::assert_valid_mlcs;
+use super::oracle::brute_force_mlcs_len;
+use super::{multiple_longest_common_subsequence, score_matrix, Context, MlcsConfig};
+use crate::dynamic_programming::longest_common_subsequence;
+
+/// The strings over {a, b} of 0 to 3 chars
+fn tiny_strings() -> Vec<String> {
+    strings_up_to(3)
+}
+
+/// The strings over {a, b} of 0 to `max_len` chars
+fn strings_up_to(max_len: usize) -> Vec<String> {
+    let mut strings = vec![String::new()];
+    for len in 1..=max_len {
+        for bits in 0..1 << len {
+            strings.push(
+                (0..len)
+                    .map(|i| if bits & (1 << i) == 0 { 'a' } else { 'b' })
+                    .collect(),
+            );
+        }
+    }
+    strings
+}
+
+fn check(input: &[&str]) {
+    let result = multiple_longest_common_subsequence(&input.to_vec());
+    assert_valid_mlcs(&result, input, 0);
+    assert_eq!(
+        result.chars().count(),
+        brute_force_mlcs_len(input),
+        "{input:?} gave {result:?}"
+    );
+}
+
+#[test]
+fn one_string() {
+    for s in &tiny_strings() {
+        check(&[s]);
+    }
+}
+
+#[test]
+fn two_strings() {
+    let strings = tiny_strings();
+    for s1 in &strings {
+        for s2 in &strings {
+            check(&[s1, s2]);
+        }
+    }
+}
+
+#[test]
+fn three_strings() {
+    let strings = tiny_strings();
+    for s1 in &strings {
+        for s2 in &strings {
+            for s3 in &strings {
+                check(&[s1, s2, s3]);
+            }
+        }
+    }
+}
+
+#[test]
+fn no_string() {
+    assert_eq!(multiple_longest_common_subsequence(&vec![]), "");
+}
+
+#[test]
+fn low_memory_reconstruction() {
+    let config = MlcsConfig {
+        low_memory_reconstruction: true,
+        ..MlcsConfig::default()
+    };
+    let strings = tiny_strings();
+    for s1 in &strings {
+        for s2 in &strings {
+            let input = [s1.as_str(), s2];
+            let expected = Context::new(&input).solve(&MlcsConfig::default());
+            assert_eq!(Context::new(&input).solve(&config), expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn suffix_tables_of_a_single_char() {
+    let (a, b, ab) = (['a'], ['b'], ['a', 'b']);
+    assert_eq!(score_matrix(&a, &a, None), [[1, 0], [0, 0]]);
+    assert_eq!(score_matrix(&a, &b, None), [[0, 0], [0, 0]]);
+    assert_eq!(score_matrix(&ab, &b, None), [[1, 0], [1, 0], [0, 0]]);
+    assert_eq!(score_matrix(&[], &ab, None), [[0, 0, 0]]);
+    assert_eq!(score_matrix(&ab, &[], None), [[0], [0], [0]]);
+}
+
+// the LCS length of the suffixes, each computed by its own full table
+fn reference_suffix_table(s1: &[char], s2: &[char]) -> Vec<Vec<u64>> {
+    (0..=s1.len())
+        .map(|i| {
+            (0..=s2.len())
+                .map(|j| {
+                    let a: String = s1[i..].iter().collect();
+                    let b: String = s2[j..].iter().collect();
+                    longest_common_subsequence(&a, &b).chars().count() as u64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn suffix_tables_of_every_length_up_to_four() {
+    let strings: Vec<Vec<char>> = strings_up_to(4)
+        .iter()
+        .map(|s| s.chars().collect())
+        .collect();
+    for s1 in &strings {
+        for s2 in &strings {
+            let matrix = score_matrix(s1, s2, None);
+            assert_eq!(matrix.len(), s1.len() + 1);
+            assert!(matrix.iter().all(|row| row.len() == s2.len() + 1));
+            assert_eq!(matrix, reference_suffix_table(s1, s2), "{s1:?} {s2:?}");
+        }
+    }
+}
+
+#[test]
+fn heuristic_at_the_end_of_the_strings() {
+    let ctx = Context::new(&["ab", "a"]);
+    // nothing can be matched after the last char of the second string
+    assert_eq!(ctx.heuristic(&[Some(0), Some(0)]), 0);
+    let ctx = Context::new(&["a", "a"]);
+    assert_eq!(ctx.heuristic(&[Some(0), Some(0)]), 0);
+    let ctx = Context::new(&["x"]);
+    assert_eq!(ctx.heuristic(&[Some(0)]), 0);
+}
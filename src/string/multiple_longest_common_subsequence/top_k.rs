@@ -0,0 +1,231 @@
+//! The k longest common subsequences which cannot be extended by appending a char.
+//!
+//! The search of `multiple_longest_common_subsequence` keeps a single parent per point,
+//! which loses every subsequence but the longest one reaching it. Here each state is a
+//! path from the root: its point, the char matched there and the state it comes from.
+//! Since a successor takes the next position of its letter in every string, a string
+//! leads to a single point, and two paths always spell two different strings.
+//!
+//! The states are expanded best first, by g + h as in `mlcs_must_include`. A state without
+//! successors is queued again as finished, with its g as f. The heuristic bounding from
+//! above the chars left to match, the finished states are taken from the queue by
+//! non-increasing length: the first one is a longest common subsequence, and the search
+//! goes on past it until k of them are found.
+//!
+//! The number of paths grows exponentially with the length of the strings, and the search
+//! expands all those which may be longer than the k-th result: it is meant for a small k.
+
+use super::{add_costs, Context};
+use std::collections::BinaryHeap;
+
+type Point = Vec<Option<usize>>;
+
+// the fields are compared in this order, the finished states coming first among equal f
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    f: u64,
+    finished: bool,
+    g: u64,
+    point: Point,
+    // the index of the path in `paths`
+    path: usize,
+}
+
+/// Finds the `k` longest common subsequences of `chains` to which no char can be appended
+///
+/// Shorter common subsequences are returned once the longest ones are exhausted, so that
+/// they rank the alternatives to a longest common subsequence. A subsequence to which a
+/// char can be appended is left out, being a prefix of a longer one.
+///
+/// # Returns
+///
+/// Up to `k` distinct subsequences with their number of chars, by non-increasing length
+/// and in order among equal lengths. Strings without a common char only have the empty
+/// subsequence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::top_k_common_subsequences;
+///
+/// assert_eq!(
+///     top_k_common_subsequences(&["abcd", "acbd"], 2),
+///     [("abd".to_string(), 3), ("acd".to_string(), 3)]
+/// );
+/// // "a" and "b" can both be followed by 'c', while nothing common comes after a 'c'
+/// assert_eq!(
+///     top_k_common_subsequences(&["abc", "bac"], 5),
+///     [("ac".to_string(), 2), ("bc".to_string(), 2), ("c".to_string(), 1)]
+/// );
+/// ```
+pub fn top_k_common_subsequences(chains: &[&str], k: usize) -> Vec<(String, usize)> {
+    let ctx = Context::new(chains);
+    // for each path, the path it extends and its last char, the root having neither
+    let mut paths: Vec<Option<(usize, char)>> = vec![None];
+    let mut queue = BinaryHeap::new();
+    queue.push(Node {
+        f: 0,
+        finished: false,
+        g: 0,
+        point: vec![None; ctx.d],
+        path: 0,
+    });
+
+    let mut found = vec![];
+    while found.len() < k {
+        let Some(node) = queue.pop() else {
+            break;
+        };
+        if node.finished {
+            found.push((spell(&paths, node.path), node.g as usize));
+            continue;
+        }
+        let successors = if node.path == 0 {
+            ctx.get_starting_p()
+        } else {
+            ctx.get_successors(&node.point)
+        };
+        if successors.is_empty() {
+            queue.push(Node {
+                f: node.g,
+                finished: true,
+                ..node
+            });
+            continue;
+        }
+        let g = add_costs(node.g, 1);
+        for q in successors {
+            let Some(ch) = ctx.matched_char(&q) else {
+                continue;
+            };
+            paths.push(Some((node.path, ch)));
+            queue.push(Node {
+                f: add_costs(g, ctx.heuristic(&q)),
+                finished: false,
+                g,
+                point: q,
+                path: paths.len() - 1,
+            });
+        }
+    }
+    found.sort_by(|(s1, len1), (s2, len2)| len2.cmp(len1).then_with(|| s1.cmp(s2)));
+    found
+}
+
+// ascend back up the paths to form the common subsequence
+fn spell(paths: &[Option<(usize, char)>], mut path: usize) -> String {
+    let mut common_subsequence = vec![];
+    while let Some((parent, ch)) = paths[path] {
+        common_subsequence.push(ch);
+        path = parent;
+    }
+    common_subsequence.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, mlcs_exact};
+    use super::*;
+    use crate::dynamic_programming::is_subsequence;
+    use std::collections::BTreeSet;
+
+    // the lengths of the common subsequences no char can be appended to, longest first,
+    // every subsequence of the first string being enumerated
+    fn brute_force_lengths(chains: &[&str]) -> Vec<usize> {
+        let first: Vec<char> = chains[0].chars().collect();
+        let common: BTreeSet<String> = (0..1u32 << first.len())
+            .map(|mask| {
+                (0..first.len())
+                    .filter(|&i| mask >> i & 1 == 1)
+                    .map(|i| first[i])
+                    .collect::<String>()
+            })
+            .filter(|sub| chains.iter().all(|s| is_subsequence(sub, s)))
+            .collect();
+        let mut lengths: Vec<usize> = common
+            .iter()
+            .filter(|sub| !first.iter().any(|&c| common.contains(&format!("{sub}{c}"))))
+            .map(|sub| sub.chars().count())
+            .collect();
+        lengths.sort_by(|a, b| b.cmp(a));
+        lengths
+    }
+
+    fn check(chains: &[&str], k: usize) -> Vec<(String, usize)> {
+        let found = top_k_common_subsequences(chains, k);
+        let expected = brute_force_lengths(chains);
+        let lengths: Vec<usize> = found.iter().map(|(_, len)| *len).collect();
+        assert_eq!(lengths, expected[..k.min(expected.len())], "{chains:?}");
+        let distinct: BTreeSet<&String> = found.iter().map(|(s, _)| s).collect();
+        assert_eq!(distinct.len(), found.len(), "{chains:?}");
+        for (sub, len) in &found {
+            assert_eq!(sub.chars().count(), *len);
+            fixtures::assert_valid_mlcs(sub, chains, *len);
+        }
+        found
+    }
+
+    #[test]
+    fn three_best_by_non_increasing_length() {
+        let chains = ["ABCBDAB", "BDCABA", "BCADBA"];
+        let found = check(&chains, 3);
+        assert_eq!(found.len(), 3);
+        assert!(found.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(found[0].1, mlcs_exact(&chains).chars().count());
+    }
+
+    #[test]
+    fn shorter_subsequences_past_the_longest() {
+        // after "abd" and "acd", every common subsequence ending with the 'd' cannot be
+        // extended either
+        let found = check(&["abcd", "acbd"], 6);
+        let found: Vec<&str> = found.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(found, ["abd", "acd", "ad", "bd", "cd", "d"]);
+        // "ab" and "ac" are followed by a common 'x' or 'd', "abx" and "acx" are not
+        let found = check(&["abcxd", "acbdx"], 4);
+        let found: Vec<&str> = found.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(found, ["abd", "abx", "acd", "acx"]);
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(top_k_common_subsequences(&["abc", "abd"], 0), []);
+        assert_eq!(
+            top_k_common_subsequences(&["abc", "xyz"], 3),
+            [(String::new(), 0)]
+        );
+        assert_eq!(top_k_common_subsequences(&[], 3), [(String::new(), 0)]);
+        // the subsequences of a single string ending with its last char
+        let found = top_k_common_subsequences(&["abc"], 5);
+        let found: Vec<&str> = found.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(found, ["abc", "ac", "bc", "c"]);
+        assert_eq!(
+            top_k_common_subsequences(&["串用于测试", "用于串测试"], 1),
+            [("用于测试".to_string(), 4)]
+        );
+        check(&["串用于测试", "用于串测试"], 4);
+    }
+
+    #[test]
+    fn random_strings_match_the_brute_force() {
+        for seed in 1..=200 {
+            let d = 2 + seed % 2;
+            let mut strings = fixtures::synthetic(d, 9, 1 + (seed / 2 % 4) as u8, seed as u64);
+            // 1 to 9 chars, the lengths shifting with the seed
+            for (j, s) in strings.iter_mut().enumerate() {
+                s.truncate(1 + (seed + 7 * j) % 9);
+            }
+            let chains: Vec<&str> = strings.iter().map(String::as_str).collect();
+            check(&chains, 1 + seed / 3 % 6);
+        }
+    }
+
+    #[test]
+    fn longest_is_as_long_as_the_exact_search() {
+        for fixture in fixtures::FIXTURES {
+            let found = top_k_common_subsequences(fixture.input, 2);
+            assert_eq!(found[0].1, fixture.expected_len, "{}", fixture.name);
+            assert!(found[1].1 <= found[0].1);
+        }
+    }
+}
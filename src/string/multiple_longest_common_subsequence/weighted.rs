@@ -0,0 +1,197 @@
+//! MLCS where some positions of the strings matter more than others.
+//!
+//! Each char of each string has a weight, its importance. A char of the subsequence is
+//! matched at one position of every string, and is worth the smallest of their weights:
+//! it only counts as much as it matters in all of them. The search maximizes the total
+//! worth of the subsequence instead of its length, through the same weighted suffix tables
+//! and heuristic as the run-collapsed search, a match in a suffix table of two strings being
+//! worth the smallest of the two weights.
+//!
+//! The search works on integers, so the weights are rounded to multiples of
+//! 1 / `WEIGHT_SCALE`.
+
+use super::{Context, MlcsConfig, MlcsError};
+
+const WEIGHT_SCALE: f64 = 1_000_000.0;
+
+/// Finds a common subsequence of the strings maximizing the weights of its chars
+///
+/// `weights[i][j]` is the importance of the j-th char of `chains[i]`. A char of the
+/// subsequence is worth the smallest weight of the chars it is matched with, and the
+/// subsequence the sum of the worth of its chars. With all the weights equal, this is a
+/// longest common subsequence.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::weighted_multiple_lcs;
+///
+/// // "ab" is longer, but 'c' matters more than 'a' and 'b' together
+/// let weights = vec![vec![1.0, 1.0, 5.0], vec![5.0, 1.0, 1.0]];
+/// assert_eq!(weighted_multiple_lcs(&["abc", "cab"], &weights), Ok("c".to_string()));
+/// ```
+///
+/// # Errors
+///
+/// * `MlcsError::WeightsCount` if there is not one weight vector per string
+/// * `MlcsError::WeightsLength` if a string does not have one weight per char
+/// * `MlcsError::InvalidWeight` if a weight is negative, infinite or NaN
+/// * `MlcsError::WeightsTooLarge` if the weights of a string add up to more than `u64`
+///   holds once scaled, the worth of a subsequence being bounded by that sum
+pub fn weighted_multiple_lcs(chains: &[&str], weights: &[Vec<f64>]) -> Result<String, MlcsError> {
+    if weights.len() != chains.len() {
+        return Err(MlcsError::WeightsCount {
+            strings: chains.len(),
+            weights: weights.len(),
+        });
+    }
+    let chains: Vec<Vec<char>> = chains.iter().map(|s| s.chars().collect()).collect();
+    let mut scaled: Vec<Vec<u64>> = Vec::with_capacity(weights.len());
+    for (string, (chain, w)) in chains.iter().zip(weights).enumerate() {
+        if w.len() != chain.len() {
+            return Err(MlcsError::WeightsLength {
+                string,
+                chars: chain.len(),
+                weights: w.len(),
+            });
+        }
+        let mut row = Vec::with_capacity(w.len());
+        let mut total: u64 = 0;
+        for (position, &weight) in w.iter().enumerate() {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(MlcsError::InvalidWeight { string, position });
+            }
+            let scaled = (weight * WEIGHT_SCALE).round();
+            // the cast saturates, a weight that large cannot be added to anything
+            if scaled >= u64::MAX as f64 {
+                return Err(MlcsError::WeightsTooLarge { string });
+            }
+            let scaled = scaled as u64;
+            total = total
+                .checked_add(scaled)
+                .ok_or(MlcsError::WeightsTooLarge { string })?;
+            row.push(scaled);
+        }
+        scaled.push(row);
+    }
+
+    Ok(Context::from_weights(chains, scaled, false).solve(&MlcsConfig::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::multiple_longest_common_subsequence;
+    use super::*;
+
+    fn uniform(chains: &[&str], weight: f64) -> Vec<Vec<f64>> {
+        chains
+            .iter()
+            .map(|s| vec![weight; s.chars().count()])
+            .collect()
+    }
+
+    #[test]
+    fn equal_weights_give_a_longest_subsequence() {
+        for input in [
+            ["ABCBDAB", "BDCABA"].as_slice(),
+            &["abcab", "bacba", "cabab"],
+            &["串用于测试", "用于串测试", "测试串用于"],
+            &["abc", "def"],
+        ] {
+            let expected = multiple_longest_common_subsequence(&input.to_vec());
+            for weight in [1.0, 0.25, 7.0] {
+                let result = weighted_multiple_lcs(input, &uniform(input, weight)).unwrap();
+                assert_eq!(
+                    result.chars().count(),
+                    expected.chars().count(),
+                    "{input:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn important_positions_are_kept() {
+        let input = ["abc", "acb"];
+        // "ab" and "ac" are both longest
+        let favour_c = vec![vec![1.0, 1.0, 2.0], vec![1.0, 2.0, 1.0]];
+        assert_eq!(weighted_multiple_lcs(&input, &favour_c), Ok("ac".into()));
+        let favour_b = vec![vec![1.0, 2.0, 1.0], vec![1.0, 1.0, 2.0]];
+        assert_eq!(weighted_multiple_lcs(&input, &favour_b), Ok("ab".into()));
+    }
+
+    #[test]
+    fn a_char_is_worth_its_smallest_weight() {
+        let input = ["abc", "cab"];
+        // 'c' only matters in the first string
+        let weights = vec![vec![1.0, 1.0, 5.0], vec![1.0, 1.0, 1.0]];
+        assert_eq!(weighted_multiple_lcs(&input, &weights), Ok("ab".into()));
+        let weights = vec![vec![1.0, 1.0, 5.0], vec![5.0, 1.0, 1.0]];
+        assert_eq!(weighted_multiple_lcs(&input, &weights), Ok("c".into()));
+    }
+
+    #[test]
+    fn zero_weights_are_not_needed() {
+        let input = ["xaybz", "axbyz", "abxyz"];
+        let mut weights = uniform(&input, 0.0);
+        // only the final 'z' matters
+        for w in &mut weights {
+            w[4] = 1.0;
+        }
+        let result = weighted_multiple_lcs(&input, &weights).unwrap();
+        assert!(result.ends_with('z'), "{result}");
+    }
+
+    #[test]
+    fn single_string() {
+        let weights = vec![vec![1.0, 0.5, 2.0]];
+        assert_eq!(weighted_multiple_lcs(&["abc"], &weights), Ok("abc".into()));
+        assert_eq!(weighted_multiple_lcs(&[], &[]), Ok(String::new()));
+    }
+
+    #[test]
+    fn invalid_weights() {
+        let input = ["abc", "ab"];
+        assert_eq!(
+            weighted_multiple_lcs(&input, &[vec![1.0; 3]]),
+            Err(MlcsError::WeightsCount {
+                strings: 2,
+                weights: 1
+            })
+        );
+        assert_eq!(
+            weighted_multiple_lcs(&input, &[vec![1.0; 3], vec![1.0; 3]]),
+            Err(MlcsError::WeightsLength {
+                string: 1,
+                chars: 2,
+                weights: 3
+            })
+        );
+        for weight in [-1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                weighted_multiple_lcs(&input, &[vec![1.0, weight, 1.0], vec![1.0; 2]]),
+                Err(MlcsError::InvalidWeight {
+                    string: 0,
+                    position: 1
+                })
+            );
+        }
+        assert_eq!(
+            weighted_multiple_lcs(&input, &[vec![1.0; 3], vec![1.0, 1e300]]),
+            Err(MlcsError::WeightsTooLarge { string: 1 })
+        );
+        assert_eq!(
+            weighted_multiple_lcs(&input, &[vec![1e13; 3], vec![1.0; 2]]),
+            Err(MlcsError::WeightsTooLarge { string: 0 })
+        );
+        // weights are counted in chars, not bytes
+        let weights = vec![vec![1.0; 2], vec![1.0; 2]];
+        assert_eq!(
+            weighted_multiple_lcs(&["串用", "用串"], &weights)
+                .unwrap()
+                .chars()
+                .count(),
+            1
+        );
+    }
+}
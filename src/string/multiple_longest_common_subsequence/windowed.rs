@@ -0,0 +1,135 @@
+//! MLCS whose consecutive chars are close in every string, e.g. to align time series where
+//! two matched samples should not be far apart.
+//!
+//! The search is the MLCS-A* search, the successors of a point being dropped when one of
+//! their coordinates steps more than `max_gap` positions past the point. The first char
+//! of the subsequence has no predecessor, so it may be anywhere.
+
+use super::{Context, MlcsConfig};
+
+/// Finds a long common subsequence of `chains` whose consecutive chars are at most
+/// `max_gap` positions apart in every string
+///
+/// Consecutive positions are 1 apart, so with a `max_gap` of 1 the subsequence is a common
+/// substring, and with 0 it has at most one char. A `max_gap` as long as the strings
+/// changes nothing.
+///
+/// The constraint can only shorten the result, compared to the unconstrained
+/// `multiple_longest_common_subsequence`. Moreover the search only steps to the next
+/// occurrence of each letter, which is the best step without a gap, but not always with
+/// one: a later occurrence is closer to the chars after it. So the result may even be
+/// shorter than the longest subsequence satisfying the gap, e.g. with a `max_gap` of 1,
+/// "ab" in "aab" and "ab" is only found as "a" or "b".
+///
+/// # Returns
+///
+/// A common subsequence satisfying the gap, empty when there are no strings.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::windowed_mlcs;
+///
+/// // the 'a' of the first string is 4 chars before its 'b'
+/// assert_eq!(windowed_mlcs(&["axxxbc", "abc"], 1), "bc");
+/// assert_eq!(windowed_mlcs(&["axxxbc", "abc"], 4), "abc");
+/// ```
+pub fn windowed_mlcs(chains: &[&str], max_gap: usize) -> String {
+    let mut ctx = Context::new(chains);
+    ctx.max_gap = Some(max_gap);
+    ctx.solve(&MlcsConfig::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{fixtures, multiple_longest_common_subsequence};
+    use super::*;
+
+    // whether sub occurs in s with at most max_gap positions between consecutive chars
+    fn fits_in(sub: &str, s: &str, max_gap: usize) -> bool {
+        let s: Vec<char> = s.chars().collect();
+        // ends holds the positions where the prefix of sub read so far can end
+        let mut ends: Vec<usize> = (0..s.len()).collect();
+        for (k, c) in sub.chars().enumerate() {
+            ends = (0..s.len())
+                .filter(|&j| {
+                    s[j] == c && (k == 0 || ends.iter().any(|&e| e < j && j - e <= max_gap))
+                })
+                .collect();
+        }
+        sub.is_empty() || !ends.is_empty()
+    }
+
+    macro_rules! windowed_tests {
+        ($($name:ident: ($input:expr, $max_gap:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let input: Vec<&str> = $input.to_vec();
+                    let result = windowed_mlcs(&input, $max_gap);
+                    assert_eq!(result, $expected);
+                    assert!(input.iter().all(|s| fits_in(&result, s, $max_gap)));
+                }
+             )*
+        };
+    }
+
+    windowed_tests! {
+        tight_gap: (["axxxbc", "abc"], 1, "bc"),
+        loose_gap: (["axxxbc", "abc"], 4, "abc"),
+        tight_gap_unicode: (["串xx用于", "串用于"], 1, "用于"),
+        loose_gap_unicode: (["串xx用于", "串用于"], 3, "串用于"),
+        common_substring: (["xabcyde", "abzcde", "zzabcde"], 1, "ab"),
+        no_common_char: (["abc", "def"], 2, ""),
+        one_empty: (["", "abc"], 2, ""),
+        no_input: ([] as [&str; 0], 2, ""),
+    }
+
+    #[test]
+    fn no_gap_at_all() {
+        let result = windowed_mlcs(&["abc", "abc"], 0);
+        assert_eq!(result.chars().count(), 1);
+        fixtures::assert_valid_mlcs(&result, &["abc"], 1);
+    }
+
+    #[test]
+    fn only_the_next_occurrences_are_tried() {
+        // "ab" fits, taking the second 'a' of "aab"
+        assert!(fits_in("ab", "aab", 1));
+        assert_eq!(windowed_mlcs(&["aab", "ab"], 1).chars().count(), 1);
+    }
+
+    #[test]
+    fn a_gap_as_long_as_the_strings_changes_nothing() {
+        for fixture in fixtures::FIXTURES {
+            let input = fixture.input.to_vec();
+            let max_gap = input.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+            assert_eq!(
+                windowed_mlcs(&input, max_gap),
+                multiple_longest_common_subsequence(&input)
+            );
+        }
+    }
+
+    #[test]
+    fn wider_gaps_give_longer_subsequences() {
+        let input = ["gxtxayb", "abgtab", "gyaytahjb", "gyayjjjtab"];
+        let mut previous = 0;
+        for max_gap in 0..10 {
+            let result = windowed_mlcs(&input, max_gap);
+            assert!(
+                input.iter().all(|s| fits_in(&result, s, max_gap)),
+                "{max_gap}"
+            );
+            let len = result.chars().count();
+            assert!(len >= previous, "{max_gap}");
+            previous = len;
+        }
+        assert_eq!(
+            previous,
+            multiple_longest_common_subsequence(&input.to_vec())
+                .chars()
+                .count()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Periods of a string.
+//!
+//! An integer `p` with `1 <= p <= n` is a period of a string `s` of length `n` when
+//! `s[i] == s[i + p]` for every valid `i`. Equivalently, `s` has a border (a proper prefix
+//! which is also a suffix) of length `n - p`, so all the periods can be read from the
+//! failure function used by Knuth-Morris-Pratt.
+//!
+//! Lengths and periods are counted in chars, so all functions work on arbitrary Unicode text.
+
+/// Computes the failure function of `chars`: `failure[i]` is the length of the longest
+/// proper border of `chars[..=i]`.
+fn failure_function(chars: &[char]) -> Vec<usize> {
+    let mut failure = vec![0; chars.len()];
+    let mut border = 0;
+
+    for i in 1..chars.len() {
+        while border > 0 && chars[i] != chars[border] {
+            border = failure[border - 1];
+        }
+        if chars[i] == chars[border] {
+            border += 1;
+        }
+        failure[i] = border;
+    }
+
+    failure
+}
+
+/// Returns the smallest period of `s`, or 0 for an empty string.
+///
+/// # Complexity
+///
+/// O(n) time and space.
+pub fn minimal_period(s: &str) -> usize {
+    let chars: Vec<char> = s.chars().collect();
+    match failure_function(&chars).last() {
+        Some(&border) => chars.len() - border,
+        None => 0,
+    }
+}
+
+/// Checks if `s` has a period smaller than its length, i.e. if it has a non-empty border.
+///
+/// Note that the period does not have to divide the length: `"abcab"` is periodic
+/// with period 3.
+pub fn is_periodic(s: &str) -> bool {
+    let len = s.chars().count();
+    len > 0 && minimal_period(s) < len
+}
+
+/// Returns all the periods of `s` in increasing order, its length included.
+///
+/// The result is empty for an empty string.
+pub fn all_periods(s: &str) -> Vec<usize> {
+    let chars: Vec<char> = s.chars().collect();
+    let failure = failure_function(&chars);
+    let n = chars.len();
+
+    // walking the chain of borders from the longest one gives the periods in increasing order
+    let mut periods = Vec::new();
+    let mut border = failure.last().copied().unwrap_or(0);
+    while border > 0 {
+        periods.push(n - border);
+        border = failure[border - 1];
+    }
+    if n > 0 {
+        periods.push(n);
+    }
+
+    periods
+}
+
+/// Returns the shortest string whose repetition produces `s`.
+///
+/// This is the prefix of length `minimal_period(s)` when the minimal period divides the
+/// length of `s`, and `s` itself otherwise.
+pub fn primitive_root(s: &str) -> &str {
+    let len = s.chars().count();
+    let period = minimal_period(s);
+    if period == 0 || !len.is_multiple_of(period) {
+        return s;
+    }
+    let end = s.char_indices().nth(period).map_or(s.len(), |(i, _)| i);
+    &s[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_periods(s: &str) -> Vec<usize> {
+        let chars: Vec<char> = s.chars().collect();
+        (1..=chars.len())
+            .filter(|&p| (0..chars.len() - p).all(|i| chars[i] == chars[i + p]))
+            .collect()
+    }
+
+    macro_rules! test_periodicity {
+        ($($name:ident: $inputs:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s, period, periodic, periods, root) = $inputs;
+                    assert_eq!(minimal_period(s), period);
+                    assert_eq!(is_periodic(s), periodic);
+                    assert_eq!(all_periods(s), periods);
+                    assert_eq!(primitive_root(s), root);
+                }
+            )*
+        }
+    }
+
+    test_periodicity! {
+        empty: ("", 0, false, vec![], ""),
+        single_char: ("a", 1, false, vec![1], "a"),
+        same_chars: ("aaaa", 1, true, vec![1, 2, 3, 4], "a"),
+        full_repetition: ("abcabcabc", 3, true, vec![3, 6, 9], "abc"),
+        partial_repetition: ("abcab", 3, true, vec![3, 5], "abcab"),
+        aperiodic: ("abcd", 4, false, vec![4], "abcd"),
+        nested_borders: ("abaababaab", 5, true, vec![5, 8, 10], "abaab"),
+        unicode: ("串用串用串用", 2, true, vec![2, 4, 6], "串用"),
+        emoji: ("🏠a🏠", 2, true, vec![2, 3], "🏠a🏠"),
+    }
+
+    #[test]
+    fn periods_match_naive() {
+        for s in [
+            "aabaabaa",
+            "abababb",
+            "xyzxyzx",
+            "zzzyzzz",
+            "abcabdabcabc",
+            "a",
+        ] {
+            assert_eq!(all_periods(s), naive_periods(s), "{s}");
+            assert_eq!(minimal_period(s), naive_periods(s)[0], "{s}");
+        }
+    }
+
+    #[test]
+    fn root_repetition_gives_the_string() {
+        for s in ["abab", "aaaaaa", "abcabcab", "xyxyxyxy", "串串串"] {
+            let root = primitive_root(s);
+            let times = s.len() / root.len();
+            assert_eq!(root.repeat(times), s);
+            assert_eq!(primitive_root(root), root);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Progressive multiple sequence alignment, guided by the LCS distances of the strings.
+//!
+//! The distance between two strings is the number of their chars left out of their LCS,
+//! read from `pairwise_similarity_matrix`. The guide tree is built from these distances by
+//! `upgma`, which merges the closest clusters first. Each internal node of the tree aligns
+//! the alignments of its two subtrees, column against column, so that the closest strings
+//! are aligned first and the gaps they get are kept afterwards.
+//!
+//! Two columns score the number of pairs of equal chars they hold, one in each column,
+//! gaps excluded, and gaps are free: aligning two strings lines up one of their LCS.
+//!
+//! Positions are counted in chars. The gap is `'-'`, which never scores, including when
+//! it occurs in the strings.
+//!
+//! References: [Multiple sequence alignment](https://en.wikipedia.org/wiki/Multiple_sequence_alignment),
+//! [UPGMA](https://en.wikipedia.org/wiki/UPGMA)
+
+use crate::string::{pairwise_similarity_matrix, upgma, NewickTree};
+use std::collections::HashMap;
+
+const GAP: char = '-';
+
+// the aligned rows of some of the strings, with their indices in the input
+struct Cluster {
+    members: Vec<usize>,
+    rows: Vec<Vec<char>>,
+}
+
+/// Aligns `chains` by inserting gaps, following the UPGMA tree of their LCS distances
+///
+/// # Returns
+///
+/// The strings in input order, padded with `'-'` to the same number of chars. Removing
+/// the gaps of a string gives it back.
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::progressive_alignment;
+///
+/// assert_eq!(
+///     progressive_alignment(&["GATTACA", "GATACA", "CATTAC"]),
+///     ["GATTACA", "GA-TACA", "CATTAC-"]
+/// );
+/// ```
+///
+/// # Complexity
+///
+/// O(n^2) LCS of pairs of strings and O(n^3) steps of UPGMA, for n strings, then one
+/// dynamic programming over the columns of the two alignments of each internal node.
+pub fn progressive_alignment(chains: &[&str]) -> Vec<String> {
+    let n = chains.len();
+    if n == 0 {
+        return vec![];
+    }
+    // lcs[i][i] is the length of the i-th string
+    let lcs = pairwise_similarity_matrix(chains);
+    let distances: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| (lcs[i][i] + lcs[j][j] - 2 * lcs[i][j]) as f64)
+                .collect()
+        })
+        .collect();
+    // the leaves of the guide tree are the indices of the strings
+    let labels: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+    let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let cluster = align_tree(&upgma(&distances, &labels), chains);
+
+    let mut aligned = vec![String::new(); n];
+    for (i, row) in cluster.members.into_iter().zip(cluster.rows) {
+        aligned[i] = row.into_iter().collect();
+    }
+    aligned
+}
+
+// aligns the strings at the leaves of tree, each subtree being aligned first
+fn align_tree(tree: &NewickTree, chains: &[&str]) -> Cluster {
+    match tree {
+        NewickTree::Leaf(label) => {
+            let i: usize = label.parse().expect("the leaves are indices");
+            Cluster {
+                members: vec![i],
+                rows: vec![chains[i].chars().collect()],
+            }
+        }
+        NewickTree::Internal { left, right, .. } => {
+            align_clusters(align_tree(left, chains), align_tree(right, chains))
+        }
+    }
+}
+
+// aligns the columns of two alignments, keeping their gaps, the rows of first coming first
+fn align_clusters(first: Cluster, second: Cluster) -> Cluster {
+    let (x, y) = (columns(&first.rows), columns(&second.rows));
+    let (n, m) = (x.len(), y.len());
+    // score[i][j] is the best score of the first i columns of x against the first j of y
+    let mut score = vec![vec![0u64; m + 1]; n + 1];
+    for i in 1..=n {
+        for j in 1..=m {
+            score[i][j] = (score[i - 1][j - 1] + pairs(&x[i - 1], &y[j - 1]))
+                .max(score[i - 1][j])
+                .max(score[i][j - 1]);
+        }
+    }
+
+    // the column of x and of y at each column of the alignment, None for a column of gaps,
+    // two columns being put together whenever that gives the score
+    let mut steps = vec![];
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && score[i][j] == score[i - 1][j - 1] + pairs(&x[i - 1], &y[j - 1]) {
+            steps.push((Some(i - 1), Some(j - 1)));
+            (i, j) = (i - 1, j - 1);
+        } else if i > 0 && score[i][j] == score[i - 1][j] {
+            steps.push((Some(i - 1), None));
+            i -= 1;
+        } else {
+            steps.push((None, Some(j - 1)));
+            j -= 1;
+        }
+    }
+    steps.reverse();
+
+    let rows = first
+        .rows
+        .iter()
+        .map(|row| spread(row, steps.iter().map(|step| step.0)))
+        .chain(
+            second
+                .rows
+                .iter()
+                .map(|row| spread(row, steps.iter().map(|step| step.1))),
+        )
+        .collect();
+    let mut members = first.members;
+    members.extend(second.members);
+    Cluster { members, rows }
+}
+
+// the chars of row at the given columns, a gap for None
+fn spread(row: &[char], columns: impl Iterator<Item = Option<usize>>) -> Vec<char> {
+    columns.map(|c| c.map_or(GAP, |c| row[c])).collect()
+}
+
+// the number of rows having each char in each column, the rows being of the same length
+fn columns(rows: &[Vec<char>]) -> Vec<HashMap<char, u64>> {
+    let mut columns = vec![HashMap::new(); rows[0].len()];
+    for row in rows {
+        for (column, &c) in columns.iter_mut().zip(row) {
+            *column.entry(c).or_insert(0) += 1;
+        }
+    }
+    columns
+}
+
+// the number of pairs of equal chars, one in each column, gaps excluded
+fn pairs(x: &HashMap<char, u64>, y: &HashMap<char, u64>) -> u64 {
+    x.iter()
+        .filter(|&(&c, _)| c != GAP)
+        .map(|(c, count)| count * y.get(c).unwrap_or(&0))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::mlcs_len_pair;
+    use crate::string::random_strings::Xorshift;
+
+    fn degap(s: &str) -> String {
+        s.chars().filter(|&c| c != GAP).collect()
+    }
+
+    // every row has the same length, gives its string back, and no column is only gaps
+    fn check(chains: &[&str], aligned: &[String]) {
+        assert_eq!(aligned.len(), chains.len());
+        let len = aligned.first().map_or(0, |row| row.chars().count());
+        for (s, row) in chains.iter().zip(aligned) {
+            assert_eq!(row.chars().count(), len, "{aligned:?}");
+            assert_eq!(degap(row), *s, "{aligned:?}");
+        }
+        let rows: Vec<Vec<char>> = aligned.iter().map(|row| row.chars().collect()).collect();
+        for column in 0..len {
+            assert!(rows.iter().any(|row| row[column] != GAP), "{aligned:?}");
+        }
+    }
+
+    macro_rules! alignment_tests {
+        ($($name:ident: ($chains:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let chains: &[&str] = &$chains;
+                    let aligned = progressive_alignment(chains);
+                    assert_eq!(aligned, $expected);
+                    check(chains, &aligned);
+                }
+            )*
+        };
+    }
+
+    alignment_tests! {
+        no_string: ([], [] as [&str; 0]),
+        single: (["GATTACA"], ["GATTACA"]),
+        identical: (["ACGT", "ACGT", "ACGT"], ["ACGT", "ACGT", "ACGT"]),
+        deletion: (["ACGT", "AGT"], ["ACGT", "A-GT"]),
+        empty_string: (["abc", ""], ["abc", "---"]),
+        all_empty: (["", "", ""], ["", "", ""]),
+        no_common_char: (["ab", "cd"], ["ab", "cd"]),
+        unicode: (["串用于测试", "用于串测试"], ["串用于-测试", "-用于串测试"]),
+        dna: (
+            ["GATTACA", "GATACA", "CATTAC"],
+            ["GATTACA", "GA-TACA", "CATTAC-"]
+        ),
+    }
+
+    #[test]
+    fn closest_strings_are_aligned_first() {
+        // the two first strings only differ by a char, the third shares the end of both
+        let chains = ["ABCDEFGH", "ABCDXFGH", "EFGH"];
+        let aligned = progressive_alignment(&chains);
+        assert_eq!(aligned, ["ABCDEFGH", "ABCDXFGH", "----EFGH"]);
+        check(&chains, &aligned);
+    }
+
+    #[test]
+    fn pairs_line_up_their_lcs() {
+        let mut rng = Xorshift::default();
+        for _ in 0..300 {
+            let letters = 1 + rng.below(4);
+            let strings: Vec<String> = (0..=rng.below(5))
+                .map(|_| rng.string_below(15, letters))
+                .collect();
+            let chains: Vec<&str> = strings.iter().map(String::as_str).collect();
+            let aligned = progressive_alignment(&chains);
+            check(&chains, &aligned);
+            if let [a, b] = chains[..] {
+                // the columns where both strings have the same char are an LCS
+                let matches = aligned[0]
+                    .chars()
+                    .zip(aligned[1].chars())
+                    .filter(|&(x, y)| x == y && x != GAP)
+                    .count();
+                assert_eq!(matches, mlcs_len_pair(a, b), "{aligned:?}");
+            }
+        }
+    }
+}
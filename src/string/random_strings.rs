@@ -0,0 +1,41 @@
+//! Pseudo-random strings for the tests and the benchmarks of the module.
+//!
+//! The output only depends on the seed, so that a failing input can be reproduced.
+//! The benchmarks include this file with `#[path]`.
+
+/// A xorshift generator, the default one being seeded by `0x2545_f491_4f6c_dd1d`
+pub struct Xorshift(u64);
+
+impl Default for Xorshift {
+    fn default() -> Self {
+        Xorshift::new(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+impl Xorshift {
+    /// A generator seeded by `seed`, 0 being replaced by 1, which xorshift never leaves
+    pub fn new(seed: u64) -> Self {
+        Xorshift(seed.max(1))
+    }
+
+    /// The next number, in [0, bound)
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+
+    /// A string of `len` chars over the first `letters` lowercase letters
+    pub fn string(&mut self, len: u64, letters: u64) -> String {
+        (0..len)
+            .map(|_| char::from(b'a' + self.below(letters) as u8))
+            .collect()
+    }
+
+    /// A string of fewer than `max_len` chars over the first `letters` lowercase letters
+    pub fn string_below(&mut self, max_len: u64, letters: u64) -> String {
+        let len = self.below(max_len);
+        self.string(len, letters)
+    }
+}
@@ -0,0 +1,120 @@
+//! Regular expression matching, restricted to the `.` and `*` operators.
+//!
+//! In a pattern, `.` matches exactly one char and `x*` matches zero or more repetitions
+//! of x, x being a char or `.`. Every other char matches itself, and the whole text must be
+//! matched. This is not a full regex engine: there are no groups, alternations, anchors,
+//! classes or escapes. A `*` starting the pattern has nothing to repeat and matches
+//! itself, and the `*` following another one is ignored, as `x**` is `x*`.
+//!
+//! The matching is the classic dynamic programming over the prefixes of the text and of
+//! the pattern: `matches[i][j]` tells if the first i chars of the text match the first
+//! j tokens of the pattern.
+//!
+//! References: [LeetCode](https://leetcode.com/problems/regular-expression-matching/)
+
+/// A char, or `.`, optionally repeated by `*`
+#[derive(Clone, Copy)]
+struct Token {
+    // None for `.`
+    ch: Option<char>,
+    repeated: bool,
+}
+
+impl Token {
+    fn matches(self, c: char) -> bool {
+        self.ch.is_none_or(|ch| ch == c)
+    }
+}
+
+/// Checks if the whole `text` matches `pattern`, made of chars, `.` and `*`
+///
+/// # Complexity
+///
+/// O(|text| * |pattern|) time and space.
+pub fn regex_match(text: &str, pattern: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    let tokens = tokenize(pattern);
+    let mut matches = vec![vec![false; tokens.len() + 1]; text.len() + 1];
+    matches[0][0] = true;
+    // the empty text only matches repeated tokens
+    for (j, token) in tokens.iter().enumerate() {
+        matches[0][j + 1] = matches[0][j] && token.repeated;
+    }
+
+    for (i, &c) in text.iter().enumerate() {
+        for (j, &token) in tokens.iter().enumerate() {
+            matches[i + 1][j + 1] = if token.repeated {
+                // zero repetitions, or one more repetition matching c
+                matches[i + 1][j] || (matches[i][j + 1] && token.matches(c))
+            } else {
+                matches[i][j] && token.matches(c)
+            };
+        }
+    }
+    matches[text.len()][tokens.len()]
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = vec![];
+    for c in pattern.chars() {
+        match (c, tokens.last_mut()) {
+            ('*', Some(last)) => last.repeated = true,
+            ('.', _) => tokens.push(Token {
+                ch: None,
+                repeated: false,
+            }),
+            _ => tokens.push(Token {
+                ch: Some(c),
+                repeated: false,
+            }),
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! regex_match_tests {
+        ($($name:ident: ($text:expr, $pattern:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(regex_match($text, $pattern), $expected);
+                }
+            )*
+        };
+    }
+
+    regex_match_tests! {
+        star_matches_empty: ("", "a*", true),
+        star_repeats: ("aaaa", "a*", true),
+        star_wrong_char: ("aab", "a*", false),
+        dot_star_matches_everything: ("anything at all", ".*", true),
+        dot_star_empty_text: ("", ".*", true),
+        dot_needs_one_char: ("ab", "a.b", false),
+        dot_one_char: ("axb", "a.b", true),
+        dot_not_two_chars: ("axyb", "a.b", false),
+        star_of_a_previous_char: ("aab", "c*a*b", true),
+        mississippi: ("mississippi", "mis*is*p*.", false),
+        mississippi_match: ("mississippi", "mis*is*ip*.", true),
+        dot_star_then_char: ("ab", ".*c", false),
+        backtracking: ("aaa", "a*a", true),
+        star_after_star: ("aaa", "a**", true),
+        leading_star_is_literal: ("*a", "*a", true),
+        leading_star_needs_a_star: ("a", "*a", false),
+        empty_pattern_empty_text: ("", "", true),
+        empty_pattern: ("a", "", false),
+        empty_text: ("", "a", false),
+        unicode: ("串串用于", "串*.于", true),
+    }
+
+    #[test]
+    fn dot_star_matches_every_text() {
+        for text in ["", "a", "*.", "串用于测试", "\n\t "] {
+            assert!(regex_match(text, ".*"), "{text:?}");
+            assert!(regex_match(text, &format!(".*{text}")), "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Longest common subsequence length of a string growing one char at a time.
+//!
+//! The classic dynamic programming fills the table of the LCS lengths of every prefix of
+//! the two strings row by row, each row only depending on the previous one. Keeping the
+//! last row, for the prefix of the growing string read so far against every prefix of the
+//! fixed string, each new char of the growing string only computes one more row.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Longest_common_subsequence)
+
+/// The LCS length between a fixed string and a string fed char by char
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::RollingLcs;
+///
+/// let mut lcs = RollingLcs::new("kitten");
+/// for c in "sit".chars() {
+///     lcs.push(c);
+/// }
+/// assert_eq!(lcs.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollingLcs {
+    fixed: Vec<char>,
+    // row[j] is the LCS length of the chars pushed so far and fixed[..j]
+    row: Vec<usize>,
+}
+
+impl RollingLcs {
+    pub fn new(fixed: &str) -> Self {
+        let fixed: Vec<char> = fixed.chars().collect();
+        RollingLcs {
+            row: vec![0; fixed.len() + 1],
+            fixed,
+        }
+    }
+
+    /// Appends `c` to the growing string
+    ///
+    /// # Complexity
+    ///
+    /// O(m) time for a fixed string of m chars, and no allocation.
+    pub fn push(&mut self, c: char) {
+        // the value of the previous row, one column to the left
+        let mut diagonal = 0;
+        for (j, &x) in self.fixed.iter().enumerate() {
+            let above = self.row[j + 1];
+            self.row[j + 1] = if x == c {
+                diagonal + 1
+            } else {
+                above.max(self.row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    /// The LCS length of the chars pushed so far and the fixed string
+    pub fn len(&self) -> usize {
+        self.row[self.fixed.len()]
+    }
+
+    /// Whether the two strings have no common char yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::longest_common_subsequence;
+
+    fn lcs_len(a: &str, b: &str) -> usize {
+        longest_common_subsequence(a, b).chars().count()
+    }
+
+    #[test]
+    fn sitting_against_kitten() {
+        let mut lcs = RollingLcs::new("kitten");
+        assert!(lcs.is_empty());
+        let mut prefix = String::new();
+        for (c, expected) in "sitting".chars().zip([0, 1, 2, 3, 3, 4, 4]) {
+            lcs.push(c);
+            prefix.push(c);
+            assert_eq!(lcs.len(), expected, "{prefix}");
+            assert_eq!(lcs.len(), lcs_len(&prefix, "kitten"));
+        }
+        assert!(!lcs.is_empty());
+    }
+
+    #[test]
+    fn every_prefix_matches_the_dynamic_programming() {
+        for (growing, fixed) in [
+            ("ABCBDAB", "BDCABA"),
+            ("AGGTAB", "GXTXAYB"),
+            ("aaaa", "aa"),
+            ("串用于测试", "用于串测试"),
+            ("abc", ""),
+        ] {
+            let mut lcs = RollingLcs::new(fixed);
+            for (i, c) in growing.char_indices() {
+                lcs.push(c);
+                let prefix = &growing[..i + c.len_utf8()];
+                assert_eq!(lcs.len(), lcs_len(prefix, fixed), "{prefix} {fixed}");
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_pushed() {
+        assert_eq!(RollingLcs::new("abc").len(), 0);
+        assert_eq!(RollingLcs::new("").len(), 0);
+    }
+}
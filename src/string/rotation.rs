@@ -0,0 +1,72 @@
+//! String rotations.
+//!
+//! `t` is a rotation of `s` when both have the same length and `t` is a substring of
+//! `s + s`, since every rotation of `s` starts somewhere in its first copy.
+
+/// Checks if `t` is a rotation of `s`, i.e. if `t` is made of a suffix of `s`
+/// followed by the matching prefix.
+///
+/// # Arguments
+///
+/// * `s` - The reference string.
+/// * `t` - The string checked against the rotations of `s`.
+///
+/// # Returns
+///
+/// * `true` if `t` is a rotation of `s`, `false` otherwise.
+pub fn is_rotation(s: &str, t: &str) -> bool {
+    rotation_index(s, t).is_some()
+}
+
+/// Finds by how many chars `s` must be rotated to the left to give `t`.
+///
+/// # Arguments
+///
+/// * `s` - The reference string.
+/// * `t` - The rotation of `s` to locate.
+///
+/// # Returns
+///
+/// * `Some(k)` with the smallest `k` such that moving the first `k` chars of `s` to its end
+///   gives `t`.
+/// * `None` if `t` is not a rotation of `s`.
+pub fn rotation_index(s: &str, t: &str) -> Option<usize> {
+    if s.len() != t.len() {
+        return None;
+    }
+    let doubled = s.to_owned() + s;
+    let start = doubled.find(t)?;
+    Some(doubled[..start].chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! rotation_tests {
+        ($($name:ident: ($s:expr, $t:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let expected: Option<usize> = $expected;
+                    assert_eq!(rotation_index($s, $t), expected);
+                    assert_eq!(is_rotation($s, $t), expected.is_some());
+                }
+            )*
+        };
+    }
+
+    rotation_tests! {
+        empty: ("", "", Some(0)),
+        same: ("abcd", "abcd", Some(0)),
+        by_one: ("abcd", "bcda", Some(1)),
+        by_three: ("abcd", "dabc", Some(3)),
+        waterbottle: ("waterbottle", "erbottlewat", Some(3)),
+        periodic_smallest_offset: ("abab", "baba", Some(1)),
+        not_a_rotation: ("abcd", "acbd", None),
+        substring_of_doubled_but_shorter: ("abcd", "cda", None),
+        different_lengths: ("abc", "abcabc", None),
+        one_empty: ("", "a", None),
+        unicode: ("串用于测试", "测试串用于", Some(3)),
+    }
+}
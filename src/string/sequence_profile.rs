@@ -0,0 +1,320 @@
+//! Profile of a multiple sequence alignment: the frequency of each char in each column.
+//!
+//! The sequences of an alignment all have the same length, a `'-'` standing for a gap, so
+//! that the chars of a column are aligned with each other. The profile keeps, for each
+//! column, how many sequences have each char there, which gives:
+//! * the consensus, the most frequent char of each column
+//! * the Shannon entropy of each column, in bits, 0 when every sequence agrees
+//! * the information content of each column of DNA, 2 bits minus the entropy
+//! * the log-odds score of a new sequence, telling how much more likely it is under the
+//!   profile than under the background frequencies of the chars
+//!
+//! The gaps take part in the consensus, but not in the statistics, which only count the
+//! residues of each column.
+//!
+//! References: [Position weight matrix](https://en.wikipedia.org/wiki/Position_weight_matrix),
+//! [Consensus sequence](https://en.wikipedia.org/wiki/Consensus_sequence)
+
+use std::collections::{BTreeMap, HashMap};
+
+const GAP: char = '-';
+
+/// Errors of the construction of a `SequenceProfile`
+#[derive(Debug, PartialEq, Eq)]
+pub enum SequenceProfileError {
+    /// No sequence was given
+    NoSequence,
+    /// The sequence at index `sequence` has `length` chars, but the first one has `expected`
+    DifferentLengths {
+        sequence: usize,
+        length: usize,
+        expected: usize,
+    },
+}
+
+/// The positional frequencies of the chars of aligned sequences
+///
+/// # Example
+///
+/// ```
+/// use the_algorithms_rust::string::SequenceProfile;
+///
+/// let profile = SequenceProfile::new(&["ACGT", "ACGA", "TC-A"]).unwrap();
+/// assert_eq!(profile.consensus(), "ACGA");
+/// assert_eq!(profile.frequency(1, 'C'), 1.0);
+/// // the second column is all 'C', the most informative a column of DNA can be
+/// assert_eq!(profile.information_content()[1], 2.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SequenceProfile {
+    // for each column, the number of sequences having each char there, gaps included
+    columns: Vec<BTreeMap<char, usize>>,
+    // the frequency of each residue over the whole alignment
+    background: HashMap<char, f64>,
+    sequences: usize,
+}
+
+impl SequenceProfile {
+    /// Builds the profile of `aligned`, sequences of the same number of chars
+    ///
+    /// # Errors
+    ///
+    /// * `SequenceProfileError::NoSequence` if `aligned` is empty
+    /// * `SequenceProfileError::DifferentLengths` if a sequence does not have as many chars
+    ///   as the first one
+    pub fn new(aligned: &[&str]) -> Result<Self, SequenceProfileError> {
+        let expected = aligned
+            .first()
+            .ok_or(SequenceProfileError::NoSequence)?
+            .chars()
+            .count();
+        let mut columns = vec![BTreeMap::new(); expected];
+        let mut residues: HashMap<char, usize> = HashMap::new();
+        for (sequence, s) in aligned.iter().enumerate() {
+            let length = s.chars().count();
+            if length != expected {
+                return Err(SequenceProfileError::DifferentLengths {
+                    sequence,
+                    length,
+                    expected,
+                });
+            }
+            for (column, c) in columns.iter_mut().zip(s.chars()) {
+                *column.entry(c).or_insert(0) += 1;
+                if c != GAP {
+                    *residues.entry(c).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let total: usize = residues.values().sum();
+        let background = residues
+            .into_iter()
+            .map(|(c, count)| (c, count as f64 / total as f64))
+            .collect();
+        Ok(SequenceProfile {
+            columns,
+            background,
+            sequences: aligned.len(),
+        })
+    }
+
+    /// The number of columns
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Whether the sequences are empty
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// The share of the sequences having `ch` in `column`, gaps included, 0 past the last
+    /// column
+    pub fn frequency(&self, column: usize, ch: char) -> f64 {
+        let count = self
+            .columns
+            .get(column)
+            .and_then(|column| column.get(&ch))
+            .copied()
+            .unwrap_or(0);
+        count as f64 / self.sequences as f64
+    }
+
+    /// The most frequent char of each column, gaps included, the smallest char among the
+    /// most frequent ones
+    pub fn consensus(&self) -> String {
+        self.columns
+            .iter()
+            .map(|column| {
+                // the first of the chars sorted by decreasing count, then increasing char
+                column
+                    .iter()
+                    .max_by_key(|&(&c, &count)| (count, std::cmp::Reverse(c)))
+                    .map_or(GAP, |(&c, _)| c)
+            })
+            .collect()
+    }
+
+    /// The Shannon entropy of the residues of each column, in bits, 0 for a column of gaps
+    pub fn entropy(&self) -> Vec<f64> {
+        self.columns
+            .iter()
+            .map(|column| {
+                let residues = residues(column);
+                let total: usize = residues.clone().map(|(_, count)| count).sum();
+                residues
+                    .map(|(_, count)| {
+                        let p = count as f64 / total as f64;
+                        -p * p.log2()
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// The information content of each column of DNA, 2 bits minus its entropy
+    ///
+    /// 2 bits is the entropy of the 4 nucleotides in equal parts, so the content goes from
+    /// 0 for a column telling nothing to 2 for a column of a single nucleotide. Over larger
+    /// alphabets, e.g. of proteins, it may be negative.
+    pub fn information_content(&self) -> Vec<f64> {
+        self.entropy().into_iter().map(|h| 2.0 - h).collect()
+    }
+
+    /// The log-odds score of `s`, in bits: the sum over its chars of the log of their
+    /// probability in their column over their background frequency
+    ///
+    /// The background frequency of a char is its share of the residues of the alignment.
+    /// Its probability in a column is its share of the residues of the column, with one
+    /// pseudocount spread along the background, so that a residue of the alignment missing
+    /// from a column is unlikely there, but not impossible.
+    ///
+    /// The gaps of `s` are not scored, nor the chars past the last column. A char found
+    /// nowhere in the alignment makes the score `f64::NEG_INFINITY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use the_algorithms_rust::string::SequenceProfile;
+    ///
+    /// let profile = SequenceProfile::new(&["ACGT", "ACGA", "ACGT"]).unwrap();
+    /// assert!(profile.score_sequence("ACGT") > 0.0);
+    /// assert!(profile.score_sequence("TGCA") < 0.0);
+    /// assert_eq!(profile.score_sequence("ACGU"), f64::NEG_INFINITY);
+    /// ```
+    pub fn score_sequence(&self, s: &str) -> f64 {
+        self.columns
+            .iter()
+            .zip(s.chars())
+            .filter(|&(_, c)| c != GAP)
+            .map(|(column, c)| {
+                let Some(&background) = self.background.get(&c) else {
+                    return f64::NEG_INFINITY;
+                };
+                let total: usize = residues(column).map(|(_, count)| count).sum();
+                let count = column.get(&c).copied().unwrap_or(0);
+                let p = (count as f64 + background) / (total as f64 + 1.0);
+                (p / background).log2()
+            })
+            .sum()
+    }
+}
+
+// the chars of a column other than gaps, with their counts
+fn residues(column: &BTreeMap<char, usize>) -> impl Iterator<Item = (char, usize)> + Clone + '_ {
+    column
+        .iter()
+        .filter(|&(&c, _)| c != GAP)
+        .map(|(&c, &count)| (c, count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::assertions::assert_close;
+
+    macro_rules! consensus_tests {
+        ($($name:ident: ($aligned:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let profile = SequenceProfile::new(&$aligned).unwrap();
+                    assert_eq!(profile.consensus(), $expected);
+                    assert_eq!(profile.len(), $expected.chars().count());
+                }
+            )*
+        };
+    }
+
+    consensus_tests! {
+        single_sequence: (["GATTACA"], "GATTACA"),
+        majority: (["ACGT", "ACGA", "TCGA"], "ACGA"),
+        gaps: (["AC-T", "A--T", "GC-A"], "AC-T"),
+        // 'A' and 'G' tie in the first column, 'A' comes first
+        ties: (["AT", "GT"], "AT"),
+        unicode: (["串用于", "串于于", "用于于"], "串于于"),
+        empty_sequences: (["", ""], ""),
+    }
+
+    #[test]
+    fn frequencies() {
+        let profile = SequenceProfile::new(&["AC-T", "A--T", "GC-A", "AGTT"]).unwrap();
+        assert_close(profile.frequency(0, 'A'), 0.75);
+        assert_close(profile.frequency(0, 'G'), 0.25);
+        assert_close(profile.frequency(1, '-'), 0.25);
+        assert_close(profile.frequency(2, '-'), 0.75);
+        assert_close(profile.frequency(2, 'C'), 0.0);
+        assert_close(profile.frequency(9, 'A'), 0.0);
+        // every column adds up to 1
+        for column in 0..profile.len() {
+            let total: f64 = "ACGT-".chars().map(|c| profile.frequency(column, c)).sum();
+            assert_close(total, 1.0);
+        }
+    }
+
+    #[test]
+    fn entropy_and_information_content() {
+        let profile = SequenceProfile::new(&["AAAA-", "ACCC-", "AGGA-", "ATTC-"]).unwrap();
+        let entropy = profile.entropy();
+        // a single nucleotide, four equally frequent ones, two halves, the second column
+        // again, only gaps
+        for (h, expected) in entropy.iter().zip([0.0, 2.0, 2.0, 1.0, 0.0]) {
+            assert_close(*h, expected);
+        }
+        let content = profile.information_content();
+        for (ic, expected) in content.iter().zip([2.0, 0.0, 0.0, 1.0, 2.0]) {
+            assert_close(*ic, expected);
+        }
+        // gaps are not residues: 'A' and 'C' in equal parts
+        let profile = SequenceProfile::new(&["A", "C", "-", "-", "-"]).unwrap();
+        assert_close(profile.entropy()[0], 1.0);
+    }
+
+    #[test]
+    fn log_odds_scores() {
+        // each nucleotide makes a quarter of the residues
+        let aligned = ["AC", "AG", "AT", "AG", "CT", "CC", "GG", "TT"];
+        let profile = SequenceProfile::new(&aligned).unwrap();
+        let background: f64 = 0.25;
+        // 'A' in 4 of the 8 residues of the first column, plus a quarter of a pseudocount
+        let first_a = (4.0 + background) / 9.0;
+        assert_close(profile.score_sequence("A"), (first_a / background).log2());
+        let second_c = (2.0 + background) / 9.0;
+        assert_close(
+            profile.score_sequence("AC"),
+            (first_a / background).log2() + (second_c / background).log2(),
+        );
+        // gaps and chars past the last column are not scored
+        assert_close(profile.score_sequence("A-"), profile.score_sequence("A"));
+        assert_close(profile.score_sequence("ACGT"), profile.score_sequence("AC"));
+        assert_close(profile.score_sequence(""), 0.0);
+        assert_eq!(profile.score_sequence("AN"), f64::NEG_INFINITY);
+        // the consensus scores best
+        let consensus = profile.consensus();
+        for s in ["CA", "GT", "TC", "CG"] {
+            assert!(
+                profile.score_sequence(&consensus) > profile.score_sequence(s),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_alignments() {
+        assert_eq!(
+            SequenceProfile::new(&[]),
+            Err(SequenceProfileError::NoSequence)
+        );
+        assert_eq!(
+            SequenceProfile::new(&["ACGT", "ACG-", "AC"]),
+            Err(SequenceProfileError::DifferentLengths {
+                sequence: 2,
+                length: 2,
+                expected: 4
+            })
+        );
+        // lengths are counted in chars
+        assert!(SequenceProfile::new(&["串用", "ab"]).is_ok());
+    }
+}
@@ -0,0 +1,180 @@
+//! Shortest common supersequence of two or more strings.
+//!
+//! A supersequence of s is a string having s as a subsequence. For two strings, a shortest
+//! common supersequence is obtained by merging them along a longest common subsequence, so
+//! |SCS(s1, s2)| = |s1| + |s2| - |LCS(s1, s2)|, and it is built from the usual LCS table.
+//!
+//! For more strings the problem is NP-hard. The supersequences of each pair of strings bound
+//! the length from below, since a supersequence of all the strings is one of each pair, and
+//! a greedy merge gives one from above: SCS(s1, s2), then SCS(SCS(s1, s2), s3), and so on.
+//! The formula of the pairs does not extend with the MLCS: `sum(|si|) - (d - 1) * |MLCS|`
+//! is no bound for d > 2, e.g. 6 for `["ab", "ab", "cd"]` whose SCS is `"abcd"`.
+//!
+//! Lengths are counted in chars.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Shortest_common_supersequence)
+
+use super::mlcs_len_pair;
+
+/// The bounds on the length of a shortest common supersequence of several strings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScsBounds {
+    /// The length of the longest shortest common supersequence of two of the strings
+    pub lower_bound: usize,
+    /// The length of `supersequence`
+    pub upper_bound: usize,
+    /// A common supersequence, merging the strings one after the other
+    pub supersequence: String,
+}
+
+/// Finds a shortest common supersequence of `s1` and `s2`
+///
+/// # Complexity
+///
+/// O(|s1| * |s2|) time and space.
+pub fn shortest_common_supersequence(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    // lcs[i][j] is the length of the LCS of a[i..] and b[j..]
+    let mut lcs = vec![vec![0; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // the chars of the LCS are written once, the others are written in their order
+    let mut scs = String::with_capacity(s1.len() + s2.len());
+    let (mut i, mut j) = (0, 0);
+    while i < m && j < n {
+        if a[i] == b[j] {
+            scs.push(a[i]);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            scs.push(a[i]);
+            i += 1;
+        } else {
+            scs.push(b[j]);
+            j += 1;
+        }
+    }
+    scs.extend(&a[i..]);
+    scs.extend(&b[j..]);
+    scs
+}
+
+/// Bounds the length of a shortest common supersequence of `chains`
+///
+/// # Returns
+///
+/// The pairwise lower bound, and the greedy supersequence with its length as the upper
+/// bound. Both bounds are exact for at most two strings, and are 0 without strings.
+///
+/// # Complexity
+///
+/// O(d^2 * n^2) time for d strings of at most n chars, the greedy merge being in O(d^2 * n^2)
+/// as well, since the merged string has at most d * n chars.
+pub fn multiple_shortest_common_supersequence_length(chains: &[&str]) -> ScsBounds {
+    let mut lower_bound = chains.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+    for (i, s1) in chains.iter().enumerate() {
+        for s2 in &chains[i + 1..] {
+            let scs_len = s1.chars().count() + s2.chars().count() - mlcs_len_pair(s1, s2);
+            lower_bound = lower_bound.max(scs_len);
+        }
+    }
+
+    let supersequence = chains.iter().fold(String::new(), |scs, s| {
+        shortest_common_supersequence(&scs, s)
+    });
+    ScsBounds {
+        lower_bound,
+        upper_bound: supersequence.chars().count(),
+        supersequence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::is_subsequence;
+
+    macro_rules! scs_tests {
+        ($($name:ident: ($s1:expr, $s2:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let (s1, s2) = ($s1, $s2);
+                    let scs = shortest_common_supersequence(s1, s2);
+                    assert_eq!(scs, $expected);
+                    assert!(is_subsequence(s1, &scs));
+                    assert!(is_subsequence(s2, &scs));
+                }
+            )*
+        };
+    }
+
+    scs_tests! {
+        empty: ("", "", ""),
+        empty_first: ("", "abc", "abc"),
+        empty_second: ("abc", "", "abc"),
+        identical: ("abc", "abc", "abc"),
+        disjoint: ("ab", "cd", "abcd"),
+        geek: ("geek", "eke", "geeke"),
+        classic: ("AGGTAB", "GXTXAYB", "AGGXTXAYB"),
+        unicode: ("串用于", "用串于", "串用串于"),
+    }
+
+    #[test]
+    fn greedy_supersequence_of_every_string() {
+        for input in [
+            ["abcab", "bacba", "cabab"].as_slice(),
+            &["AGGTAB", "GXTXAYB", "AXTB", "GTTAB"],
+            &["ab", "ab", "cd"],
+            &["串用于测试", "用于串测试", "测试"],
+            &["", "abc", ""],
+        ] {
+            let bounds = multiple_shortest_common_supersequence_length(input);
+            for s in input {
+                assert!(is_subsequence(s, &bounds.supersequence), "{input:?}");
+            }
+            assert_eq!(bounds.upper_bound, bounds.supersequence.chars().count());
+            assert!(bounds.lower_bound <= bounds.upper_bound, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn exact_for_two_strings() {
+        let bounds = multiple_shortest_common_supersequence_length(&["AGGTAB", "GXTXAYB"]);
+        assert_eq!(bounds.lower_bound, 9);
+        assert_eq!(bounds.upper_bound, 9);
+        assert_eq!(bounds.supersequence, "AGGXTXAYB");
+    }
+
+    #[test]
+    fn pairs_bound_the_length() {
+        // sum(|si|) - (d - 1) * |MLCS| would be 6
+        let bounds = multiple_shortest_common_supersequence_length(&["ab", "ab", "cd"]);
+        assert_eq!(bounds.lower_bound, 4);
+        assert_eq!(bounds.supersequence, "abcd");
+    }
+
+    #[test]
+    fn no_string() {
+        let bounds = multiple_shortest_common_supersequence_length(&[]);
+        assert_eq!(
+            bounds,
+            ScsBounds {
+                lower_bound: 0,
+                upper_bound: 0,
+                supersequence: String::new(),
+            }
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Substitution matrices scoring the alignment of two chars, e.g. of two amino acids.
+//!
+//! An LCS only matches equal chars. In protein sequences some substitutions are common and
+//! harmless, e.g. glutamate (E) for aspartate (D), and others rare, e.g. glycine (G) for
+//! tryptophan (W): a substitution matrix gives each pair of residues a score, positive when
+//! the substitution is more frequent than by chance. BLOSUM62 is the matrix of the blocks
+//! of aligned proteins sharing at most 62% identity.
+//!
+//! Matrices are read in the format of the NCBI files: `#` comments, a header line with the
+//! letters of the columns, then a line per letter with the letter and its scores, in the
+//! order of the header.
+//!
+//! References: [BLOSUM](https://en.wikipedia.org/wiki/BLOSUM),
+//! [Substitution matrix](https://en.wikipedia.org/wiki/Substitution_matrix)
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::multiple_longest_common_subsequence::score_matrix;
+
+const BLOSUM62: &str = "\
+#  Matrix made by matblas from blosum62.iij
+   A  R  N  D  C  Q  E  G  H  I  L  K  M  F  P  S  T  W  Y  V  B  Z  X  *
+A  4 -1 -2 -2  0 -1 -1  0 -2 -1 -1 -1 -1 -2 -1  1  0 -3 -2  0 -2 -1  0 -4
+R -1  5  0 -2 -3  1  0 -2  0 -3 -2  2 -1 -3 -2 -1 -1 -3 -2 -3 -1  0 -1 -4
+N -2  0  6  1 -3  0  0  0  1 -3 -3  0 -2 -3 -2  1  0 -4 -2 -3  3  0 -1 -4
+D -2 -2  1  6 -3  0  2 -1 -1 -3 -4 -1 -3 -3 -1  0 -1 -4 -3 -3  4  1 -1 -4
+C  0 -3 -3 -3  9 -3 -4 -3 -3 -1 -1 -3 -1 -2 -3 -1 -1 -2 -2 -1 -3 -3 -2 -4
+Q -1  1  0  0 -3  5  2 -2  0 -3 -2  1  0 -3 -1  0 -1 -2 -1 -2  0  3 -1 -4
+E -1  0  0  2 -4  2  5 -2  0 -3 -3  1 -2 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+G  0 -2  0 -1 -3 -2 -2  6 -2 -4 -4 -2 -3 -3 -2  0 -2 -2 -3 -3 -1 -2 -1 -4
+H -2  0  1 -1 -3  0  0 -2  8 -3 -3 -1 -2 -1 -2 -1 -2 -2  2 -3  0  0 -1 -4
+I -1 -3 -3 -3 -1 -3 -3 -4 -3  4  2 -3  1  0 -3 -2 -1 -3 -1  3 -3 -3 -1 -4
+L -1 -2 -3 -4 -1 -2 -3 -4 -3  2  4 -2  2  0 -3 -2 -1 -2 -1  1 -4 -3 -1 -4
+K -1  2  0 -1 -3  1  1 -2 -1 -3 -2  5 -1 -3 -1  0 -1 -3 -2 -2  0  1 -1 -4
+M -1 -1 -2 -3 -1  0 -2 -3 -2  1  2 -1  5  0 -2 -1 -1 -1 -1  1 -3 -1 -1 -4
+F -2 -3 -3 -3 -2 -3 -3 -3 -1  0  0 -3  0  6 -4 -2 -2  1  3 -1 -3 -3 -1 -4
+P -1 -2 -2 -1 -3 -1 -1 -2 -2 -3 -3 -1 -2 -4  7 -1 -1 -4 -3 -2 -2 -1 -2 -4
+S  1 -1  1  0 -1  0  0  0 -1 -2 -2  0 -1 -2 -1  4  1 -3 -2 -2  0  0  0 -4
+T  0 -1  0 -1 -1 -1 -1 -2 -2 -1 -1 -1 -1 -2 -1  1  5 -2 -2  0 -1 -1  0 -4
+W -3 -3 -4 -4 -2 -2 -3 -2 -2 -3 -2 -3 -1  1 -4 -3 -2 11  2 -3 -4 -3 -2 -4
+Y -2 -2 -2 -3 -2 -1 -2 -3  2 -1 -1 -2 -1  3 -3 -2 -2  2  7 -1 -3 -2 -1 -4
+V  0 -3 -3 -3 -1 -2 -2 -3 -3  3  1 -2  1 -1 -2 -2  0 -3 -1  4 -3 -2 -1 -4
+B -2 -1  3  4 -3  0  1 -1  0 -3 -4  0 -3 -3 -2  0 -1 -4 -3 -3  4  1 -1 -4
+Z -1  0  0  1 -3  3  4 -2  0 -3 -3  1 -1 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+X  0 -1 -1 -1 -2 -1 -1 -1 -1 -1 -1 -1 -1 -1 -2  0  0 -2 -1 -1 -1 -1 -1 -4
+* -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4  1
+";
+
+/// The score of aligning each pair of letters
+///
+/// A char missing from the matrix is scored as `*`, the letter of any other residue in the
+/// NCBI matrices, or 0 when the matrix has no `*`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubstitutionMatrix {
+    // index of each letter in the rows and columns of scores
+    letters: HashMap<char, usize>,
+    scores: Vec<Vec<i64>>,
+}
+
+impl SubstitutionMatrix {
+    /// The BLOSUM62 matrix, over the 20 amino acids, the ambiguous B (D or N), Z (E or Q)
+    /// and X, and `*`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use the_algorithms_rust::string::SubstitutionMatrix;
+    ///
+    /// let blosum62 = SubstitutionMatrix::blosum62();
+    /// assert_eq!(blosum62.score('E', 'D'), 2);
+    /// assert_eq!(blosum62.score('G', 'W'), -2);
+    /// assert_eq!(blosum62.score('W', 'W'), 11);
+    /// ```
+    pub fn blosum62() -> Self {
+        Self::parse(BLOSUM62).expect("BLOSUM62 is well formed")
+    }
+
+    /// Reads a matrix from a file in the NCBI format
+    ///
+    /// # Errors
+    ///
+    /// The errors of reading the file, and `io::ErrorKind::InvalidData` when a letter is
+    /// not a single char or is repeated, a score is not an integer, or the matrix is not
+    /// square with its rows in the order of the header.
+    pub fn from_file(path: &Path) -> Result<Self, io::Error> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// The score of aligning `a` with `b`
+    pub fn score(&self, a: char, b: char) -> i64 {
+        let index = |c: char| self.letters.get(&c).or_else(|| self.letters.get(&'*'));
+        match (index(a), index(b)) {
+            (Some(&i), Some(&j)) => self.scores[i][j],
+            _ => 0,
+        }
+    }
+
+    /// The best total score of aligning chars of `s1` with chars of `s2` in order, the
+    /// chars left out costing nothing
+    ///
+    /// This is the LCS length when the score is 1 between equal chars and 0 otherwise. The
+    /// pairs of negative score are never worth aligning, so the result is never negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use the_algorithms_rust::string::SubstitutionMatrix;
+    ///
+    /// let blosum62 = SubstitutionMatrix::blosum62();
+    /// // H-H, E-D and W-W
+    /// assert_eq!(blosum62.alignment_score("HEAGW", "HDW"), 21);
+    /// ```
+    ///
+    /// # Complexity
+    ///
+    /// O(n * m) time and space, for strings of n and m chars.
+    pub fn alignment_score(&self, s1: &str, s2: &str) -> u64 {
+        let a: Vec<char> = s1.chars().collect();
+        let b: Vec<char> = s2.chars().collect();
+        score_matrix(&a, &b, Some(self))[0][0]
+    }
+
+    fn parse(text: &str) -> io::Result<Self> {
+        let single_char = |token: &str| -> io::Result<char> {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(invalid_data(&format!("{token} is not a single letter"))),
+            }
+        };
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let header: Vec<char> = lines
+            .next()
+            .ok_or_else(|| invalid_data("no header"))?
+            .split_whitespace()
+            .map(single_char)
+            .collect::<io::Result<_>>()?;
+        let mut letters = HashMap::new();
+        for (k, &c) in header.iter().enumerate() {
+            if letters.insert(c, k).is_some() {
+                return Err(invalid_data(&format!("{c} is repeated")));
+            }
+        }
+
+        let mut scores = vec![];
+        for line in lines {
+            let mut tokens = line.split_whitespace();
+            let letter = tokens.next().map(single_char).transpose()?;
+            if letter != header.get(scores.len()).copied() {
+                return Err(invalid_data(&format!("unexpected row {line}")));
+            }
+            let row: Vec<i64> = tokens
+                .map(|token| {
+                    token
+                        .parse()
+                        .map_err(|_| invalid_data(&format!("{token} is not a score")))
+                })
+                .collect::<io::Result<_>>()?;
+            if row.len() != header.len() {
+                return Err(invalid_data(&format!(
+                    "row {line} has {} scores",
+                    row.len()
+                )));
+            }
+            scores.push(row);
+        }
+        if scores.len() != header.len() {
+            return Err(invalid_data("missing rows"));
+        }
+        Ok(SubstitutionMatrix { letters, scores })
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic_programming::longest_common_subsequence;
+
+    const AMINO_ACIDS: &str = "ARNDCQEGHILKMFPSTWYV";
+
+    // a matrix scoring 1 for equal chars and 0 otherwise, over the given letters
+    fn identity(letters: &str) -> SubstitutionMatrix {
+        let mut text = format!(
+            "  {}\n",
+            letters
+                .chars()
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        for a in letters.chars() {
+            text.push(a);
+            for b in letters.chars() {
+                text.push_str(if a == b { " 1" } else { " 0" });
+            }
+            text.push('\n');
+        }
+        SubstitutionMatrix::parse(&text).unwrap()
+    }
+
+    #[test]
+    fn blosum62_is_symmetric() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        assert_eq!(blosum62.letters.len(), 24);
+        for a in blosum62.letters.keys() {
+            for b in blosum62.letters.keys() {
+                assert_eq!(blosum62.score(*a, *b), blosum62.score(*b, *a), "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn blosum62_scores() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        // an amino acid always scores best against itself
+        for a in AMINO_ACIDS.chars() {
+            let own = blosum62.score(a, a);
+            assert!((4..=11).contains(&own), "{a}");
+            assert!(AMINO_ACIDS
+                .chars()
+                .all(|b| a == b || blosum62.score(a, b) < own));
+        }
+        assert_eq!(blosum62.score('C', 'C'), 9);
+        assert_eq!(blosum62.score('E', 'Q'), 2);
+        assert_eq!(blosum62.score('I', 'V'), 3);
+        assert_eq!(blosum62.score('F', 'Y'), 3);
+        assert_eq!(blosum62.score('A', 'W'), -3);
+        // lowercase letters and other chars are scored as '*'
+        assert_eq!(blosum62.score('a', 'A'), -4);
+        assert_eq!(blosum62.score('串', '*'), 1);
+    }
+
+    #[test]
+    fn alignment_scores() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        assert_eq!(blosum62.alignment_score("", "ACD"), 0);
+        assert_eq!(blosum62.alignment_score("W", "G"), 0);
+        assert_eq!(blosum62.alignment_score("D", "E"), 2);
+        assert_eq!(blosum62.alignment_score("E", "D"), 2);
+        // W-W is worth more than the rest of the alignment
+        assert_eq!(blosum62.alignment_score("WAAA", "AAAW"), 12);
+        assert_eq!(blosum62.alignment_score("WAA", "AAW"), 11);
+    }
+
+    #[test]
+    fn identity_scores_are_the_lcs_length() {
+        let matrix = identity("abc");
+        for (s1, s2) in [
+            ("", ""),
+            ("abc", "abc"),
+            ("abcbcab", "bcabca"),
+            ("aaa", "a"),
+            ("cab", "abc"),
+        ] {
+            let expected = longest_common_subsequence(s1, s2).chars().count() as u64;
+            assert_eq!(matrix.alignment_score(s1, s2), expected, "{s1} {s2}");
+        }
+        // chars missing from a matrix without '*' score 0
+        assert_eq!(matrix.alignment_score("xyz", "xyz"), 0);
+    }
+
+    #[test]
+    fn from_file() {
+        let path =
+            std::env::temp_dir().join(format!("substitution_matrix_{}.txt", std::process::id()));
+        fs::write(&path, BLOSUM62).unwrap();
+        let matrix = SubstitutionMatrix::from_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(matrix.unwrap(), SubstitutionMatrix::blosum62());
+
+        let missing = std::env::temp_dir().join("no_such_substitution_matrix.txt");
+        let error = SubstitutionMatrix::from_file(&missing).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn invalid_matrices() {
+        for text in [
+            "",
+            "# only a comment\n",
+            "  A B\nA 1 0\n",
+            "  A B\nA 1 0\nB 0\n",
+            "  A B\nA 1 0\nB 0 1 2\n",
+            "  A B\nB 0 1\nA 1 0\n",
+            "  A B\nA 1 x\nB 0 1\n",
+            "  AB C\nAB 1 0\nC 0 1\n",
+            "  A A\nA 1 0\nA 0 1\n",
+            "  A B\nA 1 0\nB 0 1\nC 0 0\n",
+        ] {
+            let error = SubstitutionMatrix::parse(text).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{text}");
+        }
+        let matrix = SubstitutionMatrix::parse("# comment\n\n  A B\nA 1 -1\n\nB -1 2\n").unwrap();
+        assert_eq!(matrix.score('B', 'B'), 2);
+        assert_eq!(matrix.score('A', 'B'), -1);
+    }
+}
@@ -83,6 +83,94 @@ pub fn generate_suffix_array(txt: &str) -> Vec<usize> {
     suffix_arr
 }
 
+/// Sorts the suffixes of `text` by prefix doubling, each round being a stable two-pass
+/// counting sort on the (class, class of the suffix 2^k further) pairs, in O(n log n).
+/// Unlike `generate_suffix_array`, any ordered values make a text, e.g. chars or the
+/// letters and separators of several concatenated strings.
+pub(super) fn sort_suffixes<T: Ord>(text: &[T]) -> Vec<usize> {
+    let n = text.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut letters: Vec<&T> = text.iter().collect();
+    letters.sort_unstable();
+    letters.dedup();
+
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = text
+        .iter()
+        .map(|c| letters.binary_search(&c).unwrap_or(0))
+        .collect();
+    let mut tmp = vec![0; n];
+    let mut classes = letters.len();
+    let mut k = 1;
+
+    loop {
+        // rank 0 is reserved for suffixes with no second half
+        let second = |i: usize| if i + k < n { rank[i + k] + 1 } else { 0 };
+        let by_second = counting_sort(&sa, classes + 1, second);
+        sa = counting_sort(&by_second, classes, |i| rank[i]);
+
+        tmp[sa[0]] = 0;
+        for w in 1..n {
+            let (prev, cur) = (sa[w - 1], sa[w]);
+            let same = rank[prev] == rank[cur] && second(prev) == second(cur);
+            tmp[cur] = tmp[prev] + usize::from(!same);
+        }
+        std::mem::swap(&mut rank, &mut tmp);
+        classes = rank[sa[n - 1]] + 1;
+
+        if classes == n {
+            return sa;
+        }
+        k *= 2;
+    }
+}
+
+/// Stable counting sort of `items` by `key`, whose values are smaller than `range`.
+fn counting_sort(items: &[usize], range: usize, key: impl Fn(usize) -> usize) -> Vec<usize> {
+    let mut start = vec![0; range + 1];
+    for &item in items {
+        start[key(item) + 1] += 1;
+    }
+    for i in 1..=range {
+        start[i] += start[i - 1];
+    }
+    let mut sorted = vec![0; items.len()];
+    for &item in items {
+        let slot = &mut start[key(item)];
+        sorted[*slot] = item;
+        *slot += 1;
+    }
+    sorted
+}
+
+/// Kasai's algorithm: `lcp[i]` is the length of the longest common prefix of the
+/// suffixes `sa[i - 1]` and `sa[i]`, `lcp[0]` is 0.
+pub(super) fn lcp_array<T: Eq>(text: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut rank = vec![0; n];
+    for (i, &s) in sa.iter().enumerate() {
+        rank[s] = i;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut h = 0;
+    for i in 0..n {
+        if rank[i] > 0 {
+            let j = sa[rank[i] - 1];
+            while i + h < n && j + h < n && text[i + h] == text[j + h] {
+                h += 1;
+            }
+            lcp[rank[i]] = h;
+            h = h.saturating_sub(1);
+        } else {
+            h = 0;
+        }
+    }
+    lcp
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +180,22 @@ mod tests {
         let a = generate_suffix_array("banana");
         assert_eq!(a, vec![5, 3, 1, 0, 4, 2]);
     }
+
+    #[test]
+    fn sorted_suffixes_of_any_text() {
+        let chars: Vec<char> = "mississippi".chars().collect();
+        assert_eq!(sort_suffixes(&chars), [10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2]);
+        let text: Vec<usize> = "banana".bytes().map(|b| (b - b'a') as usize).collect();
+        assert_eq!(sort_suffixes(&text), [5, 3, 1, 0, 4, 2]);
+        assert!(sort_suffixes::<char>(&[]).is_empty());
+        assert_eq!(sort_suffixes(&['a']), [0]);
+    }
+
+    #[test]
+    fn lcp_of_neighbour_suffixes() {
+        let chars: Vec<char> = "banana".chars().collect();
+        let sa = sort_suffixes(&chars);
+        // a, ana, anana, banana, na, nana
+        assert_eq!(lcp_array(&chars, &sa), [0, 1, 3, 0, 0, 2]);
+    }
 }
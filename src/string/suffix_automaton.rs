@@ -0,0 +1,268 @@
+//! Suffix automaton (or DAWG, directed acyclic word graph) of a string.
+//!
+//! The suffix automaton of s is the smallest automaton recognizing the suffixes of s. Each
+//! of its states stands for a set of substrings of s having the same end positions in s:
+//! the suffixes of its longest substring whose length is greater than the length of the
+//! longest substring of its suffix link. It has at most 2n - 1 states and 3n - 4 transitions,
+//! and is built online in O(n) time, for a fixed alphabet.
+//!
+//! Every substring of s is read from the initial state, which gives:
+//! * substring checks in O(m) time for a pattern of m chars
+//! * occurrence counts in O(m) time, the number of end positions of each state being
+//!   computed once, after the construction
+//! * the number of distinct substrings, sum of len(v) - len(link(v)) over the states.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Suffix_automaton),
+//! [cp-algorithms](https://cp-algorithms.com/string/suffix-automaton.html)
+
+use std::collections::HashMap;
+
+pub(super) struct State {
+    /// The length of the longest substring of the state
+    pub(super) len: usize,
+    /// The state of the longest suffix of these substrings which belongs to another state,
+    /// None for the initial state
+    pub(super) link: Option<usize>,
+    /// The position of the last char of the first occurrence of the substrings of the state
+    pub(super) first_end: usize,
+    pub(super) next: HashMap<char, usize>,
+    /// The number of end positions of the substrings of the state
+    occurrences: u64,
+}
+
+/// The suffix automaton of a string, positions and lengths being counted in chars
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+}
+
+impl SuffixAutomaton {
+    pub fn new(s: &str) -> Self {
+        let mut states = vec![State {
+            len: 0,
+            link: None,
+            first_end: 0,
+            next: HashMap::new(),
+            occurrences: 0,
+        }];
+        let mut last = 0;
+        for (i, c) in s.chars().enumerate() {
+            let cur = states.len();
+            states.push(State {
+                len: states[last].len + 1,
+                link: None,
+                first_end: i,
+                next: HashMap::new(),
+                occurrences: 1,
+            });
+            let mut p = Some(last);
+            while let Some(q) = p {
+                if states[q].next.contains_key(&c) {
+                    break;
+                }
+                states[q].next.insert(c, cur);
+                p = states[q].link;
+            }
+            states[cur].link = Some(match p {
+                None => 0,
+                Some(p) => {
+                    let q = states[p].next[&c];
+                    if states[p].len + 1 == states[q].len {
+                        q
+                    } else {
+                        // q is split: its shortest substrings move to a clone
+                        let clone = states.len();
+                        states.push(State {
+                            len: states[p].len + 1,
+                            link: states[q].link,
+                            first_end: states[q].first_end,
+                            next: states[q].next.clone(),
+                            occurrences: 0,
+                        });
+                        let mut p = Some(p);
+                        while let Some(r) = p {
+                            if states[r].next.get(&c) != Some(&q) {
+                                break;
+                            }
+                            states[r].next.insert(c, clone);
+                            p = states[r].link;
+                        }
+                        states[q].link = Some(clone);
+                        clone
+                    }
+                }
+            });
+            last = cur;
+        }
+
+        // the end positions of a state are also end positions of its suffix link
+        let mut by_len: Vec<usize> = (1..states.len()).collect();
+        by_len.sort_unstable_by_key(|&v| std::cmp::Reverse(states[v].len));
+        for v in by_len {
+            if let Some(link) = states[v].link {
+                states[link].occurrences += states[v].occurrences;
+            }
+        }
+
+        SuffixAutomaton { states }
+    }
+
+    /// Checks if `pattern` is a substring of the string
+    pub fn contains_substring(&self, pattern: &str) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /// Counts the occurrences of `pattern` in the string, overlapping ones included
+    ///
+    /// The empty pattern occurs at each of the n + 1 positions of a string of n chars.
+    pub fn count_occurrences(&self, pattern: &str) -> u64 {
+        if pattern.is_empty() {
+            return self.len() as u64 + 1;
+        }
+        self.walk(pattern)
+            .map_or(0, |state| self.states[state].occurrences)
+    }
+
+    /// The number of distinct non-empty substrings of the string
+    pub fn count_distinct_substrings(&self) -> u64 {
+        self.states
+            .iter()
+            .filter_map(|state| {
+                let link = state.link?;
+                Some((state.len - self.states[link].len) as u64)
+            })
+            .sum()
+    }
+
+    /// The length of the string
+    pub fn len(&self) -> usize {
+        self.states.iter().map(|state| state.len).max().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(super) fn states(&self) -> &[State] {
+        &self.states
+    }
+
+    /// The state reached by reading `pattern` from the initial state
+    fn walk(&self, pattern: &str) -> Option<usize> {
+        pattern
+            .chars()
+            .try_fold(0, |state, c| self.states[state].next.get(&c).copied())
+    }
+}
+
+/// Counts the distinct non-empty substrings of `s`
+///
+/// # Complexity
+///
+/// O(n) time and space, for a fixed alphabet.
+pub fn count_distinct_substrings(s: &str) -> u64 {
+    SuffixAutomaton::new(s).count_distinct_substrings()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn naive_distinct_substrings(s: &str) -> u64 {
+        let chars: Vec<char> = s.chars().collect();
+        let mut substrings = HashSet::new();
+        for i in 0..chars.len() {
+            for j in i + 1..=chars.len() {
+                substrings.insert(&chars[i..j]);
+            }
+        }
+        substrings.len() as u64
+    }
+
+    fn naive_occurrences(s: &str, pattern: &str) -> u64 {
+        let chars: Vec<char> = s.chars().collect();
+        let pattern: Vec<char> = pattern.chars().collect();
+        (0..=chars.len().saturating_sub(pattern.len()))
+            .filter(|&i| chars[i..].starts_with(&pattern))
+            .count() as u64
+    }
+
+    macro_rules! distinct_substrings_tests {
+        ($($name:ident: ($s:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(count_distinct_substrings($s), $expected);
+                    assert_eq!(naive_distinct_substrings($s), $expected);
+                }
+            )*
+        };
+    }
+
+    distinct_substrings_tests! {
+        empty: ("", 0),
+        single_char: ("a", 1),
+        aab: ("aab", 5),
+        same_chars: ("aaaa", 4),
+        all_different: ("abcd", 10),
+        banana: ("banana", 15),
+        unicode: ("串用串", 5),
+    }
+
+    #[test]
+    fn queries() {
+        let s = "abracadabra";
+        let automaton = SuffixAutomaton::new(s);
+        assert_eq!(automaton.len(), 11);
+        for pattern in [
+            "",
+            "a",
+            "abra",
+            "bra",
+            "cad",
+            "abracadabra",
+            "abc",
+            "z",
+            "aa",
+            "rac",
+            "ra",
+        ] {
+            assert_eq!(
+                automaton.contains_substring(pattern),
+                s.contains(pattern),
+                "{pattern}"
+            );
+            assert_eq!(
+                automaton.count_occurrences(pattern),
+                naive_occurrences(s, pattern),
+                "{pattern}"
+            );
+        }
+        assert_eq!(automaton.count_occurrences("a"), 5);
+        assert_eq!(automaton.count_occurrences("abra"), 2);
+    }
+
+    #[test]
+    fn overlapping_occurrences() {
+        let automaton = SuffixAutomaton::new("aaaaa");
+        assert_eq!(automaton.count_occurrences("aa"), 4);
+        assert_eq!(automaton.count_occurrences("aaaaaa"), 0);
+    }
+
+    #[test]
+    fn empty_string() {
+        let automaton = SuffixAutomaton::new("");
+        assert!(automaton.is_empty());
+        assert!(automaton.contains_substring(""));
+        assert!(!automaton.contains_substring("a"));
+        assert_eq!(automaton.count_occurrences(""), 1);
+    }
+
+    #[test]
+    fn at_most_2n_states() {
+        let s = "abcbcbabaabcbabcbbacbacb";
+        let n = s.chars().count();
+        assert!(SuffixAutomaton::new(s).states().len() < 2 * n);
+        assert_eq!(count_distinct_substrings(s), naive_distinct_substrings(s));
+    }
+}
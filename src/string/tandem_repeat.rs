@@ -0,0 +1,183 @@
+//! Tandem repeats, also called squares or repetitions, of a string.
+//!
+//! A tandem repeat is a substring made of two or more consecutive copies of the same block,
+//! e.g. "abcabc" in "xabcabcy". `find_tandem_repeats` reports each maximal run of copies
+//! once, with its smallest period, checking every period length in O(n^2) time.
+//!
+//! `has_square` only tells if a square exists, in O(n log n) time, with the divide and
+//! conquer of Main and Lorentz: a square either lies in one half of the string, or crosses
+//! the middle, and the squares crossing the middle are found with Z-arrays.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Tandem_repeat),
+//! [Main-Lorentz](https://cp-algorithms.com/string/main_lorentz.html)
+
+use super::z_array;
+use std::collections::HashSet;
+
+/// A maximal run of copies of a block, positions being counted in chars
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TandemRepeat {
+    /// The position of the first char of the run
+    pub start: usize,
+    /// The length of the block, the smallest period of the run
+    pub period: usize,
+    /// The number of full copies of the block, at least 2.
+    /// The run may end with a partial copy, e.g. "abcabca" has 2 copies of "abc"
+    pub copies: u32,
+}
+
+/// Finds the tandem repeats of `s`
+///
+/// # Returns
+///
+/// * One `TandemRepeat` for each maximal run of at least two copies of a block,
+///   sorted by start and then by period.
+pub fn find_tandem_repeats(s: &str) -> Vec<TandemRepeat> {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+    let mut runs: Vec<TandemRepeat> = vec![];
+    // the runs found with a smaller period, as (start, end)
+    let mut found: HashSet<(usize, usize)> = HashSet::new();
+
+    for period in 1..=n / 2 {
+        // a maximal stretch of positions i with s[i] == s[i + period], at least period long,
+        // is a run of period `period`
+        let mut i = 0;
+        while i + period < n {
+            let start = i;
+            while i + period < n && chars[i] == chars[i + period] {
+                i += 1;
+            }
+            let end = i + period;
+            // a run of a smaller period p covering the same chars also has the period
+            // `period`, which is then a multiple of p
+            if i - start >= period && found.insert((start, end)) {
+                runs.push(TandemRepeat {
+                    start,
+                    period,
+                    copies: ((end - start) / period) as u32,
+                });
+            }
+            i += 1;
+        }
+    }
+
+    runs.sort_unstable_by_key(|run| (run.start, run.period));
+    runs
+}
+
+/// Checks if `s` contains a tandem repeat, i.e. two consecutive copies of the same block
+pub fn has_square(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    has_square_in(&chars)
+}
+
+fn has_square_in(s: &[char]) -> bool {
+    if s.len() < 2 {
+        return false;
+    }
+    let (u, v) = s.split_at(s.len() / 2);
+    has_square_in(u) || has_square_in(v) || has_crossing_square(u, v)
+}
+
+/// Checks if u + v has a square containing both the last char of u and the first char of v
+fn has_crossing_square(u: &[char], v: &[char]) -> bool {
+    let (nu, nv) = (u.len(), v.len());
+    let ru: Vec<Option<char>> = u.iter().rev().copied().map(Some).collect();
+    let rv: Vec<Option<char>> = v.iter().rev().copied().map(Some).collect();
+    let u: Vec<Option<char>> = u.iter().copied().map(Some).collect();
+    let v: Vec<Option<char>> = v.iter().copied().map(Some).collect();
+    // None separates the two strings, matching no char
+    let join = |a: &[Option<char>], b: &[Option<char>]| [a, &[None], b].concat();
+
+    let z1 = z_array(&ru);
+    let z2 = z_array(&join(&v, &u));
+    let z3 = z_array(&join(&ru, &rv));
+    let z4 = z_array(&v);
+    let get = |z: &[usize], i: usize| z.get(i).copied().unwrap_or(0);
+
+    // for each position of the center of the square, the length of its block and how far
+    // the block can extend on each side of the center
+    (0..nu + nv).any(|center| {
+        let (len, k1, k2) = if center < nu {
+            (
+                nu - center,
+                get(&z1, nu - center),
+                get(&z2, nv + 1 + center),
+            )
+        } else {
+            (
+                center - nu + 1,
+                get(&z3, nu + nv - (center - nu)),
+                get(&z4, center - nu + 1),
+            )
+        };
+        k1 + k2 >= len
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(start: usize, period: usize, copies: u32) -> TandemRepeat {
+        TandemRepeat {
+            start,
+            period,
+            copies,
+        }
+    }
+
+    fn naive_has_square(s: &str) -> bool {
+        let chars: Vec<char> = s.chars().collect();
+        (0..chars.len()).any(|i| {
+            (1..=(chars.len() - i) / 2).any(|p| chars[i..i + p] == chars[i + p..i + 2 * p])
+        })
+    }
+
+    macro_rules! tandem_repeat_tests {
+        ($($name:ident: ($s:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let expected: Vec<TandemRepeat> = $expected;
+                    assert_eq!(find_tandem_repeats($s), expected);
+                    assert_eq!(has_square($s), !expected.is_empty());
+                    assert_eq!(has_square($s), naive_has_square($s));
+                }
+            )*
+        };
+    }
+
+    tandem_repeat_tests! {
+        empty: ("", vec![]),
+        single_char: ("a", vec![]),
+        three_copies: ("abcabcabc", vec![run(0, 3, 3)]),
+        no_repeat: ("abcdef", vec![]),
+        same_chars: ("aaaa", vec![run(0, 1, 4)]),
+        partial_last_copy: ("xabcabcay", vec![run(1, 3, 2)]),
+        several_runs: ("aabb", vec![run(0, 1, 2), run(2, 1, 2)]),
+        nested_periods: ("abaaba", vec![run(0, 3, 2), run(2, 1, 2)]),
+        mississippi: (
+            "mississippi",
+            vec![run(1, 3, 2), run(2, 1, 2), run(5, 1, 2), run(8, 1, 2)]
+        ),
+        unicode: ("串用串用于", vec![run(0, 2, 2)]),
+        square_free: ("abcacbabcbac", vec![]),
+    }
+
+    #[test]
+    fn has_square_matches_naive() {
+        let words = [
+            "abacaba",
+            "abcbabcbcacb",
+            "abcabcbacbcabcbabcacbabcbacbc",
+            "xyzyx",
+            "abcdcbabcd",
+            "abcacbabcbacabcacbacabcb",
+        ];
+        for s in words {
+            assert_eq!(has_square(s), naive_has_square(s), "{s}");
+        }
+    }
+}
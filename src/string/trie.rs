@@ -0,0 +1,149 @@
+//! A trie (prefix tree) over a set of words.
+//!
+//! This is `data_structures::Trie` keyed by chars, so any Unicode text can be stored, with
+//! the word queries on top of it. The nodes ending a word keep a copy of that word as their
+//! value, which lets the queries return slices borrowed from the trie instead of rebuilding
+//! the words char by char.
+
+use crate::data_structures::Trie as CharTrie;
+
+/// A set of words supporting prefix queries
+#[derive(Debug, Default)]
+pub struct Trie {
+    words: CharTrie<char, String>,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Adds `word` to the set, inserting it again has no effect
+    pub fn insert(&mut self, word: &str) {
+        self.words.insert(word.chars(), word.to_owned());
+    }
+
+    /// Checks if `word` was inserted
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.get(word.chars()).is_some()
+    }
+
+    /// Checks if some inserted word starts with `prefix`
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.words.has_prefix(prefix.chars())
+    }
+
+    /// Returns all the inserted words starting with `prefix`, in lexicographic order
+    pub fn all_with_prefix<'a>(&'a self, prefix: &str) -> Vec<&'a str> {
+        let mut words: Vec<&str> = self
+            .words
+            .values_with_prefix(prefix.chars())
+            .into_iter()
+            .map(String::as_str)
+            .collect();
+        words.sort_unstable();
+        words
+    }
+
+    /// Returns the longest inserted word which is a prefix of `s`
+    pub fn longest_prefix<'a>(&'a self, s: &str) -> Option<&'a str> {
+        self.words
+            .longest_prefix_value(s.chars())
+            .map(String::as_str)
+    }
+
+    /// Returns the number of distinct words in the trie
+    pub fn word_count(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Removes `word` from the set, pruning the branches left without words
+    ///
+    /// # Returns
+    /// `true` if the word was in the set
+    pub fn delete(&mut self, word: &str) -> bool {
+        self.words.remove(word.chars()).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trie_of(words: &[&str]) -> Trie {
+        let mut trie = Trie::new();
+        for word in words {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn insert_and_contains() {
+        let trie = trie_of(&["apple", "app", "orange", "串用"]);
+        assert!(trie.contains("apple"));
+        assert!(trie.contains("app"));
+        assert!(trie.contains("串用"));
+        assert!(!trie.contains("ap"));
+        assert!(!trie.contains("apples"));
+        assert!(!trie.contains(""));
+        assert_eq!(trie.word_count(), 4);
+    }
+
+    #[test]
+    fn empty_word() {
+        let mut trie = trie_of(&[""]);
+        assert!(trie.contains(""));
+        assert_eq!(trie.word_count(), 1);
+        assert_eq!(trie.longest_prefix("abc"), Some(""));
+        assert!(trie.delete(""));
+        assert_eq!(trie.word_count(), 0);
+    }
+
+    #[test]
+    fn prefixes() {
+        let trie = trie_of(&["apple", "orange", "oregano", "or", "串用于", "串中"]);
+        assert!(trie.starts_with("ore"));
+        assert!(trie.starts_with(""));
+        assert!(!trie.starts_with("ab"));
+        assert_eq!(trie.all_with_prefix("or"), vec!["or", "orange", "oregano"]);
+        assert_eq!(trie.all_with_prefix("串"), vec!["串中", "串用于"]);
+        assert_eq!(trie.all_with_prefix("x"), Vec::<&str>::new());
+        assert_eq!(trie.all_with_prefix("").len(), 6);
+    }
+
+    #[test]
+    fn longest_prefix() {
+        let trie = trie_of(&["a", "ab", "abcd", "b"]);
+        assert_eq!(trie.longest_prefix("abcde"), Some("abcd"));
+        assert_eq!(trie.longest_prefix("abc"), Some("ab"));
+        assert_eq!(trie.longest_prefix("ba"), Some("b"));
+        assert_eq!(trie.longest_prefix("c"), None);
+        assert_eq!(trie.longest_prefix(""), None);
+    }
+
+    #[test]
+    fn delete() {
+        let mut trie = trie_of(&["app", "apple", "apply"]);
+        assert!(trie.delete("apple"));
+        assert!(!trie.delete("apple"));
+        assert!(!trie.delete("ap"));
+        assert!(!trie.delete("banana"));
+        assert!(trie.contains("app"));
+        assert!(trie.contains("apply"));
+        assert!(!trie.starts_with("apple"));
+        assert_eq!(trie.word_count(), 2);
+
+        assert!(trie.delete("app"));
+        assert!(trie.delete("apply"));
+        assert_eq!(trie.word_count(), 0);
+        assert!(!trie.starts_with("a"));
+    }
+
+    #[test]
+    fn duplicates_are_counted_once() {
+        let trie = trie_of(&["word", "word", "words"]);
+        assert_eq!(trie.word_count(), 2);
+        assert_eq!(trie.all_with_prefix("wo"), vec!["word", "words"]);
+    }
+}
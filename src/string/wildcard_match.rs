@@ -0,0 +1,154 @@
+//! Wildcard pattern matching.
+//!
+//! In a pattern, `?` matches exactly one char and `*` matches any sequence of chars,
+//! the empty one included. Every other char matches itself.
+//!
+//! The matching is the classic dynamic programming over the prefixes of the pattern and of
+//! the text, keeping a single row of |pattern| + 1 booleans: after reading a prefix of the
+//! text, `row[j]` tells if the first j chars of the pattern match it.
+//!
+//! References: [Wikipedia](https://en.wikipedia.org/wiki/Matching_wildcards)
+
+use std::ops::Range;
+
+/// Checks if the whole `text` matches `pattern`
+///
+/// # Complexity
+///
+/// O(|text| * |pattern|) time and O(|pattern|) space.
+pub fn wildcard_match(text: &str, pattern: &str) -> bool {
+    let pattern = compile(pattern);
+    let mut row = initial_row(&pattern);
+    for c in text.chars() {
+        row = next_row(&pattern, &row, c);
+    }
+    row[pattern.len()]
+}
+
+/// Finds all the substrings of `text` matching `pattern`
+///
+/// # Returns
+///
+/// The byte ranges of the matching substrings, sorted by start and then by end. The empty
+/// substrings are included when the pattern matches the empty string, e.g. for `*`.
+///
+/// # Complexity
+///
+/// O(|text|^2 * |pattern|) time and O(|pattern|) space, besides the output.
+pub fn wildcard_find(text: &str, pattern: &str) -> Vec<Range<usize>> {
+    let pattern = compile(pattern);
+    let mut matches = vec![];
+    let starts = text.char_indices().map(|(i, _)| i).chain([text.len()]);
+    for start in starts {
+        let mut row = initial_row(&pattern);
+        if row[pattern.len()] {
+            matches.push(start..start);
+        }
+        for (offset, c) in text[start..].char_indices() {
+            row = next_row(&pattern, &row, c);
+            if row[pattern.len()] {
+                let end = start + offset + c.len_utf8();
+                matches.push(start..end);
+            }
+        }
+    }
+    matches
+}
+
+/// The pattern as chars, each run of `*` being replaced by a single `*`
+fn compile(pattern: &str) -> Vec<char> {
+    let mut chars: Vec<char> = pattern.chars().collect();
+    chars.dedup_by(|a, b| *a == '*' && *b == '*');
+    chars
+}
+
+/// The row matching the empty text: only the empty prefixes and the ones made of `*` match
+fn initial_row(pattern: &[char]) -> Vec<bool> {
+    let mut row = vec![false; pattern.len() + 1];
+    row[0] = true;
+    for (j, &p) in pattern.iter().enumerate() {
+        row[j + 1] = row[j] && p == '*';
+    }
+    row
+}
+
+/// The row after reading c, given the row before it
+fn next_row(pattern: &[char], row: &[bool], c: char) -> Vec<bool> {
+    let mut next = vec![false; row.len()];
+    for (j, &p) in pattern.iter().enumerate() {
+        next[j + 1] = match p {
+            // * matches nothing more, or also matches c
+            '*' => next[j] || row[j + 1],
+            '?' => row[j],
+            _ => row[j] && p == c,
+        };
+    }
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! wildcard_match_tests {
+        ($($name:ident: ($text:expr, $pattern:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(wildcard_match($text, $pattern), $expected);
+                }
+            )*
+        };
+    }
+
+    wildcard_match_tests! {
+        star_between: ("axyzb", "a*b", true),
+        star_matches_empty: ("ab", "a*b", true),
+        star_wrong_end: ("axyzc", "a*b", false),
+        star_alone: ("anything at all", "*", true),
+        star_alone_empty_text: ("", "*", true),
+        question_mark: ("abc", "a?c", true),
+        question_mark_needs_a_char: ("ac", "a?c", false),
+        consecutive_stars: ("abc", "a***c", true),
+        empty_pattern_empty_text: ("", "", true),
+        empty_pattern: ("a", "", false),
+        empty_text: ("", "a", false),
+        exact: ("hello", "hello", true),
+        prefix_only: ("hello", "hell", false),
+        star_and_question_marks: ("mississippi", "m??*ss*i*pi", true),
+        backtracking: ("aaaaaaaaab", "*a*a*a*b", true),
+        backtracking_fails: ("aaaaaaaaaa", "*a*a*a*b", false),
+        unicode: ("串用于测试", "串?于*", true),
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(wildcard_find("abcab", "a?"), [0..2, 3..5]);
+        assert_eq!(wildcard_find("abcab", "a*b"), [0..2, 0..5, 3..5]);
+        assert_eq!(
+            wildcard_find("ab", "*"),
+            [0..0, 0..1, 0..2, 1..1, 1..2, 2..2]
+        );
+        assert!(wildcard_find("abc", "x").is_empty());
+        assert!(wildcard_find("", "?").is_empty());
+        assert_eq!(wildcard_find("串用串", "串"), [0..3, 6..9]);
+    }
+
+    #[test]
+    fn find_is_match_on_every_range() {
+        let text = "abracadabra";
+        for pattern in ["a*a", "?b*", "*c?", "r?c*b"] {
+            let found = wildcard_find(text, pattern);
+            for start in 0..=text.len() {
+                for end in start..=text.len() {
+                    let range = start..end;
+                    assert_eq!(
+                        found.contains(&range),
+                        wildcard_match(&text[range.clone()], pattern),
+                        "{pattern} on {range:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Checks the memory used by the MLCS search against the bytes actually allocated.
+//!
+//! This lives in its own test binary because it installs a counting global allocator.
+//! The tests hold `SERIAL` while measuring, so that no other test allocates meanwhile.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use the_algorithms_rust::string::{Context, MlcsConfig};
+
+// only some of the fixtures are used here
+#[allow(dead_code)]
+#[path = "../src/string/multiple_longest_common_subsequence/fixtures.rs"]
+mod fixtures;
+
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static SERIAL: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let live = LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK_BYTES.fetch_max(live, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn live_bytes() -> usize {
+    LIVE_BYTES.load(Ordering::SeqCst)
+}
+
+/// Runs `f` and returns the largest number of bytes it had allocated at once
+fn peak_bytes_of(f: impl FnOnce()) -> usize {
+    let before = live_bytes();
+    PEAK_BYTES.store(before, Ordering::SeqCst);
+    f();
+    PEAK_BYTES.load(Ordering::SeqCst) - before
+}
+
+fn assert_close(actual: usize, estimated: usize, tolerance: f64, what: &str) {
+    let ratio = actual as f64 / estimated as f64;
+    assert!(
+        (1.0 - tolerance..=1.0 + tolerance).contains(&ratio),
+        "{what}: allocated {actual} bytes, estimated {estimated} ({ratio:.3})"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_matches_allocations() {
+        let _serial = SERIAL.lock().unwrap();
+        let strings: Vec<String> = (0..4)
+            .map(|k| {
+                (0..300)
+                    .map(|i| char::from(b'a' + ((i * 7 + k * 3 + i / 5) % 6) as u8))
+                    .collect()
+            })
+            .collect();
+        let input: Vec<&str> = strings.iter().map(String::as_str).collect();
+
+        let before = live_bytes();
+        let mut ctx = Context::new(&input);
+        let tables = live_bytes() - before;
+        let estimate = ctx.estimated_memory();
+        assert_close(tables, estimate.tables(), 0.1, "tables");
+
+        let (_, stats) = ctx.solve_with_stats(&MlcsConfig::default());
+        let search = live_bytes() - before - tables;
+        assert_close(
+            search,
+            estimate.per_point * stats.nodes_expanded,
+            0.5,
+            "search maps",
+        );
+        assert_close(
+            live_bytes() - before,
+            estimate.with_points(stats.nodes_expanded),
+            0.1,
+            "total",
+        );
+    }
+
+    #[test]
+    fn low_memory_reconstruction_lowers_the_peak() {
+        let _serial = SERIAL.lock().unwrap();
+        let input = fixtures::MEDIUM_PLUS.input;
+        let low_memory = MlcsConfig {
+            low_memory_reconstruction: true,
+            ..MlcsConfig::default()
+        };
+
+        let mut results = vec![];
+        let peaks: Vec<usize> = [MlcsConfig::default(), low_memory]
+            .iter()
+            .map(|config| {
+                let ctx = Context::new(input);
+                peak_bytes_of(|| {
+                    let mut ctx = ctx;
+                    results.push(ctx.solve(config));
+                })
+            })
+            .collect();
+
+        assert_eq!(results[0], fixtures::MEDIUM_PLUS.expected);
+        assert_eq!(results[1], results[0]);
+        assert!(
+            peaks[1] * 10 < peaks[0] * 8,
+            "peak of {} bytes with breadcrumbs, {} bytes without",
+            peaks[1],
+            peaks[0]
+        );
+    }
+}